@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::clock::ticks_for;
+use crate::constants::*;
+
+// Power-ups that only last a while, unlike the upgrades a ship keeps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PowerUp {
+    RapidFire,     // Fires RAPID_FIRE_FACTOR times as often
+    Invincibility, // Shrugs off every hit
+    TimeSlow,      // Asteroids, saucers and bosses move at TIME_SLOW_FACTOR
+}
+
+impl PowerUp {
+    pub fn duration(self) -> Duration {
+        match self {
+            PowerUp::RapidFire => RAPID_FIRE_DURATION,
+            PowerUp::Invincibility => POWER_UP_INVINCIBILITY_DURATION,
+            PowerUp::TimeSlow => TIME_SLOW_DURATION,
+        }
+    }
+
+    // Short name for the HUD countdown.
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerUp::RapidFire => "Rapid",
+            PowerUp::Invincibility => "Invincible",
+            PowerUp::TimeSlow => "Slow-mo",
+        }
+    }
+}
+
+// --- ActiveEffects: the power-ups a ship is running, counted down in simulation ticks ---
+// Picking up one that's already running restarts its countdown rather than stacking a second copy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActiveEffects {
+    effects: Vec<(PowerUp, u64)>, // Each power-up and the ticks it has left
+}
+
+impl ActiveEffects {
+    pub fn new() -> Self {
+        ActiveEffects { effects: Vec::new() }
+    }
+
+    pub fn activate(&mut self, power_up: PowerUp) {
+        let ticks = ticks_for(power_up.duration());
+        match self.effects.iter_mut().find(|(active, _)| *active == power_up) {
+            Some((_, ticks_left)) => *ticks_left = ticks,
+            None => self.effects.push((power_up, ticks)),
+        }
+    }
+
+    pub fn has(&self, power_up: PowerUp) -> bool {
+        self.effects.iter().any(|&(active, _)| active == power_up)
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    // Advances every power-up by one tick. Returns the ones that ran out.
+    pub fn tick(&mut self) -> Vec<PowerUp> {
+        let mut expired = Vec::new();
+        for (power_up, ticks_left) in &mut self.effects {
+            *ticks_left = ticks_left.saturating_sub(1);
+            if *ticks_left == 0 {
+                expired.push(*power_up);
+            }
+        }
+        self.effects.retain(|&(_, ticks_left)| ticks_left > 0);
+        expired
+    }
+
+    // Multiplier on how often the ship can fire.
+    pub fn fire_rate_factor(&self) -> f64 {
+        if self.has(PowerUp::RapidFire) { RAPID_FIRE_FACTOR } else { 1.0 }
+    }
+
+    // The HUD's countdowns, e.g. "Rapid 7s  Slow-mo 2s", or None when nothing is running.
+    pub fn label(&self) -> Option<String> {
+        let second = ticks_for(Duration::from_secs(1));
+        let parts: Vec<String> = self.effects.iter().map(|&(power_up, ticks_left)| format!("{} {}s", power_up.label(), ticks_left.div_ceil(second))).collect();
+        (!parts.is_empty()).then(|| parts.join("  "))
+    }
+}
+
+impl Default for ActiveEffects {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::constants::*;
+
+// How stick travel past the dead zone maps to rotation speed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResponseCurve {
+    Linear,  // Speed in proportion to the push
+    Smooth,  // Squared: gentle near the centre, full speed at the edge
+    Precise, // Cubed: very fine control for small pushes
+}
+
+impl ResponseCurve {
+    const ALL: [ResponseCurve; 3] = [ResponseCurve::Linear, ResponseCurve::Smooth, ResponseCurve::Precise];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ResponseCurve::Linear => "linear",
+            ResponseCurve::Smooth => "smooth",
+            ResponseCurve::Precise => "precise",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|curve| curve.name() == name)
+    }
+
+    // The next curve in the settings menu, wrapping around.
+    pub fn step(self, forward: bool) -> Self {
+        let index = Self::ALL.iter().position(|&curve| curve == self).unwrap_or(0);
+        let count = Self::ALL.len();
+        Self::ALL[if forward { (index + 1) % count } else { (index + count - 1) % count }]
+    }
+
+    fn apply(self, travel: f64) -> f64 {
+        match self {
+            ResponseCurve::Linear => travel,
+            ResponseCurve::Smooth => travel.powi(2),
+            ResponseCurve::Precise => travel.powi(3),
+        }
+    }
+}
+
+// --- AnalogSettings: how raw stick readings become ship controls ---
+// Part of the profile and edited in Settings. Gamepad backends run every axis through `normalize` before it
+// reaches the ship, so replays record the shaped values and play back the same whatever the settings are then.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnalogSettings {
+    pub dead_zone: u8, // Percent of the stick's travel ignored around the centre, 0 to MAX_DEAD_ZONE
+    pub curve: ResponseCurve,
+    pub sensitivity: u8, // Percent, MIN_SENSITIVITY to MAX_SENSITIVITY; above 100 reaches full speed before the edge
+}
+
+impl AnalogSettings {
+    pub fn new() -> Self {
+        AnalogSettings { dead_zone: DEFAULT_DEAD_ZONE, curve: ResponseCurve::Linear, sensitivity: DEFAULT_SENSITIVITY }
+    }
+
+    // A raw axis reading (-1.0 to 1.0) shaped by the dead zone, curve and sensitivity. Travel past the dead zone is
+    // rescaled to start from zero, so there's no jump in speed at its edge.
+    pub fn normalize(&self, raw: f64) -> f64 {
+        let dead_zone = self.dead_zone as f64 / 100.0;
+        let magnitude = raw.abs();
+        if magnitude <= dead_zone {
+            return 0.0;
+        }
+        let travel = ((magnitude - dead_zone) / (1.0 - dead_zone)).min(1.0);
+        (raw.signum() * self.curve.apply(travel) * self.sensitivity as f64 / 100.0).clamp(-1.0, 1.0)
+    }
+
+    pub fn step_dead_zone(&mut self, forward: bool) {
+        self.dead_zone = if forward { (self.dead_zone + DEAD_ZONE_STEP).min(MAX_DEAD_ZONE) } else { self.dead_zone.saturating_sub(DEAD_ZONE_STEP) };
+    }
+
+    pub fn step_sensitivity(&mut self, forward: bool) {
+        let stepped = if forward { self.sensitivity.saturating_add(SENSITIVITY_STEP) } else { self.sensitivity.saturating_sub(SENSITIVITY_STEP) };
+        self.sensitivity = stepped.clamp(MIN_SENSITIVITY, MAX_SENSITIVITY);
+    }
+}
+
+impl Default for AnalogSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+// --- AssistSettings: independently toggleable accessibility helpers ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssistSettings {
+    pub enemy_speed_percent: u32,
+    pub extra_starting_health: u32,
+    pub extended_invincibility: bool,
+    pub auto_fire: bool,
+    pub forgiving_hitbox: bool, // Only the ship's centre cell collides
+}
+
+impl Default for AssistSettings {
+    fn default() -> Self {
+        AssistSettings {
+            enemy_speed_percent: 100,
+            extra_starting_health: 0,
+            extended_invincibility: false,
+            auto_fire: false,
+            forgiving_hitbox: false,
+        }
+    }
+}
+
+impl AssistSettings {
+    // Reads the --assist-* flags. A value that is missing or out of range comes back as the message to show.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut assist = AssistSettings::default();
+        let value_after = |flag: &str| {
+            args.iter().position(|arg| arg == flag).map(|index| args.get(index + 1).and_then(|value| value.parse::<u32>().ok()))
+        };
+        match value_after("--assist-speed") {
+            None => {}
+            Some(Some(percent)) if (10..=100).contains(&percent) => assist.enemy_speed_percent = percent,
+            Some(_) => return Err("--assist-speed expects an asteroid speed percentage from 10 to 100.".to_string()),
+        }
+        match value_after("--assist-health") {
+            None => {}
+            Some(Some(health)) => assist.extra_starting_health = health,
+            Some(None) => return Err("--assist-health expects a number of extra starting health points.".to_string()),
+        }
+        assist.extended_invincibility = args.iter().any(|arg| arg == "--assist-invincibility");
+        assist.auto_fire = args.iter().any(|arg| arg == "--assist-autofire");
+        assist.forgiving_hitbox = args.iter().any(|arg| arg == "--assist-hitbox");
+        Ok(assist)
+    }
+
+    pub fn is_active(&self) -> bool {
+        *self != AssistSettings::default()
+    }
+
+    pub fn enemy_speed_factor(&self) -> f64 {
+        self.enemy_speed_percent as f64 / 100.0
+    }
+
+    pub fn invincibility(&self, base: Duration) -> Duration {
+        if self.extended_invincibility { base * 2 } else { base }
+    }
+
+    // Short description used to flag assisted runs.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.enemy_speed_percent != 100 {
+            parts.push(format!("speed {}%", self.enemy_speed_percent));
+        }
+        if self.extra_starting_health > 0 {
+            parts.push(format!("+{} health", self.extra_starting_health));
+        }
+        if self.extended_invincibility {
+            parts.push("long invincibility".to_string());
+        }
+        if self.auto_fire {
+            parts.push("auto-fire".to_string());
+        }
+        if self.forgiving_hitbox {
+            parts.push("small hitbox".to_string());
+        }
+        parts.join(", ")
+    }
+}
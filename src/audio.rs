@@ -0,0 +1,139 @@
+use crate::constants::*;
+use crate::entities::AsteroidSize;
+use crate::events::GameEvent;
+
+// --- Sound effects: the game names what happened and a backend decides how it sounds ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sound {
+    Shoot,
+    Thrust,
+    Explosion { big: bool },
+    UpgradePickup,
+}
+
+impl Sound {
+    pub fn from_game_event(event: &GameEvent) -> Option<Self> {
+        match event {
+            GameEvent::AsteroidDestroyed { size, .. } => Some(Sound::Explosion { big: *size == AsteroidSize::Large }),
+            GameEvent::SaucerDestroyed { .. } | GameEvent::BossDestroyed { .. } => Some(Sound::Explosion { big: true }),
+            GameEvent::UpgradeCollected { .. } => Some(Sound::UpgradePickup),
+            _ => None,
+        }
+    }
+}
+
+pub trait AudioBackend {
+    fn play(&mut self, sound: Sound);
+
+    // Called every frame with the mixer's current levels; backends without music can ignore them.
+    fn set_levels(&mut self, _levels: MixLevels) {}
+}
+
+// The mixer's channels, each with its own volume.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Channel {
+    Music,
+    Effects,
+}
+
+// The music that plays under the action.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Track {
+    Wave,
+    Boss,
+}
+
+// Gains from 0.0 (silent) to 1.0 (full) for a backend to play at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MixLevels {
+    pub wave_music: f64,
+    pub boss_music: f64,
+    pub effects: f64,
+}
+
+// --- Mixer: music and effect levels steered by game events ---
+// A boss warning crossfades from the wave track to the boss track and ducks the music under the warning; big
+// explosions duck it briefly too. The mixer only works out the levels; `Game` feeds it the event stream and game
+// time and hands `levels` to the backend every frame.
+pub struct Mixer {
+    music_volume: u8,   // Percent
+    effects_volume: u8, // Percent
+    track: Track,
+    crossfade: f64, // Seconds since `track` started fading in
+    duck: f64,      // Seconds of ducking left
+}
+
+impl Mixer {
+    pub fn new(music_volume: u8, effects_volume: u8) -> Self {
+        // Starts with the wave track already faded in
+        Mixer { music_volume, effects_volume, track: Track::Wave, crossfade: MUSIC_CROSSFADE.as_secs_f64(), duck: 0.0 }
+    }
+
+    pub fn set_volume(&mut self, channel: Channel, percent: u8) {
+        match channel {
+            Channel::Music => self.music_volume = percent.min(100),
+            Channel::Effects => self.effects_volume = percent.min(100),
+        }
+    }
+
+    pub fn on_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::BossArrived { .. } => {
+                self.switch_to(Track::Boss);
+                self.duck_for(DUCK_BOSS_WARNING.as_secs_f64());
+            }
+            GameEvent::BossDestroyed { .. } => {
+                self.switch_to(Track::Wave);
+                self.duck_for(DUCK_EXPLOSION.as_secs_f64());
+            }
+            _ if Sound::from_game_event(event) == Some(Sound::Explosion { big: true }) => self.duck_for(DUCK_EXPLOSION.as_secs_f64()),
+            _ => {}
+        }
+    }
+
+    // Moves fades and ducks on by `dt` seconds of game time.
+    pub fn update(&mut self, dt: f64) {
+        self.crossfade += dt;
+        self.duck = (self.duck - dt).max(0.0);
+    }
+
+    pub fn levels(&self) -> MixLevels {
+        let release = DUCK_RELEASE.as_secs_f64();
+        let ducked = (self.duck / release).min(1.0);
+        let music = self.music_volume as f64 / 100.0 * (1.0 - ducked * (1.0 - DUCKED_MUSIC_GAIN));
+        let fade_in = (self.crossfade / MUSIC_CROSSFADE.as_secs_f64()).min(1.0);
+        let (wave, boss) = match self.track {
+            Track::Wave => (fade_in, 1.0 - fade_in),
+            Track::Boss => (1.0 - fade_in, fade_in),
+        };
+        MixLevels { wave_music: music * wave, boss_music: music * boss, effects: self.effects_volume as f64 / 100.0 }
+    }
+
+    // A switch in the middle of a crossfade picks up from the levels it had reached, so nothing jumps.
+    fn switch_to(&mut self, track: Track) {
+        if track == self.track {
+            return;
+        }
+        let fade = MUSIC_CROSSFADE.as_secs_f64();
+        self.crossfade = fade - self.crossfade.min(fade);
+        self.track = track;
+    }
+
+    // Overlapping ducks don't stack; the longest one wins.
+    fn duck_for(&mut self, seconds: f64) {
+        self.duck = self.duck.max(seconds);
+    }
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MUSIC_VOLUME, DEFAULT_EFFECTS_VOLUME)
+    }
+}
+
+// Debug, ScreenBuffer and headless runs make no noise.
+pub struct SilentAudio;
+
+impl AudioBackend for SilentAudio {
+    fn play(&mut self, _sound: Sound) {}
+}
@@ -0,0 +1,47 @@
+use std::f64::consts::PI;
+
+use crate::constants::{AUTOPILOT_AIM_TOLERANCE, AUTOPILOT_CHASE_DISTANCE, AUTOPILOT_FIRE_ARC};
+use crate::entities::{Asteroid, Ship};
+use crate::pilot::{InputAction, Pilot, WorldView};
+
+// --- Autopilot: a simple seek-and-shoot pilot that flies a ship with the same controls as the keyboard ---
+// It turns towards the nearest asteroid, fires once it is roughly lined up and closes in on targets that are far
+// away. It never uses hyperspace or dodges, which is plenty for the title screen's attract demo and makes a
+// baseline for `--bot`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Autopilot;
+
+impl Autopilot {
+    pub fn new() -> Self {
+        Autopilot
+    }
+}
+
+impl Pilot for Autopilot {
+    fn decide(&mut self, view: &WorldView) -> InputAction {
+        let ship = view.ship;
+        let mut input = InputAction::default();
+        let nearest = view.asteroids.iter().map(|asteroid| (asteroid, distance(ship, asteroid))).min_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((target, distance)) = nearest else {
+            return input;
+        };
+        // Shots fly along the ship's heading in cells, so that's the angle to line up
+        let bearing = (target.position.y - ship.position.y).atan2(target.position.x - ship.position.x);
+        let error = (bearing - ship.angle + PI).rem_euclid(2.0 * PI) - PI;
+        input.rotate_left = error < -AUTOPILOT_AIM_TOLERANCE;
+        input.rotate_right = error > AUTOPILOT_AIM_TOLERANCE;
+        input.fire = error.abs() < AUTOPILOT_FIRE_ARC;
+        input.thrust = input.fire && distance > AUTOPILOT_CHASE_DISTANCE;
+        input
+    }
+}
+
+impl Default for Autopilot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn distance(ship: &Ship, asteroid: &Asteroid) -> f64 {
+    ((asteroid.position.x - ship.position.x).powi(2) + (asteroid.position.y - ship.position.y).powi(2)).sqrt()
+}
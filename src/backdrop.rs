@@ -0,0 +1,65 @@
+use crossterm::style::Color;
+
+use crate::clock::ticks_for;
+use crate::constants::{BACKDROP_NEBULA_DRIFT, BACKDROP_PHASE};
+use crate::rendering::GameGrid;
+
+// --- Backdrop: a slowly cycling star field behind the playfield ---
+
+pub struct BackdropTheme {
+    pub name: &'static str,
+    pub star_density: u32, // Stars per thousand cells
+    pub nebula: Option<char>, // Pattern used for nebula bands, if any
+    pub tint: Color,
+}
+
+pub const BACKDROP_THEMES: [BackdropTheme; 4] = [
+    BackdropTheme { name: "Deep Space", star_density: 8, nebula: None, tint: Color::White },
+    BackdropTheme { name: "Nebula Dawn", star_density: 12, nebula: Some('∙'), tint: Color::Magenta },
+    BackdropTheme { name: "Star Cluster", star_density: 30, nebula: None, tint: Color::Cyan },
+    BackdropTheme { name: "Nebula Dusk", star_density: 15, nebula: Some(','), tint: Color::DarkYellow },
+];
+
+pub struct Backdrop {
+    pub static_theme: bool, // Reduced motion: keep the first theme and don't drift
+}
+
+impl Backdrop {
+    pub fn new(static_theme: bool) -> Self {
+        Backdrop { static_theme }
+    }
+
+    pub fn theme(&self, tick: u64) -> &'static BackdropTheme {
+        if self.static_theme {
+            return &BACKDROP_THEMES[0];
+        }
+        let phase = (tick / ticks_for(BACKDROP_PHASE)) as usize % BACKDROP_THEMES.len();
+        &BACKDROP_THEMES[phase]
+    }
+
+    // Fills empty cells with stars and nebula bands. Call right after clearing the grid.
+    pub fn draw(&self, game_grid: &mut GameGrid, tick: u64) {
+        let theme = self.theme(tick);
+        let drift = if self.static_theme { 0 } else { tick / ticks_for(BACKDROP_NEBULA_DRIFT) };
+        for y in 0..game_grid.height {
+            for x in 0..game_grid.width {
+                if star_hash(x, y) % 1000 < theme.star_density {
+                    let star = if star_hash(y, x).is_multiple_of(4) { '*' } else { '·' };
+                    game_grid.set_cell(x, y, star, theme.tint);
+                } else if let Some(pattern) = theme.nebula
+                    && (x as u64 + 2 * y as u64 + drift) % 24 < 3
+                    && (x + y).is_multiple_of(2)
+                {
+                    game_grid.set_cell(x, y, pattern, theme.tint);
+                }
+            }
+        }
+    }
+}
+
+// Cheap integer hash so stars stay put from frame to frame.
+fn star_hash(x: u16, y: u16) -> u32 {
+    let mut h = (x as u32).wrapping_mul(374_761_393) ^ (y as u32).wrapping_mul(668_265_263);
+    h = (h ^ (h >> 13)).wrapping_mul(1_274_126_177);
+    h ^ (h >> 16)
+}
@@ -0,0 +1,129 @@
+use crate::constants::*;
+use crate::entities::Ship;
+use crate::upgrades::UpgradeType;
+use crate::weapons::WEAPONS;
+
+// --- UpgradeBalance: caps and diminishing returns for the stacking multiplier upgrades ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpgradeBalance {
+    pub fire_rate_cap: f64,
+    pub bullet_speed_cap: f64,
+    pub booster_cap: f64,
+    pub step: f64, // Share of the remaining headroom each pickup grants
+}
+
+impl UpgradeBalance {
+    pub fn new() -> Self {
+        UpgradeBalance {
+            fire_rate_cap: MAX_FIRE_RATE_MULTIPLIER,
+            bullet_speed_cap: MAX_BULLET_SPEED_MULTIPLIER,
+            booster_cap: MAX_BOOSTER_MULTIPLIER,
+            step: UPGRADE_HEADROOM_STEP,
+        }
+    }
+
+    // Each pickup closes part of the gap to the cap, so early pickups count most and the cap is never passed.
+    pub fn improve(&self, current: f64, cap: f64) -> f64 {
+        (current + (cap - current).max(0.0) * self.step).min(cap)
+    }
+
+    // Adds a kept upgrade's effect to the ship's stats. Shields and health are the caller's to hand out.
+    pub fn apply(&self, ship: &mut Ship, upgrade_type: UpgradeType) {
+        match upgrade_type {
+            UpgradeType::FireRate => ship.fire_rate_multiplier = self.improve(ship.fire_rate_multiplier, self.fire_rate_cap),
+            UpgradeType::BulletSpeed => ship.bullet_speed_multiplier = self.improve(ship.bullet_speed_multiplier, self.bullet_speed_cap),
+            UpgradeType::BulletSize => ship.bullet_size_multiplier += 0.5,
+            UpgradeType::CritChance => ship.crit_chance = (ship.crit_chance + CRIT_CHANCE_PER_UPGRADE).min(MAX_CRIT_CHANCE),
+            UpgradeType::Range => ship.bullet_range_multiplier = (ship.bullet_range_multiplier + BULLET_RANGE_PER_UPGRADE).min(MAX_BULLET_RANGE_MULTIPLIER),
+            UpgradeType::Piercing => ship.pierce = (ship.pierce + 1).min(MAX_PIERCE),
+            UpgradeType::Booster => ship.booster_multiplier = self.improve(ship.booster_multiplier, self.booster_cap),
+            UpgradeType::ShipSize => {
+                ship.ship_size_multiplier += 0.2;
+                ship.max_health += 1;
+            }
+            UpgradeType::EnergyCapacity => ship.energy.expand(),
+            // Weapons change how the ship fires rather than a stat; see `weapons::volley_angles`
+            UpgradeType::TripleShot | UpgradeType::RearGun | UpgradeType::RadialBurst => {}
+            UpgradeType::Shield | UpgradeType::Health | UpgradeType::HealthMax => {}
+            // Power-ups run on the ship's `ActiveEffects` instead
+            UpgradeType::RapidFire | UpgradeType::Invincibility | UpgradeType::TimeSlow => {}
+        }
+    }
+
+    // Takes the latest pickup of `upgrade_type` back off the ship. Caps and diminishing returns make the effects
+    // order-dependent, so the stats are rebuilt from the pickups that are left rather than stepped back.
+    // Returns false if the ship doesn't have one.
+    pub fn jettison(&self, ship: &mut Ship, upgrade_type: UpgradeType) -> bool {
+        let Some(index) = ship.upgrades.iter().rposition(|&kept| kept == upgrade_type) else {
+            return false;
+        };
+        ship.upgrades.remove(index);
+        if upgrade_type == UpgradeType::ShipSize {
+            ship.max_health -= 1;
+        }
+        let (max_health, energy) = (ship.max_health, ship.energy.current);
+        ship.fire_rate_multiplier = 1.0;
+        ship.bullet_speed_multiplier = 1.0;
+        ship.bullet_size_multiplier = 1.0;
+        ship.crit_chance = BASE_CRIT_CHANCE;
+        ship.bullet_range_multiplier = 1.0;
+        ship.pierce = 0;
+        ship.booster_multiplier = 1.0;
+        ship.ship_size_multiplier = 1.0;
+        ship.energy.capacity = SHIP_ENERGY_CAPACITY;
+        for kept in ship.upgrades.clone() {
+            self.apply(ship, kept);
+        }
+        ship.max_health = max_health;
+        ship.energy.current = energy.min(ship.energy.capacity);
+        true
+    }
+
+    // The HUD's upgrade panel: every upgrade the ship has picked up, led by its pickup icon, with its cap where
+    // there is one. Shields are left out: the status panel shows them as pips.
+    pub fn active_upgrades(&self, ship: &Ship) -> Vec<(UpgradeType, String)> {
+        let mut lines = Vec::new();
+        if ship.fire_rate_multiplier > 1.0 {
+            let (icon, shots_per_second) = (UpgradeType::FireRate.icon(), 1.0 / ship.shot_cooldown().as_secs_f64());
+            let (value, cap) = (ship.fire_rate_multiplier, self.fire_rate_cap);
+            lines.push((UpgradeType::FireRate, format!("{} {:<10} x{:.2}/{:.1} {:.1}/s", icon, "Fire rate", value, cap, shots_per_second)));
+        }
+        let capped = [
+            (UpgradeType::BulletSpeed, "Shot speed", ship.bullet_speed_multiplier, self.bullet_speed_cap),
+            (UpgradeType::Booster, "Booster", ship.booster_multiplier, self.booster_cap),
+            (UpgradeType::Range, "Range", ship.bullet_range_multiplier, MAX_BULLET_RANGE_MULTIPLIER),
+        ];
+        for (upgrade_type, name, value, cap) in capped {
+            if value > 1.0 {
+                lines.push((upgrade_type, format!("{} {:<10} x{:.2}/{:.1}", upgrade_type.icon(), name, value, cap)));
+            }
+        }
+        if ship.crit_chance > BASE_CRIT_CHANCE {
+            let icon = UpgradeType::CritChance.icon();
+            lines.push((UpgradeType::CritChance, format!("{} {:<10} {:>3.0}%/{:.0}%", icon, "Crit", ship.crit_chance * 100.0, MAX_CRIT_CHANCE * 100.0)));
+        }
+        if ship.bullet_size_multiplier > 1.0 {
+            lines.push((UpgradeType::BulletSize, format!("{} {:<10} x{:.1}", UpgradeType::BulletSize.icon(), "Shot size", ship.bullet_size_multiplier)));
+        }
+        if ship.pierce > 0 {
+            lines.push((UpgradeType::Piercing, format!("{} {:<10} {}/{}", UpgradeType::Piercing.icon(), "Piercing", ship.pierce, MAX_PIERCE)));
+        }
+        for weapon in WEAPONS.into_iter().filter(|weapon| ship.upgrades.contains(weapon)) {
+            lines.push((weapon, format!("{} {}", weapon.icon(), weapon.name())));
+        }
+        if ship.ship_size_multiplier > 1.0 {
+            lines.push((UpgradeType::ShipSize, format!("{} {:<10} x{:.1}", UpgradeType::ShipSize.icon(), "Ship size", ship.ship_size_multiplier)));
+        }
+        if ship.energy.capacity > SHIP_ENERGY_CAPACITY {
+            let icon = UpgradeType::EnergyCapacity.icon();
+            lines.push((UpgradeType::EnergyCapacity, format!("{} {:<10} {:.0}/{:.0}", icon, "Energy cap", ship.energy.capacity, MAX_ENERGY_CAPACITY)));
+        }
+        lines
+    }
+}
+
+impl Default for UpgradeBalance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,197 @@
+use rand::Rng;
+
+use crate::clock::tick_seconds;
+use crate::constants::*;
+use crate::entities::{Asteroid, AsteroidSize, Entity, UpdateContext};
+use crate::entity_id::{EntityAllocator, EntityId, Identified};
+use crate::rendering::GameGrid;
+use crate::types::{Vector2D, wrap_coordinate};
+
+// One cell of the boss's body. Each is hit and destroyed on its own, so shots chip the boss away.
+#[derive(Clone, Copy, Debug)]
+pub struct BossSegment {
+    pub offset: (f64, f64),
+    pub hits_remaining: u32,
+}
+
+// --- Boss: a large multi-cell asteroid that drifts slowly and has to be taken apart segment by segment ---
+#[derive(Clone)]
+pub struct Boss {
+    pub id: EntityId,
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub segments: Vec<BossSegment>,
+    pub max_health: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BossHit {
+    Damaged,
+    Destroyed { position: Vector2D },
+}
+
+impl Boss {
+    pub fn new(id: EntityId, position: Vector2D, velocity: Vector2D) -> Self {
+        // An ellipse twice as wide as it is tall, so it looks round in terminal cells
+        let mut segments = Vec::new();
+        for dy in -BOSS_RADIUS..=BOSS_RADIUS {
+            for dx in -2 * BOSS_RADIUS..=2 * BOSS_RADIUS {
+                let (x, y) = (dx as f64 / 2.0, dy as f64);
+                if x * x + y * y <= (BOSS_RADIUS * BOSS_RADIUS) as f64 {
+                    segments.push(BossSegment { offset: (dx as f64, dy as f64), hits_remaining: BOSS_SEGMENT_HITS });
+                }
+            }
+        }
+        let max_health = segments.len() as u32 * BOSS_SEGMENT_HITS;
+        Boss { id, position, velocity, segments, max_health }
+    }
+
+    pub fn health(&self) -> u32 {
+        self.segments.iter().map(|segment| segment.hits_remaining).sum()
+    }
+
+    fn segment_cell(&self, segment: &BossSegment) -> (u16, u16) {
+        ((self.position.x + segment.offset.0).round() as u16, (self.position.y + segment.offset.1).round() as u16)
+    }
+
+    // Whether a segment of the body is at `point`.
+    pub fn covers(&self, point: (u16, u16)) -> bool {
+        self.segments.iter().any(|segment| self.segment_cell(segment) == point)
+    }
+
+    // Takes `damage` hits off the segment at `point`, removing it once it has taken them all. Damage left over
+    // (from a critical hit) carries on into the nearest segment still standing. Returns whether one was hit.
+    pub fn hit_segment(&mut self, point: (u16, u16), mut damage: u32) -> bool {
+        let Some(mut index) = self.segments.iter().position(|segment| self.segment_cell(segment) == point) else {
+            return false;
+        };
+        loop {
+            let taken = damage.min(self.segments[index].hits_remaining);
+            self.segments[index].hits_remaining -= taken;
+            damage -= taken;
+            let (x, y) = self.segments[index].offset;
+            if self.segments[index].hits_remaining == 0 {
+                self.segments.swap_remove(index);
+            }
+            let distance = |segment: &BossSegment| (segment.offset.0 - x).powi(2) + (segment.offset.1 - y).powi(2);
+            let nearest = self.segments.iter().enumerate().min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)));
+            match nearest {
+                Some((next, _)) if damage > 0 => index = next,
+                _ => return true,
+            }
+        }
+    }
+}
+
+impl Entity for Boss {
+    fn update(&mut self, ctx: &UpdateContext) {
+        self.position = self.position.add(self.velocity.scale(ctx.dt / tick_seconds()));
+        self.position.x = wrap_coordinate(self.position.x, ctx.world_width as f64);
+        self.position.y = wrap_coordinate(self.position.y, ctx.world_height as f64);
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        for segment in &self.segments {
+            let (x, y) = self.segment_cell(segment);
+            let glyph = if segment.hits_remaining > 1 { '▓' } else { '▒' };
+            game_grid.set_cell(x, y, glyph, BOSS_COLOR);
+        }
+
+        // Health bar floating above the body
+        let filled = (self.health() as usize * BOSS_HEALTH_BAR_WIDTH).div_ceil(self.max_health.max(1) as usize);
+        let bar = format!("[{}{}]", "=".repeat(filled), " ".repeat(BOSS_HEALTH_BAR_WIDTH - filled));
+        let bar_x = (self.position.x.round() as u16).saturating_sub(bar.len() as u16 / 2);
+        let bar_y = (self.position.y.round() as u16).saturating_sub(BOSS_RADIUS as u16 + 1);
+        game_grid.write_str(bar_x, bar_y, &bar, BOSS_COLOR);
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        self.segments.iter().map(|segment| self.segment_cell(segment)).collect()
+    }
+}
+
+impl Identified for Boss {
+    fn id(&self) -> EntityId {
+        self.id
+    }
+}
+
+// --- BossEncounter: the boss that arrives every few waves ---
+#[derive(Clone)]
+pub struct BossEncounter {
+    pub boss: Option<Boss>,
+}
+
+impl BossEncounter {
+    pub fn new() -> Self {
+        BossEncounter { boss: None }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.boss.is_some()
+    }
+
+    pub fn is_boss_wave(wave: usize) -> bool {
+        wave > 0 && wave.is_multiple_of(BOSS_WAVE_INTERVAL)
+    }
+
+    // Brings the boss in from the top edge, drifting slowly across the field.
+    pub fn spawn(&mut self, entity_ids: &mut EntityAllocator, rng: &mut impl Rng, terminal_width: u16) {
+        let x = rng.gen_range(0.0..terminal_width.max(1) as f64);
+        let angle = rng.gen_range(0.25..0.75) * std::f64::consts::PI; // Downwards, left or right
+        let velocity = Vector2D::new(angle.cos() * BOSS_SPEED, angle.sin() * BOSS_SPEED);
+        self.boss = Some(Boss::new(entity_ids.allocate(), Vector2D::new(x, 0.0), velocity));
+    }
+
+    // Moves the boss by one tick. Returns which of the ships (given by their cells) its body overlaps.
+    pub fn update(&mut self, ctx: &UpdateContext, ship_coords: &[Vec<(u16, u16)>]) -> Vec<bool> {
+        let Some(boss) = &mut self.boss else {
+            return vec![false; ship_coords.len()];
+        };
+        boss.update(ctx);
+        let coords = boss.coords();
+        ship_coords.iter().map(|ship| ship.iter().any(|point| coords.contains(point))).collect()
+    }
+
+    // Whether a shot at `point` would hit the boss, so the crit is only rolled for shots that land.
+    pub fn covers(&self, point: (u16, u16)) -> bool {
+        self.boss.as_ref().is_some_and(|boss| boss.covers(point))
+    }
+
+    pub fn hit_by(&mut self, point: (u16, u16), damage: u32, entity_ids: &mut EntityAllocator) -> Option<BossHit> {
+        let boss = self.boss.as_mut()?;
+        if !boss.hit_segment(point, damage) {
+            return None;
+        }
+        if boss.health() > 0 {
+            return Some(BossHit::Damaged);
+        }
+        let boss = self.boss.take()?;
+        entity_ids.release(boss.id);
+        Some(BossHit::Destroyed { position: boss.position })
+    }
+
+    pub fn draw(&self, game_grid: &mut GameGrid) {
+        if let Some(boss) = &self.boss {
+            boss.draw(game_grid);
+        }
+    }
+}
+
+impl Default for BossEncounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The large asteroids a destroyed boss breaks into, flying outwards in an even ring around `center`.
+pub fn shatter(center: Vector2D, entity_ids: &mut EntityAllocator, rng: &mut impl Rng, game_speed_multiplier: f64) -> Vec<Asteroid> {
+    (0..BOSS_RING_ASTEROIDS).map(|i| {
+        let angle = i as f64 * 2.0 * std::f64::consts::PI / BOSS_RING_ASTEROIDS as f64;
+        let x = center.x + angle.cos() * BOSS_RADIUS as f64 * TERMINAL_ASPECT_RATIO_COMPENSATION;
+        let y = center.y + angle.sin() * BOSS_RADIUS as f64;
+        let mut asteroid = Asteroid::new(entity_ids.allocate(), x, y, rng, AsteroidSize::Large, game_speed_multiplier);
+        asteroid.set_heading(angle);
+        asteroid
+    }).collect()
+}
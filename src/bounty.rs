@@ -0,0 +1,69 @@
+use rand::Rng;
+
+use crate::clock::{GameClock, Timer};
+use crate::constants::{BOUNTY_INTERVAL, BOUNTY_TIME_LIMIT};
+use crate::entities::Asteroid;
+use crate::entity_id::{EntityAllocator, EntityId};
+use crate::types::Vector2D;
+
+// What changed about the bounty this tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BountyChange {
+    Posted { position: Vector2D },
+    Expired,
+}
+
+// --- Bounty: every so often one asteroid in view is marked, and shooting it down in time pays out ---
+// The first bounty goes up BOUNTY_INTERVAL into the game and the next one that long after each is claimed or
+// runs out. None are posted while a boss is on the field; one already posted keeps its time.
+#[derive(Clone)]
+pub struct Bounty {
+    pub target: Option<(EntityId, Timer)>, // The marked asteroid and the time left to destroy it
+    next_posting: Option<Timer>,           // None until the first update schedules one
+}
+
+impl Bounty {
+    pub fn new() -> Self {
+        Bounty { target: None, next_posting: None }
+    }
+
+    // `in_view` says which asteroids are on screen; a bounty is only ever posted on one of those. The target's ID
+    // stops being live in `entity_ids` as soon as anything destroys it, so that is all the check needs.
+    pub fn update(&mut self, clock: &GameClock, asteroids: &[Asteroid], entity_ids: &EntityAllocator, in_view: impl Fn(&Asteroid) -> bool, boss_active: bool, rng: &mut impl Rng) -> Option<BountyChange> {
+        if let Some((id, timer)) = self.target {
+            if timer.is_running(clock) && entity_ids.is_alive(id) {
+                return None;
+            }
+            self.target = None;
+            self.next_posting = Some(clock.timer(BOUNTY_INTERVAL));
+            return Some(BountyChange::Expired);
+        }
+        let next_posting = *self.next_posting.get_or_insert_with(|| clock.timer(BOUNTY_INTERVAL));
+        if next_posting.is_running(clock) || boss_active {
+            return None;
+        }
+        let candidates: Vec<&Asteroid> = asteroids.iter().filter(|asteroid| in_view(asteroid)).collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        let marked = candidates[rng.gen_range(0..candidates.len())];
+        self.target = Some((marked.id, clock.timer(BOUNTY_TIME_LIMIT)));
+        Some(BountyChange::Posted { position: marked.position })
+    }
+
+    // Call for every asteroid destroyed by a shot or a bomb. Returns true if it was the bounty.
+    pub fn claim(&mut self, id: EntityId, clock: &GameClock) -> bool {
+        if self.target.is_none_or(|(target, _)| target != id) {
+            return false;
+        }
+        self.target = None;
+        self.next_posting = Some(clock.timer(BOUNTY_INTERVAL));
+        true
+    }
+}
+
+impl Default for Bounty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::types::Vector2D;
+
+// --- Camera: where the viewport sits in a wrapping world that can be larger than the screen ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Camera {
+    pub x: f64, // World position of the viewport's top-left corner
+    pub y: f64,
+}
+
+impl Camera {
+    // Centres the viewport on `target`; the world wraps, so the corner is kept inside it.
+    pub fn follow(target: Vector2D, viewport: (u16, u16), world: (u16, u16)) -> Self {
+        if viewport == world {
+            return Camera { x: 0.0, y: 0.0 };
+        }
+        Camera {
+            x: (target.x - viewport.0 as f64 / 2.0).rem_euclid(world.0.max(1) as f64),
+            y: (target.y - viewport.1 as f64 / 2.0).rem_euclid(world.1.max(1) as f64),
+        }
+    }
+
+    // Whether `position` is inside the viewport, across the world's seam too.
+    pub fn sees(&self, position: Vector2D, viewport: (u16, u16), world: (u16, u16)) -> bool {
+        let x = (position.x - self.x).rem_euclid(world.0.max(1) as f64);
+        let y = (position.y - self.y).rem_euclid(world.1.max(1) as f64);
+        x < viewport.0 as f64 && y < viewport.1 as f64
+    }
+
+    // Added to world cells to get viewport cells.
+    pub fn offset(&self) -> (i32, i32) {
+        (-(self.x.round() as i32), -(self.y.round() as i32))
+    }
+}
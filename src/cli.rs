@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::io;
+use crossterm::event::{Event, KeyCode};
+use log::info;
+
+use crate::assist::AssistSettings;
+use crate::constants::{FLEET_SHIPS, MAX_FPS, MAX_WORLD_SCALE, MIN_FPS, VERSUS_KILLS_TO_WIN};
+use crate::difficulty::{Difficulty, DIFFICULTIES};
+use crate::letterbox::Letterbox;
+use crate::mode_rules::Goal;
+use crate::profiler::Profiler;
+use crate::scoring::{ScoringMode, SCORING_MODES};
+use crate::terminal_guard;
+use crate::terminal_io;
+use crate::tournament::Ruleset;
+
+// --- Options: the command line, read and checked before the terminal goes into raw mode ---
+// A bad value is reported on a normal screen and exits straight away, so a typo never leaves the player in a
+// half-set-up terminal or in the middle of the onboarding wizard.
+pub struct Options {
+    pub ruleset: Option<Ruleset>, // --tournament
+    pub seed: Option<u64>,
+    pub practice: bool,
+    pub coop: bool,
+    pub versus: Option<u32>,   // Kills to win
+    pub fleet: Option<usize>,  // Ships
+    pub goal: Goal,
+    pub lives: Option<u32>,
+    pub profiler: Option<Profiler>,
+    pub letterbox: Option<Option<Letterbox>>, // Some(None) for --letterbox off
+    pub difficulty: Option<Difficulty>,
+    pub scoring: Option<ScoringMode>,
+    pub world_scale: Option<u16>,
+    pub fps: Option<u32>,
+    pub assist: AssistSettings,
+    pub practice_field: Option<(usize, usize, usize)>, // Large, medium and small asteroids
+    pub hires: bool,
+    pub stream: Option<String>,
+    pub record: Option<String>,
+}
+
+impl Options {
+    pub fn parse(args: &[String]) -> io::Result<Self> {
+        let ruleset = match args.iter().position(|arg| arg == "--tournament").and_then(|index| args.get(index + 1)) {
+            Some(path) => {
+                let ruleset = Ruleset::load(path)?;
+                if args.iter().any(|arg| arg.starts_with("--assist") || ["--idle-decay", "--lives", "--practice", "--coop", "--versus", "--fleet", "--target-score", "--waves"].contains(&arg.as_str())) {
+                    eprintln!("Tournament mode locks assist settings and mutators; remove --assist-*, --idle-decay, --lives, --practice, --coop, --versus, --fleet, --target-score and --waves.");
+                    terminal_guard::exit(1);
+                }
+                info!("Tournament ruleset {:016x} loaded from {}.", ruleset.hash(), path);
+                Some(ruleset)
+            }
+            None => None,
+        };
+
+        let seed = match args.iter().position(|arg| arg == "--seed").and_then(|index| args.get(index + 1)) {
+            Some(value) => match value.parse::<u64>() {
+                Ok(seed) if ruleset.is_none() => Some(seed),
+                Ok(_) => {
+                    eprintln!("Tournament mode takes its seed from the ruleset; remove --seed.");
+                    terminal_guard::exit(1);
+                }
+                Err(_) => {
+                    eprintln!("--seed expects a non-negative integer, got '{}'.", value);
+                    terminal_guard::exit(1);
+                }
+            },
+            None => None,
+        };
+
+        let practice = args.iter().any(|arg| arg == "--practice");
+        let coop = args.iter().any(|arg| arg == "--coop");
+        // --versus takes an optional kill target; the next argument is only it if it doesn't look like a flag
+        let versus = match args.iter().position(|arg| arg == "--versus") {
+            Some(index) => match args.get(index + 1).filter(|value| !value.starts_with("--")) {
+                Some(value) => match value.parse::<u32>() {
+                    Ok(kills) if kills > 0 => Some(kills),
+                    _ => {
+                        eprintln!("--versus expects a positive number of kills to win, got '{}'.", value);
+                        terminal_guard::exit(1);
+                    }
+                },
+                None => Some(VERSUS_KILLS_TO_WIN),
+            },
+            None => None,
+        };
+        if coop && versus.is_some() {
+            eprintln!("--coop and --versus are different two-player modes; pick one.");
+            terminal_guard::exit(1);
+        }
+        // --fleet takes an optional ship count, read the same way as --versus
+        let fleet = match args.iter().position(|arg| arg == "--fleet") {
+            Some(index) => match args.get(index + 1).filter(|value| !value.starts_with("--")) {
+                Some(value) => match value.parse::<usize>() {
+                    Ok(ships) if (2..=FLEET_SHIPS).contains(&ships) => Some(ships),
+                    _ => {
+                        eprintln!("--fleet expects 2 to {} ships, got '{}'.", FLEET_SHIPS, value);
+                        terminal_guard::exit(1);
+                    }
+                },
+                None => Some(FLEET_SHIPS),
+            },
+            None => None,
+        };
+        if fleet.is_some() && (coop || versus.is_some()) {
+            eprintln!("--fleet is flown by one player; remove --coop or --versus.");
+            terminal_guard::exit(1);
+        }
+
+        // Without a goal the run is endless
+        let target_score = args.iter().position(|arg| arg == "--target-score").and_then(|index| args.get(index + 1)).map(|value| match value.parse::<u32>() {
+            Ok(score) if score > 0 => Goal::TargetScore(score),
+            _ => {
+                eprintln!("--target-score expects a positive integer, got '{}'.", value);
+                terminal_guard::exit(1);
+            }
+        });
+        let clear_waves = args.iter().position(|arg| arg == "--waves").and_then(|index| args.get(index + 1)).map(|value| match value.parse::<usize>() {
+            Ok(waves) if waves > 0 => Goal::ClearWaves(waves),
+            _ => {
+                eprintln!("--waves expects a positive integer, got '{}'.", value);
+                terminal_guard::exit(1);
+            }
+        });
+        let goal = match (target_score, clear_waves) {
+            (Some(_), Some(_)) => {
+                eprintln!("A run has one goal; pick --target-score or --waves.");
+                terminal_guard::exit(1);
+            }
+            (Some(goal), None) | (None, Some(goal)) => goal,
+            (None, None) => Goal::Endless,
+        };
+        if versus.is_some() && goal != Goal::Endless {
+            eprintln!("Versus rounds are won on kills; remove --target-score or --waves.");
+            terminal_guard::exit(1);
+        }
+        if practice && args.iter().any(|arg| arg == "--record") {
+            eprintln!("Practice runs rewind the game and can't be recorded; remove --record or --practice.");
+            terminal_guard::exit(1);
+        }
+
+        let lives = match args.iter().position(|arg| arg == "--lives").and_then(|index| args.get(index + 1)) {
+            Some(value) => match value.parse::<u32>() {
+                Ok(lives) if lives > 0 => Some(lives),
+                _ => {
+                    eprintln!("--lives expects a positive integer, got '{}'.", value);
+                    terminal_guard::exit(1);
+                }
+            },
+            None => None,
+        };
+
+        let profiler = match args.iter().position(|arg| arg == "--profile-log") {
+            Some(index) => match args.get(index + 1).filter(|value| !value.starts_with("--")) {
+                Some(path) => Some(Profiler::with_log(path)?),
+                None => {
+                    eprintln!("--profile-log expects a file to write frame timings to.");
+                    terminal_guard::exit(1);
+                }
+            },
+            None => None,
+        };
+        let profiler = match args.iter().position(|arg| arg == "--profile-flame") {
+            Some(index) => match args.get(index + 1).filter(|value| !value.starts_with("--")) {
+                Some(path) => Some(profiler.unwrap_or_default().with_flame(path)),
+                None => {
+                    eprintln!("--profile-flame expects a file to write the folded-stack profile to.");
+                    terminal_guard::exit(1);
+                }
+            },
+            None => profiler,
+        };
+
+        let letterbox = args.iter().position(|arg| arg == "--letterbox").map(|index| match args.get(index + 1).map(String::as_str) {
+            Some("off") => None,
+            Some(size) if let Some(letterbox) = Letterbox::parse(size) => Some(letterbox),
+            _ => {
+                eprintln!("--letterbox expects a size like 160x48, or off.");
+                terminal_guard::exit(1);
+            }
+        });
+        let difficulty = args.iter().position(|arg| arg == "--difficulty").map(|index| {
+            let name = args.get(index + 1).map_or("", String::as_str);
+            Difficulty::parse(name).unwrap_or_else(|| {
+                let names: Vec<&str> = DIFFICULTIES.iter().map(|difficulty| difficulty.name()).collect();
+                eprintln!("Unknown difficulty '{}'; choose one of: {}.", name, names.join(", "));
+                terminal_guard::exit(1);
+            })
+        });
+        let scoring = args.iter().position(|arg| arg == "--scoring").map(|index| {
+            let name = args.get(index + 1).map_or("", String::as_str);
+            ScoringMode::parse(name).unwrap_or_else(|| {
+                let names: Vec<&str> = SCORING_MODES.iter().map(|mode| mode.name()).collect();
+                eprintln!("Unknown scoring rules '{}'; choose one of: {}.", name, names.join(", "));
+                terminal_guard::exit(1);
+            })
+        });
+        let world_scale = args.iter().position(|arg| arg == "--world-scale").map(|index| match args.get(index + 1).and_then(|value| value.parse::<u16>().ok()) {
+            Some(scale) if (1..=MAX_WORLD_SCALE).contains(&scale) => scale,
+            _ => {
+                eprintln!("--world-scale expects a number from 1 to {}.", MAX_WORLD_SCALE);
+                terminal_guard::exit(1);
+            }
+        });
+        let fps = args.iter().position(|arg| arg == "--fps").map(|index| match args.get(index + 1).and_then(|value| value.parse::<u32>().ok()) {
+            Some(fps) if (MIN_FPS..=MAX_FPS).contains(&fps) => fps,
+            _ => {
+                eprintln!("--fps expects a frame rate from {} to {}.", MIN_FPS, MAX_FPS);
+                terminal_guard::exit(1);
+            }
+        });
+        let assist = AssistSettings::from_args(args).unwrap_or_else(|message| {
+            eprintln!("{}", message);
+            terminal_guard::exit(1);
+        });
+        let practice_field = args.iter().position(|arg| arg == "--practice-field").map(|index| {
+            let counts: Vec<Option<usize>> = args.get(index + 1).map_or(Vec::new(), |value| value.split(',').map(|count| count.parse().ok()).collect());
+            let (true, [Some(large), Some(medium), Some(small)]) = (practice, &counts[..]) else {
+                eprintln!("--practice-field expects large,medium,small asteroid counts (e.g. 4,2,0) and --practice.");
+                terminal_guard::exit(1);
+            };
+            (*large, *medium, *small)
+        });
+
+        let stream = args.iter().position(|arg| arg == "--stream").and_then(|index| args.get(index + 1)).cloned();
+        #[cfg(not(feature = "score-stream"))]
+        if let Some(path) = &stream {
+            eprintln!("--stream {}: this build was made without the score-stream feature.", path);
+            terminal_guard::exit(1);
+        }
+        let record = args.iter().position(|arg| arg == "--record").and_then(|index| args.get(index + 1)).cloned();
+
+        Ok(Options {
+            ruleset,
+            seed,
+            practice,
+            coop,
+            versus,
+            fleet,
+            goal,
+            lives,
+            profiler,
+            letterbox,
+            difficulty,
+            scoring,
+            world_scale,
+            fps,
+            assist,
+            practice_field,
+            hires: args.iter().any(|arg| arg == "--hires"),
+            stream,
+            record,
+        })
+    }
+
+    // The seed to play: the tournament's, then --seed, then `fallback`.
+    pub fn seed_or(&self, fallback: impl FnOnce() -> u64) -> u64 {
+        self.ruleset.map(|ruleset| ruleset.seed).or(self.seed).unwrap_or_else(fallback)
+    }
+}
+
+// --minutes for --bot and preview runs.
+pub fn minutes(args: &[String], default: u64) -> u64 {
+    match args.iter().position(|arg| arg == "--minutes").and_then(|index| args.get(index + 1)) {
+        Some(value) => value.parse::<u64>().unwrap_or_else(|_| {
+            eprintln!("--minutes expects a non-negative integer, got '{}'.", value);
+            terminal_guard::exit(1);
+        }),
+        None => default,
+    }
+}
+
+// The frame count after --bench, or None when this isn't a benchmark run.
+pub fn bench_frames(args: &[String]) -> Option<u64> {
+    let index = args.iter().position(|arg| arg == "--bench")?;
+    match args.get(index + 1).map(|value| value.parse::<u64>()) {
+        Some(Ok(frames)) if frames > 0 => Some(frames),
+        _ => {
+            eprintln!("--bench expects a positive number of frames.");
+            terminal_guard::exit(1);
+        }
+    }
+}
+
+// `--debug [width height [frames]]` or `--debug --preset <name>`: the simulated screen size.
+pub fn debug_screen(args: &[String]) -> (u16, u16) {
+    if let Some(name) = args.iter().position(|arg| arg == "--preset").and_then(|index| args.get(index + 1)) {
+        return terminal_io::debug_preset(name).unwrap_or_else(|| {
+            let names: Vec<&str> = terminal_io::DEBUG_PRESETS.iter().map(|(name, _, _)| *name).collect();
+            eprintln!("Unknown debug preset '{}'; choose one of: {}.", name, names.join(", "));
+            terminal_guard::exit(1);
+        });
+    }
+    if args.len() >= 4 {
+        return (args[2].parse::<u16>().unwrap_or(80), args[3].parse::<u16>().unwrap_or(24));
+    }
+    (80, 24)
+}
+
+// The scripted input of a debug run: a few ship controls, a quit after 10 frames and any --resize events.
+pub fn debug_events(args: &[String]) -> HashMap<u64, Event> {
+    let mut sim_events = HashMap::new();
+    sim_events.insert(1, Event::Key(KeyCode::Up.into()));
+    sim_events.insert(2, Event::Key(KeyCode::Right.into()));
+    sim_events.insert(3, Event::Key(KeyCode::Char(' ').into()));
+    sim_events.insert(4, Event::Key(KeyCode::Left.into()));
+    sim_events.insert(10, Event::Key(KeyCode::Char('q').into())); // Quit after 10 frames
+    // --resize <frame> <width> <height>, repeatable: simulate the terminal window changing size mid-run
+    for (index, _) in args.iter().enumerate().filter(|(_, arg)| *arg == "--resize") {
+        let frame = args.get(index + 1).and_then(|value| value.parse::<u64>().ok());
+        let size: Vec<Option<u16>> = (2..=3).map(|offset| args.get(index + offset).and_then(|value| value.parse().ok())).collect();
+        let (Some(frame), [Some(width), Some(height)]) = (frame, &size[..]) else {
+            eprintln!("--resize expects a frame, then a width and a height of at most {}.", u16::MAX);
+            terminal_guard::exit(1);
+        };
+        info!("Simulated resize to {}x{} at frame {}.", width, height, frame);
+        sim_events.insert(frame, Event::Resize(*width, *height));
+    }
+    sim_events
+}
+
+// The frame limit: the first argument of a normal run, or the fourth after --debug.
+pub fn max_frames(args: &[String], debug_mode_active: bool) -> Option<u64> {
+    if !debug_mode_active && args.len() > 1 {
+        args[1].parse::<u64>().ok()
+    } else if debug_mode_active && args.len() > 4 {
+        args[4].parse::<u64>().ok()
+    } else {
+        None
+    }
+}
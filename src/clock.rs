@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::constants::{MAX_TICKS_PER_FRAME, SIMULATION_TICK};
+use crate::time_scale::TimeScale;
+
+// Number of whole simulation ticks covering `duration` (rounded up, so short durations still last a tick).
+pub fn ticks_for(duration: Duration) -> u64 {
+    duration.as_nanos().div_ceil(SIMULATION_TICK.as_nanos()) as u64
+}
+
+// Length of one simulation tick in seconds, the `dt` handed to entity updates.
+pub fn tick_seconds() -> f64 {
+    SIMULATION_TICK.as_secs_f64()
+}
+
+// --- Where the number of ticks per frame comes from ---
+enum StepSource {
+    RealTime { last_frame_at: Instant, accumulator: Duration }, // Interactive play: follow the wall clock
+    Fixed,                                                       // Debug runs: one tick per rendered frame
+    Scripted(HashMap<u64, u32>),                                 // Replays: the counts recorded in play (1 if absent)
+}
+
+// --- GameClock: rendered frames, simulation ticks and pause-aware wall time ---
+pub struct GameClock {
+    frame: u64, // Rendered frames, paused or not (input playback, visual effects)
+    tick: u64,  // Simulation steps actually run (stops while paused, slows in bullet time)
+    step_source: StepSource,
+    paused: bool,
+    time_scale: TimeScale,
+    started_at: Instant,
+    paused_at: Option<Instant>,
+    paused_total: Duration,
+}
+
+impl GameClock {
+    pub fn new() -> Self {
+        GameClock {
+            frame: 0,
+            tick: 0,
+            step_source: StepSource::RealTime { last_frame_at: Instant::now(), accumulator: Duration::ZERO },
+            paused: false,
+            time_scale: TimeScale::new(),
+            started_at: Instant::now(),
+            paused_at: None,
+            paused_total: Duration::ZERO,
+        }
+    }
+
+    // Deterministic clock for debug runs and replay verification.
+    pub fn fixed() -> Self {
+        GameClock { step_source: StepSource::Fixed, ..Self::new() }
+    }
+
+    pub fn scripted(steps: HashMap<u64, u32>) -> Self {
+        GameClock { step_source: StepSource::Scripted(steps), ..Self::new() }
+    }
+
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    // Number of simulation steps to run during the current frame.
+    pub fn steps_this_frame(&mut self) -> u32 {
+        match &mut self.step_source {
+            StepSource::RealTime { last_frame_at, accumulator } => {
+                let now = Instant::now();
+                let elapsed = now - *last_frame_at;
+                *last_frame_at = now;
+                if self.paused {
+                    return 0;
+                }
+                *accumulator += elapsed.mul_f64(self.time_scale.scale(self.tick));
+                let steps = (accumulator.as_nanos() / SIMULATION_TICK.as_nanos()) as u32;
+                if steps > MAX_TICKS_PER_FRAME {
+                    // Too far behind (a stall or a suspended terminal): drop the backlog instead of fast-forwarding
+                    *accumulator = Duration::ZERO;
+                    return MAX_TICKS_PER_FRAME;
+                }
+                *accumulator -= SIMULATION_TICK * steps;
+                steps
+            }
+            _ if self.paused => 0,
+            StepSource::Fixed => self.time_scale.steps_for_frame(self.tick),
+            StepSource::Scripted(steps) => steps.get(&self.frame).copied().unwrap_or(1),
+        }
+    }
+
+    pub fn advance_tick(&mut self) {
+        self.tick += 1;
+    }
+
+    // Practice checkpoints rewind the simulation; timers are tick-based, so they rewind with it.
+    pub fn restore_tick(&mut self, tick: u64) {
+        self.tick = tick;
+    }
+
+    // A quick-loaded game carries on numbering frames from where it was saved, as its recorded input does.
+    pub fn restore_frame(&mut self, frame: u64) {
+        self.frame = frame;
+    }
+
+    pub fn end_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    pub fn pause(&mut self) {
+        if !self.paused {
+            self.paused = true;
+            self.paused_at = Some(Instant::now());
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused_total += paused_at.elapsed();
+        }
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    // Slow motion covers `duration` of game time, which plays out over `duration / scale` on screen.
+    pub fn slow_down(&mut self, scale: f64, duration: Duration) {
+        self.time_scale.slow_down(scale, self.tick, ticks_for(duration));
+    }
+
+    // Wall time spent playing, excluding pauses.
+    pub fn elapsed(&self) -> Duration {
+        let paused_now = self.paused_at.map(|paused_at| paused_at.elapsed()).unwrap_or(Duration::ZERO);
+        self.started_at.elapsed().saturating_sub(self.paused_total + paused_now)
+    }
+
+    pub fn timer(&self, duration: Duration) -> Timer {
+        Timer { expires_at_tick: self.tick + ticks_for(duration) }
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- Timer: a countdown measured in simulation ticks ---
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timer {
+    expires_at_tick: u64,
+}
+
+impl Timer {
+    pub fn is_running(&self, clock: &GameClock) -> bool {
+        clock.tick < self.expires_at_tick
+    }
+
+    pub fn remaining(&self, clock: &GameClock) -> u64 {
+        self.expires_at_tick.saturating_sub(clock.tick)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::entities::Asteroid;
+use crate::spatial::SpatialHash;
+use crate::types::Vector2D;
+
+// Bounces overlapping asteroids off each other as equal-density discs. The spatial hash keeps this
+// to neighbouring buckets, so the cost grows with the number of asteroids rather than pairs of them.
+pub fn resolve_asteroid_collisions(asteroids: &mut [Asteroid], spatial_hash: &mut SpatialHash) {
+    spatial_hash.clear();
+    for (index, asteroid) in asteroids.iter().enumerate() {
+        spatial_hash.insert(index, asteroid.position);
+    }
+
+    // Broad phase: pairs in the same or adjacent buckets (buckets are at least as big as two radii)
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for (index, asteroid) in asteroids.iter().enumerate() {
+        let (column, row) = spatial_hash.cell_of(asteroid.position);
+        for neighbour_row in row.saturating_sub(1)..=(row + 1).min(spatial_hash.rows() - 1) {
+            for neighbour_column in column.saturating_sub(1)..=(column + 1).min(spatial_hash.columns() - 1) {
+                for &other in spatial_hash.indices_at(neighbour_column, neighbour_row) {
+                    if other > index {
+                        pairs.push((index, other));
+                    }
+                }
+            }
+        }
+    }
+
+    // Narrow phase: separate overlapping discs and exchange momentum along the line between centres
+    for (a, b) in pairs {
+        let (first, second) = (&asteroids[a], &asteroids[b]);
+        let offset = Vector2D::new(second.position.x - first.position.x, second.position.y - first.position.y);
+        let distance = (offset.x * offset.x + offset.y * offset.y).sqrt();
+        let overlap = first.size.radius() + second.size.radius() - distance;
+        if overlap <= 0.0 {
+            continue;
+        }
+        let normal = if distance > f64::EPSILON { offset.scale(1.0 / distance) } else { Vector2D::new(1.0, 0.0) };
+        let (mass_a, mass_b) = (first.size.mass(), second.size.mass());
+        let total_mass = mass_a + mass_b;
+
+        let approach_speed = (first.velocity.x - second.velocity.x) * normal.x + (first.velocity.y - second.velocity.y) * normal.y;
+        let impulse = if approach_speed > 0.0 { 2.0 * approach_speed / total_mass } else { 0.0 };
+
+        let first = &mut asteroids[a];
+        first.position = first.position.add(normal.scale(-overlap * mass_b / total_mass));
+        first.velocity = first.velocity.add(normal.scale(-impulse * mass_b));
+        let second = &mut asteroids[b];
+        second.position = second.position.add(normal.scale(overlap * mass_a / total_mass));
+        second.velocity = second.velocity.add(normal.scale(impulse * mass_a));
+    }
+}
@@ -0,0 +1,159 @@
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::analog::{AnalogSettings, ResponseCurve};
+use crate::constants::{DEFAULT_EFFECTS_VOLUME, DEFAULT_MUSIC_VOLUME, MAX_DEAD_ZONE, MAX_SCREEN_SHAKE, MAX_SENSITIVITY, MIN_SENSITIVITY};
+use crate::difficulty::Difficulty;
+use crate::letterbox::Letterbox;
+use crate::rendering::Corner;
+
+const CONFIG_HEADER: &str = "vibe-asteroid-config 1";
+
+// --- Config: terminal capabilities detected by the onboarding wizard ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    pub color: bool,       // Terminal renders foreground colours
+    pub unicode: bool,     // Glyphs like ● ░ ┌ render; otherwise ASCII fallbacks are used
+    pub key_release: bool, // Terminal reports key releases (keyboard enhancement protocol)
+    pub gamepad: bool,     // A joystick device was present during setup
+    pub dampeners: bool,   // Flight model preference: false for classic Newtonian drift
+    pub difficulty: Difficulty,
+    pub minimap: bool,
+    pub minimap_corner: Corner,
+    pub screen_shake: u8,  // 0 (off) to MAX_SCREEN_SHAKE
+    pub hit_flash: bool,   // Invert the screen for a moment when the ship takes damage
+    pub letterbox: Option<Letterbox>, // Largest playfield to draw; bigger terminals get a centred, framed one
+    pub outlines: bool,    // Dim ring around the ship and large asteroids so they stand out from busy backgrounds
+    pub analog: AnalogSettings, // Gamepad stick dead zone, response curve and sensitivity
+    pub hires: bool,       // Plot ships, asteroids and shots in Braille dots (needs `unicode`)
+    pub music_volume: u8,  // Percent
+    pub effects_volume: u8, // Percent
+}
+
+impl Config {
+    // Location of the profile: $XDG_CONFIG_HOME/vibe-asteroid/config or ~/.config/vibe-asteroid/config.
+    pub fn path() -> Option<PathBuf> {
+        let base = match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+        Some(base.join("vibe-asteroid").join("config"))
+    }
+
+    // Returns None when there is no profile yet (first launch).
+    pub fn load() -> io::Result<Option<Self>> {
+        let Some(path) = Self::path() else {
+            return Ok(None);
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let mut lines = contents.lines();
+        if lines.next() != Some(CONFIG_HEADER) {
+            return Err(invalid_config("missing config header"));
+        }
+
+        let mut config = Config::default();
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["color", value] => config.color = parse_flag(value)?,
+                ["unicode", value] => config.unicode = parse_flag(value)?,
+                ["key-release", value] => config.key_release = parse_flag(value)?,
+                ["gamepad", value] => config.gamepad = parse_flag(value)?,
+                ["dampeners", value] => config.dampeners = parse_flag(value)?,
+                ["difficulty", name] => config.difficulty = Difficulty::parse(name).ok_or_else(|| invalid_config("unknown difficulty"))?,
+                ["minimap", value] => config.minimap = parse_flag(value)?,
+                ["minimap-corner", name] => config.minimap_corner = Corner::parse(name).ok_or_else(|| invalid_config("unknown minimap corner"))?,
+                ["screen-shake", value] => {
+                    config.screen_shake = value.parse::<u8>().map_err(|_| invalid_config("expected a shake level"))?.min(MAX_SCREEN_SHAKE);
+                }
+                ["hit-flash", value] => config.hit_flash = parse_flag(value)?,
+                ["letterbox", "off"] => config.letterbox = None,
+                ["outlines", value] => config.outlines = parse_flag(value)?,
+                ["hires", value] => config.hires = parse_flag(value)?,
+                ["music-volume", value] => config.music_volume = parse_volume(value)?,
+                ["effects-volume", value] => config.effects_volume = parse_volume(value)?,
+                ["dead-zone", value] => {
+                    config.analog.dead_zone = value.parse::<u8>().map_err(|_| invalid_config("expected a dead zone percentage"))?.min(MAX_DEAD_ZONE);
+                }
+                ["response-curve", name] => config.analog.curve = ResponseCurve::parse(name).ok_or_else(|| invalid_config("unknown response curve"))?,
+                ["sensitivity", value] => {
+                    let sensitivity = value.parse::<u8>().map_err(|_| invalid_config("expected a sensitivity percentage"))?;
+                    config.analog.sensitivity = sensitivity.clamp(MIN_SENSITIVITY, MAX_SENSITIVITY);
+                }
+                ["letterbox", size] => config.letterbox = Some(Letterbox::parse(size).ok_or_else(|| invalid_config("expected a letterbox size like 160x48"))?),
+                _ => {} // Keys from newer versions are ignored rather than rejected
+            }
+        }
+        Ok(Some(config))
+    }
+
+    pub fn save(&self) -> io::Result<PathBuf> {
+        let path = Self::path().ok_or_else(|| invalid_config("no home directory for the config profile"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(&path)?;
+        writeln!(file, "{}", CONFIG_HEADER)?;
+        writeln!(file, "color {}", self.color)?;
+        writeln!(file, "unicode {}", self.unicode)?;
+        writeln!(file, "key-release {}", self.key_release)?;
+        writeln!(file, "gamepad {}", self.gamepad)?;
+        writeln!(file, "dampeners {}", self.dampeners)?;
+        writeln!(file, "difficulty {}", self.difficulty.name())?;
+        writeln!(file, "minimap {}", self.minimap)?;
+        writeln!(file, "minimap-corner {}", self.minimap_corner.name())?;
+        writeln!(file, "screen-shake {}", self.screen_shake)?;
+        writeln!(file, "hit-flash {}", self.hit_flash)?;
+        writeln!(file, "outlines {}", self.outlines)?;
+        writeln!(file, "hires {}", self.hires)?;
+        writeln!(file, "music-volume {}", self.music_volume)?;
+        writeln!(file, "effects-volume {}", self.effects_volume)?;
+        writeln!(file, "dead-zone {}", self.analog.dead_zone)?;
+        writeln!(file, "response-curve {}", self.analog.curve.name())?;
+        writeln!(file, "sensitivity {}", self.analog.sensitivity)?;
+        writeln!(file, "letterbox {}", self.letterbox.map_or("off".to_string(), |letterbox| letterbox.name()))?;
+        Ok(path)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            color: true,
+            unicode: true,
+            key_release: false,
+            gamepad: false,
+            dampeners: true,
+            difficulty: Difficulty::Normal,
+            minimap: true,
+            minimap_corner: Corner::NorthEast,
+            screen_shake: 1,
+            hit_flash: false,
+            letterbox: None,
+            outlines: false,
+            analog: AnalogSettings::new(),
+            hires: false,
+            music_volume: DEFAULT_MUSIC_VOLUME,
+            effects_volume: DEFAULT_EFFECTS_VOLUME,
+        }
+    }
+}
+
+fn parse_flag(value: &str) -> io::Result<bool> {
+    value.parse().map_err(|_| invalid_config("expected true or false"))
+}
+
+fn parse_volume(value: &str) -> io::Result<u8> {
+    Ok(value.parse::<u8>().map_err(|_| invalid_config("expected a volume percentage"))?.min(100))
+}
+
+fn invalid_config(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -1,29 +1,230 @@
+use std::time::Duration;
+use crossterm::style::Color;
+
 // --- Game Constants ---
-pub const INITIAL_ASTEROID_SPAWN_RATE: u64 = 100; // Frames per asteroid spawn
-pub const INITIAL_MAX_ASTEROIDS: usize = 4;
-pub const DIFFICULTY_INCREASE_INTERVAL_FRAMES: u64 = 60 * 60; // Every 60 seconds (assuming 60 FPS)
-pub const ASTEROID_SPAWN_RATE_DECREASE_FACTOR: f64 = 0.9; // Decrease spawn rate by 10%
-pub const MIN_ASTEROID_SPAWN_RATE: u64 = 10;
+pub const SIMULATION_TICK: Duration = Duration::from_millis(50); // Fixed physics step; velocities are in cells per tick
+pub const MAX_TICKS_PER_FRAME: u32 = 5; // Catch-up limit after a stall
+pub const DEFAULT_FPS: u32 = 30; // Live play is paced to this many frames per second unless --fps says otherwise
+pub const MIN_FPS: u32 = 10;
+pub const MAX_FPS: u32 = 120;
+pub const TITLE_POLL_INTERVAL: Duration = Duration::from_millis(250); // The title screen checks for idleness this often
+pub const SUBMISSION_POLL_INTERVAL: Duration = Duration::from_millis(50); // The game over screen checks on the high score save this often
+pub const ATTRACT_IDLE: Duration = Duration::from_secs(15); // Idle this long on the title menu to start the demo
+pub const CREDITS_SCROLL_SPEED: f64 = 3.0; // Rows per second the About screen's credits roll up
+pub const CREDITS_SCROLL_STEP: f64 = 2.0; // Rows Up and Down move the roll by
+pub const AUTOPILOT_AIM_TOLERANCE: f64 = 0.1; // Radians off target before the autopilot turns
+pub const AUTOPILOT_FIRE_ARC: f64 = 0.25; // Radians off target it still fires within
+pub const AUTOPILOT_CHASE_DISTANCE: f64 = 15.0; // Cells; it thrusts towards targets further away than this
+pub const GAMEPAD_DEVICE: &str = "/dev/input/js0";
+pub const DEFAULT_DEAD_ZONE: u8 = 20; // Percent of a stick's travel ignored around the centre
+pub const MAX_DEAD_ZONE: u8 = 50;
+pub const DEAD_ZONE_STEP: u8 = 5;
+pub const DEFAULT_SENSITIVITY: u8 = 100; // Percent of full rotation speed at full stick travel
+pub const MIN_SENSITIVITY: u8 = 50;
+pub const MAX_SENSITIVITY: u8 = 200;
+pub const SENSITIVITY_STEP: u8 = 10;
+pub const DEFAULT_MUSIC_VOLUME: u8 = 80; // Percent
+pub const DEFAULT_EFFECTS_VOLUME: u8 = 100; // Percent
+pub const MUSIC_CROSSFADE: Duration = Duration::from_secs(2); // Switching between the wave and boss tracks
+pub const DUCKED_MUSIC_GAIN: f64 = 0.3; // Share of the music volume left while it is ducked
+pub const DUCK_BOSS_WARNING: Duration = Duration::from_millis(2500); // Music stays ducked under a boss warning this long
+pub const DUCK_EXPLOSION: Duration = Duration::from_millis(400);
+pub const DUCK_RELEASE: Duration = Duration::from_millis(300); // The end of a duck fades back up over this long
+pub const GYM_NEAREST_ASTEROIDS: usize = 8; // Asteroids described in each gym observation
+
+pub const WAVE_BASE_ASTEROIDS: usize = 4; // Large asteroids in the first wave
+pub const WAVE_ASTEROID_INCREMENT: usize = 1; // Extra large asteroids per wave
+pub const WAVE_INTERSTITIAL: Duration = Duration::from_secs(2); // "WAVE N" banner before a wave starts spawning
+pub const INITIAL_ASTEROID_SPAWN_INTERVAL: Duration = Duration::from_secs(5);
+pub const ASTEROID_SPAWN_RATE_DECREASE_FACTOR: f64 = 0.9; // Each wave spawns 10% faster
+pub const MIN_ASTEROID_SPAWN_INTERVAL: Duration = Duration::from_millis(500);
 pub const INITIAL_GAME_SPEED_MULTIPLIER: f64 = 0.1;
-pub const GAME_SPEED_MULTIPLIER_INCREASE: f64 = 0.05;
+pub const GAME_SPEED_MULTIPLIER_INCREASE: f64 = 0.05; // Per wave
+pub const SPAWN_BIAS_BY_WAVE: [f64; 5] = [0.9, 0.8, 0.7, 0.6, 0.5]; // Chance a formation planned in the ship's quadrant is moved away; the last entry applies to later waves
 
 pub const SHIP_ROTATION_SPEED: f64 = 0.1;
 pub const SHIP_THRUST_POWER: f64 = 0.05;
 pub const SHIP_FRICTION: f64 = 0.98;
+pub const NEWTONIAN_FRICTION: f64 = 1.0; // Dampeners off: the ship drifts until thrust counters it
 pub const SHIP_ANGULAR_FRICTION: f64 = 0.9;
 
+pub const SHIP_COLOR: Color = Color::Cyan;
+pub const PLAYER_TWO_SHIP_COLOR: Color = Color::Blue; // Co-op: the second ship and its shots
+pub const PLAYER_TWO_BULLET_COLOR: Color = Color::DarkCyan;
+pub const ASTEROID_COLOR: Color = Color::Grey;
+pub const BULLET_COLOR: Color = Color::Yellow;
+pub const UPGRADE_COLOR: Color = Color::Green;
+
+pub const DEATH_MAP_COLOR: Color = Color::DarkRed;
+pub const OUTLINE_COLOR: Color = Color::DarkGrey;
+pub const OUTLINE_CHAR: char = '░';
+
+pub const SAUCER_COLOR: Color = Color::Magenta;
+pub const SAUCER_BULLET_COLOR: Color = Color::Red;
+
 pub const BULLET_SPEED: f64 = 2.0;
-pub const BULLET_LIFETIME: u32 = 30; // Frames
+pub const BULLET_LIFETIME: Duration = Duration::from_millis(1500);
+
+pub const SAUCER_SPAWN_INTERVAL: Duration = Duration::from_secs(30);
+pub const SAUCER_LIFETIME: Duration = Duration::from_secs(15); // Then it leaves the field
+pub const SAUCER_SPEED: f64 = 0.4; // Cells per tick
+pub const SAUCER_TRACKING: f64 = 0.05; // How quickly it turns towards the ship (0-1 per tick)
+pub const SAUCER_FIRE_INTERVAL: Duration = Duration::from_millis(1500);
+pub const SAUCER_AIM_ERROR: f64 = 0.25; // Radians of inaccuracy on each shot
+pub const SAUCER_BULLET_SPEED: f64 = 1.0;
+pub const SAUCER_BULLET_LIFETIME: Duration = Duration::from_secs(2);
+pub const SCORE_SAUCER: u32 = 500;
+
+pub const BOSS_WAVE_INTERVAL: usize = 5; // Every fifth wave brings a boss
+pub const BOSS_RADIUS: i32 = 2; // Rows from the centre; twice as many columns
+pub const BOSS_SEGMENT_HITS: u32 = 1; // Hits each body segment takes before it breaks off
+pub const BOSS_SPEED: f64 = 0.15;
+pub const BOSS_RING_ASTEROIDS: usize = 6; // Large asteroids it shatters into
+pub const BOSS_UPGRADE_DROPS: usize = 3; // Guaranteed upgrades when it is destroyed
+pub const BOSS_HEALTH_BAR_WIDTH: usize = 10;
+pub const BOSS_COLOR: Color = Color::DarkYellow;
+pub const SCORE_BOSS: u32 = 2000;
+
+pub const SPLIT_ANGLE_JITTER: f64 = 0.35; // Radians of randomness around the perpendicular split
 
 pub const SCORE_LARGE_ASTEROID: u32 = 20;
 pub const SCORE_MEDIUM_ASTEROID: u32 = 50;
 pub const SCORE_SMALL_ASTEROID: u32 = 100;
+pub const COMBO_WINDOW: Duration = Duration::from_secs(2); // Combo scoring: longest gap between kills that keeps the chain
+pub const COMBO_MAX_MULTIPLIER: u32 = 5;
+pub const TIME_WEIGHT_BONUS_PER_MINUTE: f64 = 0.25; // Time-weighted scoring: extra share of base points per minute survived
 
-pub const BULLET_COOLDOWN: u64 = 10; // Frames between shots
+pub const MAX_FIRE_RATE_MULTIPLIER: f64 = 2.0;
+pub const MAX_BULLET_SPEED_MULTIPLIER: f64 = 2.0;
+pub const MAX_BOOSTER_MULTIPLIER: f64 = 1.5;
+pub const UPGRADE_HEADROOM_STEP: f64 = 0.1; // Each pickup closes 10% of the gap to the cap (x1.10 for the first fire-rate pickup)
+pub const BULLET_RANGE_PER_UPGRADE: f64 = 0.2; // Extra flight time per range upgrade
+pub const MAX_BULLET_RANGE_MULTIPLIER: f64 = 2.0;
+pub const BULLET_DAMAGE: f64 = 1.0; // Damage of a shot at x1.0 shot size; shot-size upgrades scale it
+pub const MAX_PIERCE: u32 = 3; // Kills a shot can pass through, one per piercing upgrade
+pub const MAX_BOMBS: u32 = 3; // Bombs a ship can carry; each upgrade box broken open adds one
+pub const SHOCKWAVE_RADIUS: f64 = 15.0; // Rows a bomb's shockwave reaches before it dies out
+pub const SHOCKWAVE_DURATION: Duration = SIMULATION_TICK.saturating_mul(20); // 20 ticks to reach full size
+pub const SHOCKWAVE_DAMAGE: f64 = 2.0; // Breaks small and medium asteroids; large ones survive and are pushed
+pub const SHOCKWAVE_PUSH: f64 = 0.6; // Speed (cells per tick) added to a survivor, straight away from the bomb
+pub const SHOCKWAVE_CHAR: char = 'o';
+pub const SHOCKWAVE_COLOR: Color = Color::White;
+pub const TRIPLE_SHOT_SPREAD: f64 = 0.26; // Radians either side of the forward shot (about 15 degrees)
+pub const RADIAL_BURST_EVERY: u32 = 4; // The radial burst goes off on every fourth volley
+pub const RADIAL_BURST_SHOTS: u32 = 8; // Shots in a full ring, forward one included
+pub const BULLET_COOLDOWN: Duration = Duration::from_millis(500); // Time between shots
+pub const MIN_SHOT_COOLDOWN: Duration = Duration::from_millis(100); // However fire rate is raised, shots never come faster
 pub const MAX_HEALTH: u32 = 1;
 pub const UPGRADE_COLLECTION_RADIUS: f64 = 2.0; // Ship can collect upgrade within this radius
+pub const LOADOUT_FAVOUR_WEIGHT: u32 = 3; // A loadout's favoured upgrades drop this many times as often as the others
+pub const MAX_LOADOUT_START: usize = 3; // Upgrades a loadout can launch with, so a preset can't stack its way up the score table
+pub const JETTISON_DISTANCE: f64 = 4.0; // Rows behind the ship a jettisoned upgrade lands, clear of the collection radius
 pub const TERMINAL_ASPECT_RATIO_COMPENSATION: f64 = 2.0; // Adjust this based on terminal character aspect ratio (height/width)
 
-pub const INVINCIBILITY_FRAMES: u64 = 60 * 2; // 2 seconds of invincibility
+pub const INVINCIBILITY_DURATION: Duration = Duration::from_secs(2);
+pub const INVINCIBILITY_BLINK: Duration = Duration::from_millis(100); // The ship flashes on and off at this rate while invincible
+
+pub const STARTING_LIVES: u32 = 3;
+pub const RESPAWN_DELAY: Duration = Duration::from_secs(1); // Minimum time out of play after losing a life
+pub const RESPAWN_CLEAR_RADIUS: f64 = 6.0; // Rows around the centre that must be free of asteroids
+pub const COOP_SPAWN_SPACING: f64 = 8.0; // Two-player ships start this many columns either side of the centre
+pub const VERSUS_KILLS_TO_WIN: u32 = 5; // --versus without a count
+pub const FLEET_SHIPS: usize = 3; // --fleet without a count; also the most it allows
+pub const FLEET_SPACING_X: f64 = 6.0; // Columns between neighbouring ranks of the fleet's V
+pub const FLEET_SPACING_Y: f64 = 3.0; // Rows each rank trails the one ahead
+
+pub const SHIP_ENERGY_CAPACITY: f64 = 100.0;
+pub const ENERGY_REGEN_PER_SECOND: f64 = 10.0;
+pub const ENERGY_CAPACITY_PER_UPGRADE: f64 = 25.0;
+pub const MAX_ENERGY_CAPACITY: f64 = 200.0;
+pub const ENERGY_BAR_WIDTH: usize = 10; // Cells in the HUD gauge
+pub const HEALTH_BAR_WIDTH: usize = 10;
+pub const PRESSURE_BAR_WIDTH: usize = 10;
+
+pub const HYPERSPACE_ENERGY_COST: f64 = 50.0; // Five seconds of regeneration
+pub const HYPERSPACE_FAILURE_CHANCE: f64 = 0.05; // Chance a jump destroys the ship
+pub const HYPERSPACE_SAFE_DISTANCE: f64 = 6.0; // Rows of clearance from the nearest asteroid
+pub const FIELD_CLEAR_RADIUS: f64 = 6.0; // Rows kept clear around the ship's start in a generated field
+pub const FIELD_PLACEMENT_ATTEMPTS: usize = 20;
+pub const HYPERSPACE_ATTEMPTS: usize = 20; // Random destinations tried before settling for the roomiest
+pub const HYPERSPACE_WARP_PARTICLES: usize = 12;
+pub const COSMETIC_SEED_SALT: u64 = 0x9e37_79b9_7f4a_7c15; // Mixed into the run seed for the effects-only RNG stream
+
+pub const MAX_SCREEN_SHAKE: u8 = 3; // Settings levels: off, low, medium, high
+
+pub const BANNER_DURATION: Duration = Duration::from_secs(1);
+
+pub const UPGRADE_BOX_SPAWN_INTERVAL: Duration = Duration::from_secs(10);
+
+pub const PREVIEW_MINUTES: u64 = 3; // Default length of a `preview` simulation
+pub const BOT_MINUTES: u64 = 10; // --bot: longest game time a run plays before it is stopped
+pub const BOT_WIDTH: u16 = 80; // --bot plays on a standard terminal-sized field
+pub const BOT_HEIGHT: u16 = 24;
+pub const BENCH_SEED: u64 = 1; // --bench plays the same field every time unless given --seed, so runs compare
+pub const BENCH_WIDTH: u16 = 160; // A large terminal, to load the renderer
+pub const BENCH_HEIGHT: u16 = 48;
+pub const PROFILE_FLAME_INTERVAL: u64 = 600; // Frames between rewrites of the --profile-flame file
+
+pub const DIFFICULTY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1); // How often the difficulty overlay takes a sample
+pub const DIFFICULTY_CHART_SAMPLES: usize = 20; // Seconds of history in each bar chart
+
+pub const SPATIAL_HASH_CELL_WIDTH: u16 = 8; // Columns per broad-phase bucket
+pub const SPATIAL_HASH_CELL_HEIGHT: u16 = 4; // Rows per broad-phase bucket (roughly square on screen)
+
+pub const MULTI_KILL_THRESHOLD: usize = 3; // Asteroids destroyed by one shot to trigger bullet time
+pub const EVENT_LOG_LINES: usize = 6;
+pub const EVENT_LOG_LIFETIME: Duration = Duration::from_secs(5); // How long an entry stays in the event log
+pub const MINIMAP_SIZE: u16 = 20; // Cells per side, border included
+pub const MAX_WORLD_SCALE: u16 = 4; // --world-scale: largest world, in screens across and down
+pub const FIXED_WORLD_WIDTH: u16 = 120; // --fixed-world and tournaments: the same field on every terminal,
+pub const FIXED_WORLD_HEIGHT: u16 = 40; // half again the classic 80x24 and a little taller, as cells are tall
+pub const JUGGLE_WINDOW: Duration = Duration::from_millis(1500); // Longest gap between fragment kills that keeps a juggle going
+pub const JUGGLE_BONUS_PER_LINK: u32 = 25; // Second fragment in a chain earns 25, the third 50, ...
+pub const BOUNTY_INTERVAL: Duration = Duration::from_secs(30); // Between one bounty ending and the next being posted
+pub const BOUNTY_TIME_LIMIT: Duration = Duration::from_secs(10);
+pub const BOUNTY_BONUS: u32 = 1000;
+pub const BOUNTY_BLINK: Duration = Duration::from_millis(250); // The bounty marker flashes on and off at this rate
+pub const BOUNTY_COLOR: Color = Color::Magenta;
+pub const BULLET_TIME_SCALE: f64 = 0.5; // Simulation runs at half speed
+pub const BULLET_TIME_DURATION: Duration = Duration::from_millis(500); // Game time, so a second on screen at half speed
+
+pub const EXPLOSION_KNOCKBACK_RADIUS: f64 = 8.0; // Distance (in rows) an asteroid explosion pushes the ship
+pub const EXPLOSION_KNOCKBACK_LARGE: f64 = 0.3;
+pub const EXPLOSION_KNOCKBACK_MEDIUM: f64 = 0.2;
+pub const EXPLOSION_KNOCKBACK_SMALL: f64 = 0.1;
+
+pub const BURN_DURATION: Duration = Duration::from_secs(5);
+pub const BURN_DAMAGE_INTERVAL: Duration = Duration::from_secs(2); // Burning costs a point of health this often
+pub const SLOWED_SPEED_FACTOR: f64 = 0.5;
+pub const BURNING_COLOR: Color = Color::Red;
+pub const SLOWED_COLOR: Color = Color::Blue;
+pub const STUNNED_COLOR: Color = Color::Yellow;
+pub const VOLATILE_ASTEROID_EVERY: u32 = 5; // Large asteroids whose ID index is a multiple of this are volatile
+pub const VOLATILE_ASTEROID_COLOR: Color = Color::DarkRed;
+pub const VOLATILE_BURN_RADIUS: f64 = 6.0; // Rows around a volatile asteroid's explosion that set ships burning
+pub const ION_CLOUD_FIRST_WAVE: usize = 2; // Each wave from this one on brings an ion cloud
+pub const ION_CLOUD_RADIUS: f64 = 5.0; // Rows; twice as many columns
+pub const ION_CLOUD_SPEED: f64 = 0.05; // Cells per tick
+pub const ION_CLOUD_LIFETIME: Duration = Duration::from_secs(40);
+pub const ION_CLOUD_SLOW_DURATION: Duration = Duration::from_millis(500); // Lingers this long after leaving the cloud
+pub const ION_CLOUD_COLOR: Color = Color::DarkBlue;
+pub const HYPERSPACE_EMP_RADIUS: f64 = 10.0; // Rows around a hyperspace arrival that stun a saucer
+pub const EMP_STUN_DURATION: Duration = Duration::from_secs(3);
+pub const RAPID_FIRE_DURATION: Duration = Duration::from_secs(10);
+pub const RAPID_FIRE_FACTOR: f64 = 2.0; // Shots come this many times as often, still no faster than MIN_SHOT_COOLDOWN
+pub const POWER_UP_INVINCIBILITY_DURATION: Duration = Duration::from_secs(5);
+pub const TIME_SLOW_DURATION: Duration = Duration::from_secs(5);
+pub const TIME_SLOW_FACTOR: f64 = 0.4; // Hazards move at this share of their speed while a ship has time slowed
+pub const POWER_UP_COLOR: Color = Color::Cyan;
+
+pub const IDLE_DECAY_GRACE: Duration = Duration::from_secs(5); // Idle this long before score starts decaying
+pub const IDLE_DECAY_INTERVAL: Duration = Duration::from_secs(1); // Then lose points once per second
+pub const IDLE_DECAY_POINTS: u32 = 10;
 
-pub const UPGRADE_BOX_SPAWN_RATE: u64 = 60 * 10; // Every 10 seconds
\ No newline at end of file
+pub const BASE_CRIT_CHANCE: f64 = 0.05;
+pub const CRIT_CHANCE_PER_UPGRADE: f64 = 0.05;
+pub const MAX_CRIT_CHANCE: f64 = 0.5;
+pub const CRIT_DAMAGE_MULTIPLIER: u32 = 2;
+pub const DAMAGE_POPUP_LIFETIME: Duration = Duration::from_secs(1); // How long a damage number floats above its target
+pub const BACKDROP_PHASE: Duration = Duration::from_secs(45); // Backdrop theme changes this often
+pub const BACKDROP_NEBULA_DRIFT: Duration = Duration::from_millis(350); // Nebula bands shift one column this often
@@ -0,0 +1,97 @@
+use std::env;
+
+use crossterm::style::Color;
+
+use crate::config::Config;
+use crate::rendering::GameGrid;
+
+const CREDITS_TEXT: [&str; 9] = [
+    "CREDITS",
+    "",
+    "Design and code: the vibe-asteroid contributors",
+    "After Asteroids (Atari, 1979)",
+    "Built on crossterm, rand, log and simple-logging",
+    "",
+    "Thanks to everyone who filed bugs,",
+    "played tournaments and sent in replays.",
+    "",
+];
+
+const SCROLL_HINT: &str = "Up/Down to scroll, any other key to go back";
+
+// --- Credits: the About screen, a roll of credits, build info and what the terminal can do ---
+// The text enters at the bottom and scrolls off the top, then starts over. `Game::show_credits` runs it.
+pub struct Credits {
+    lines: Vec<String>,
+}
+
+impl Credits {
+    // Built once when the screen opens; `size` is the terminal's.
+    pub fn new(config: &Config, size: (u16, u16)) -> Self {
+        let mut lines = vec!["VIBE-ASTEROID".to_string(), String::new()];
+        lines.extend(CREDITS_TEXT.iter().map(|line| line.to_string()));
+
+        lines.push("BUILD".to_string());
+        lines.push(String::new());
+        lines.push(format!("Version {}", env!("CARGO_PKG_VERSION")));
+        let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+        lines.push(format!("{} build for {}-{}", profile, env::consts::OS, env::consts::ARCH));
+        lines.push(format!("Features: {}", features()));
+        lines.push(String::new());
+
+        lines.push("TERMINAL".to_string());
+        lines.push(String::new());
+        lines.push(format!("Size: {}x{}", size.0, size.1));
+        lines.push(format!("TERM: {}", env::var("TERM").unwrap_or_else(|_| "unknown".to_string())));
+        let truecolor = env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit");
+        lines.push(format!("Colour: {}{}", yes_no(config.color), if truecolor { " (24-bit)" } else { "" }));
+        lines.push(format!("Unicode glyphs: {}", yes_no(config.unicode)));
+        lines.push(format!("Key releases: {}", yes_no(config.key_release)));
+        lines.push(format!("Gamepad: {}", yes_no(config.gamepad)));
+        Credits { lines }
+    }
+
+    // Rows scrolled through before the roll starts over: all the text, plus the screen it scrolls up from.
+    pub fn cycle(&self, height: u16) -> f64 {
+        (self.lines.len() as f64 + height as f64).max(1.0)
+    }
+
+    // Draws the roll `offset` rows in. The bottom two rows are kept for the key hint.
+    pub fn draw(&self, game_grid: &mut GameGrid, offset: f64) {
+        let area = game_grid.height.saturating_sub(2) as f64;
+        let offset = offset.rem_euclid(self.cycle(game_grid.height));
+        for (i, line) in self.lines.iter().enumerate() {
+            let y = (area - offset + i as f64).floor();
+            if (0.0..area).contains(&y) {
+                let color = if line.chars().all(|c| c.is_ascii_uppercase() || c == '-') { Color::Cyan } else { Color::Reset };
+                write_centered(game_grid, y as u16, line, color);
+            }
+        }
+        write_centered(game_grid, game_grid.height.saturating_sub(1), SCROLL_HINT, Color::DarkGrey);
+    }
+}
+
+// The optional subsystems compiled into this binary.
+fn features() -> String {
+    let features: Vec<&str> = [
+        ("audio", cfg!(feature = "audio")),
+        ("score-stream", cfg!(feature = "score-stream")),
+        ("overlays", cfg!(feature = "overlays")),
+        ("gamepad", cfg!(feature = "gamepad")),
+        ("gym", cfg!(feature = "gym")),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(name, _)| name)
+    .collect();
+    if features.is_empty() { "none".to_string() } else { features.join(", ") }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+fn write_centered(game_grid: &mut GameGrid, y: u16, text: &str, color: Color) {
+    let x = (game_grid.width / 2).saturating_sub(text.chars().count() as u16 / 2);
+    game_grid.write_str(x, y, text, color);
+}
@@ -0,0 +1,73 @@
+use std::time::Duration;
+use crossterm::style::Color;
+
+use crate::entities::{Entity, Ship};
+use crate::rendering::GameGrid;
+
+// What happens on screen while a scene's caption is shown.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SceneAction {
+    Hold,      // Caption only
+    ShipFlyIn, // The ship glides up from the bottom edge to the centre
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Scene {
+    pub duration: Duration,
+    pub caption: &'static str,
+    pub action: SceneAction,
+}
+
+// --- Cutscene: scenes played back to back on a timeline, skippable with any key ---
+pub struct Cutscene {
+    scenes: Vec<Scene>,
+}
+
+impl Cutscene {
+    pub fn new(scenes: Vec<Scene>) -> Self {
+        Cutscene { scenes }
+    }
+
+    // Short opener played before the first game of a session.
+    pub fn intro() -> Self {
+        Cutscene::new(vec![
+            Scene { duration: Duration::from_millis(1500), caption: "Sector 7. Asteroid density: rising.", action: SceneAction::Hold },
+            Scene { duration: Duration::from_millis(2000), caption: "One pilot volunteered.", action: SceneAction::ShipFlyIn },
+            Scene { duration: Duration::from_millis(1000), caption: "Clear the field.", action: SceneAction::Hold },
+        ])
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.scenes.iter().map(|scene| scene.duration).sum()
+    }
+
+    // Draws the moment `elapsed` into the cutscene; returns false once it has ended.
+    pub fn draw(&self, game_grid: &mut GameGrid, elapsed: Duration) -> bool {
+        let mut scene_start = Duration::ZERO;
+        for scene in &self.scenes {
+            if elapsed < scene_start + scene.duration {
+                let progress = (elapsed - scene_start).as_secs_f64() / scene.duration.as_secs_f64();
+                draw_scene(game_grid, scene, progress);
+                return true;
+            }
+            scene_start += scene.duration;
+        }
+        false
+    }
+}
+
+fn draw_scene(game_grid: &mut GameGrid, scene: &Scene, progress: f64) {
+    let center_x = game_grid.width as f64 / 2.0;
+    let center_y = game_grid.height as f64 / 2.0;
+    if scene.action == SceneAction::ShipFlyIn {
+        let start_y = game_grid.height as f64 - 1.0;
+        let ship = Ship::new(center_x, start_y + (center_y - start_y) * progress.min(1.0));
+        ship.draw(game_grid);
+    }
+    let caption_x = (game_grid.width / 2).saturating_sub(scene.caption.chars().count() as u16 / 2);
+    let caption_y = (game_grid.height / 2).saturating_sub(5);
+    game_grid.write_str(caption_x, caption_y, scene.caption, Color::Reset);
+    let hint = "Press any key to skip";
+    let hint_x = (game_grid.width / 2).saturating_sub(hint.len() as u16 / 2);
+    game_grid.write_str(hint_x, game_grid.height.saturating_sub(2), hint, Color::DarkGrey);
+}
@@ -0,0 +1,102 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::types::Vector2D;
+
+const DEATH_LOG_HEADER: &str = "vibe-asteroid-deaths 1";
+
+// Where a life was lost, as a fraction of the playfield so runs at different terminal sizes line up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeathRecord {
+    pub x: f64,
+    pub y: f64,
+    pub tick: u64, // Simulation tick of the run the death happened on
+}
+
+// --- DeathMap: every life lost across local runs, appended to a persistent log ---
+pub struct DeathMap {
+    pub deaths: Vec<DeathRecord>,
+}
+
+impl DeathMap {
+    // Location of the log: $XDG_DATA_HOME/vibe-asteroid/deaths or ~/.local/share/vibe-asteroid/deaths.
+    pub fn path() -> Option<PathBuf> {
+        let base = match env::var_os("XDG_DATA_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".local").join("share"),
+        };
+        Some(base.join("vibe-asteroid").join("deaths"))
+    }
+
+    // A missing log is an empty map (nobody has died yet).
+    pub fn load() -> io::Result<Self> {
+        let mut map = DeathMap { deaths: Vec::new() };
+        let Some(path) = Self::path() else {
+            return Ok(map);
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(map),
+            Err(e) => return Err(e),
+        };
+        let mut lines = contents.lines();
+        if lines.next() != Some(DEATH_LOG_HEADER) {
+            return Err(invalid_log("missing death log header"));
+        }
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            // A line cut short by a crash loses one death, not the whole map
+            if let ["death", x, y, tick] = fields.as_slice() {
+                map.deaths.push(DeathRecord { x: parse_field(x)?, y: parse_field(y)?, tick: parse_field(tick)? });
+            }
+        }
+        Ok(map)
+    }
+
+    pub fn record(&mut self, position: Vector2D, width: u16, height: u16, tick: u64) -> io::Result<()> {
+        let record = DeathRecord {
+            x: (position.x / width.max(1) as f64).clamp(0.0, 1.0),
+            y: (position.y / height.max(1) as f64).clamp(0.0, 1.0),
+            tick,
+        };
+        self.deaths.push(record);
+
+        let path = Self::path().ok_or_else(|| invalid_log("no home directory for the death log"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "{}", DEATH_LOG_HEADER)?;
+        }
+        writeln!(file, "death {:.4} {:.4} {}", record.x, record.y, record.tick)
+    }
+
+    // Deaths per bucket of a `columns` x `rows` grid laid over the playfield, row-major.
+    pub fn counts(&self, columns: usize, rows: usize) -> Vec<usize> {
+        let mut counts = vec![0; columns * rows];
+        for death in &self.deaths {
+            let column = ((death.x * columns as f64) as usize).min(columns - 1);
+            let row = ((death.y * rows as f64) as usize).min(rows - 1);
+            counts[row * columns + column] += 1;
+        }
+        counts
+    }
+
+    pub fn average_survival_ticks(&self) -> Option<u64> {
+        if self.deaths.is_empty() {
+            return None;
+        }
+        Some(self.deaths.iter().map(|death| death.tick).sum::<u64>() / self.deaths.len() as u64)
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str) -> io::Result<T> {
+    field.parse().map_err(|_| invalid_log("malformed death record"))
+}
+
+fn invalid_log(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use crate::balance::UpgradeBalance;
+use crate::constants::*;
+
+// --- Difficulty: a profile preference (or --difficulty) that sets how hard each wave hits ---
+// Every preset scales the same knobs of the wave director and the ship. Normal leaves them all at their base
+// values, so it plays exactly as the game did before there were presets.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Insane,
+}
+
+pub const DIFFICULTIES: [Difficulty; 4] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard, Difficulty::Insane];
+
+impl Difficulty {
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Insane => "insane",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        DIFFICULTIES.into_iter().find(|difficulty| difficulty.name() == name)
+    }
+
+    // Applied to asteroid speed on top of the per-wave increase.
+    pub fn speed_factor(self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.3,
+            Difficulty::Insane => 1.6,
+        }
+    }
+
+    // Applied to the time between formations; the floor (MIN_ASTEROID_SPAWN_INTERVAL) stays the same.
+    pub fn spawn_interval_factor(self) -> f64 {
+        match self {
+            Difficulty::Easy => 1.4,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.8,
+            Difficulty::Insane => 0.6,
+        }
+    }
+
+    // Applied to each wave's quota of large asteroids.
+    pub fn wave_size_factor(self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Insane => 1.5,
+        }
+    }
+
+    // Applied to the per-wave spawn bias (`spawn_bias::strength`): how often a formation that would land on the
+    // ship is moved elsewhere.
+    pub fn spawn_bias_factor(self) -> f64 {
+        match self {
+            Difficulty::Easy => 1.2,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.6,
+            Difficulty::Insane => 0.3,
+        }
+    }
+
+    // How often an upgrade box drifts in.
+    pub fn upgrade_box_interval(self) -> Duration {
+        match self {
+            Difficulty::Easy => UPGRADE_BOX_SPAWN_INTERVAL.mul_f64(0.7),
+            Difficulty::Normal => UPGRADE_BOX_SPAWN_INTERVAL,
+            Difficulty::Hard => UPGRADE_BOX_SPAWN_INTERVAL.mul_f64(1.3),
+            Difficulty::Insane => UPGRADE_BOX_SPAWN_INTERVAL.mul_f64(1.6),
+        }
+    }
+
+    // Health the ship starts with on top of MAX_HEALTH.
+    pub fn extra_starting_health(self) -> u32 {
+        match self {
+            Difficulty::Easy => 2,
+            Difficulty::Normal | Difficulty::Hard | Difficulty::Insane => 0,
+        }
+    }
+
+    // Caps and diminishing-returns step for the stacking upgrades. Each cap's headroom above x1 is scaled, so
+    // hard presets top out lower and get there more slowly.
+    pub fn upgrade_balance(self) -> UpgradeBalance {
+        let (headroom, step) = match self {
+            Difficulty::Easy => (1.5, 0.15),
+            Difficulty::Normal => (1.0, UPGRADE_HEADROOM_STEP),
+            Difficulty::Hard => (0.75, 0.08),
+            Difficulty::Insane => (0.5, 0.06),
+        };
+        let scaled = |cap: f64| 1.0 + (cap - 1.0) * headroom;
+        UpgradeBalance {
+            fire_rate_cap: scaled(MAX_FIRE_RATE_MULTIPLIER),
+            bullet_speed_cap: scaled(MAX_BULLET_SPEED_MULTIPLIER),
+            booster_cap: scaled(MAX_BOOSTER_MULTIPLIER),
+            step,
+        }
+    }
+
+    // Cycles through the presets in either direction, wrapping at the ends.
+    pub fn step(self, forward: bool) -> Self {
+        let index = DIFFICULTIES.iter().position(|&difficulty| difficulty == self).unwrap_or(1);
+        let count = DIFFICULTIES.len();
+        DIFFICULTIES[if forward { (index + 1) % count } else { (index + count - 1) % count }]
+    }
+}
@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::constants::DIFFICULTY_CHART_SAMPLES;
+
+// What the wave director was doing at one moment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirectorSample {
+    pub spawn_interval: Duration,
+    pub asteroid_cap: usize, // Large asteroids in the current wave
+    pub speed_multiplier: f64,
+    pub asteroid_count: usize,
+}
+
+// --- DirectorStats: a rolling history of director samples for the difficulty overlay ---
+#[derive(Clone, Default)]
+pub struct DirectorStats {
+    samples: VecDeque<DirectorSample>,
+}
+
+impl DirectorStats {
+    pub fn new() -> Self {
+        DirectorStats { samples: VecDeque::new() }
+    }
+
+    pub fn record(&mut self, sample: DirectorSample) {
+        if self.samples.len() == DIFFICULTY_CHART_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn latest(&self) -> Option<&DirectorSample> {
+        self.samples.back()
+    }
+
+    // One value per sample, oldest first, for the chart row picked by `metric`.
+    pub fn series(&self, metric: impl Fn(&DirectorSample) -> f64) -> Vec<f64> {
+        self.samples.iter().map(metric).collect()
+    }
+}
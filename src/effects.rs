@@ -0,0 +1,192 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::entities::Particle;
+use crate::types::Vector2D;
+
+const EFFECTS_HEADER: &str = "vibe-asteroid-effects 1";
+
+// The particle effects a theme styles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Effect {
+    Thrust,    // Exhaust behind a thrusting ship
+    Spark,     // A shot hitting something it doesn't destroy
+    Explosion, // An asteroid breaking up
+    Boss,      // The ring a boss shatters in
+    Wreck,     // A ship or saucer going down
+    Debris,    // Shots chipping an upgrade box
+    Warp,      // Hyperspace departures and arrivals
+}
+
+impl Effect {
+    pub const ALL: [Effect; 7] = [Effect::Thrust, Effect::Spark, Effect::Explosion, Effect::Boss, Effect::Wreck, Effect::Debris, Effect::Warp];
+
+    // Name used in the effects file.
+    pub fn key(self) -> &'static str {
+        match self {
+            Effect::Thrust => "thrust",
+            Effect::Spark => "spark",
+            Effect::Explosion => "explosion",
+            Effect::Boss => "boss",
+            Effect::Wreck => "wreck",
+            Effect::Debris => "debris",
+            Effect::Warp => "warp",
+        }
+    }
+
+    pub fn parse(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|effect| effect.key() == key)
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&effect| effect == self).unwrap_or(0)
+    }
+}
+
+// How one effect looks: the glyphs a particle steps through from birth to fading out, how long it lasts, and
+// what share of the usual particle count is spawned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectStyle {
+    pub glyphs: Arc<[char]>,
+    pub lifetime: Duration,
+    pub density: u32, // Percent
+}
+
+// --- EffectsTheme: the look of every particle effect, so they can be toned up or down without code changes ---
+// The classic theme is what the game has always drawn. Others are written by hand in the `effects` file next to
+// the config profile, starting from a built-in theme and overriding single effects:
+//
+//     vibe-asteroid-effects 1
+//     theme subtle
+//     density 50
+//     explosion glyphs @#*+.
+//     explosion lifetime 900
+//
+// `density` alone scales every effect; after an effect's name it scales just that one. Particles only ever
+// draw on the cosmetic random number stream, so a theme never changes how a game plays out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectsTheme {
+    styles: Vec<EffectStyle>, // Indexed like `Effect::ALL`
+}
+
+impl EffectsTheme {
+    pub fn classic() -> Self {
+        let style = |glyphs: &str, millis: u64| EffectStyle { glyphs: glyphs.chars().collect(), lifetime: Duration::from_millis(millis), density: 100 };
+        EffectsTheme {
+            styles: vec![style(".", 500), style("*", 400), style("#", 750), style("#", 1000), style("*", 750), style("+", 500), style("+", 400)],
+        }
+    }
+
+    // Fewer, shorter-lived particles that fade out, for busy screens or sensitive eyes.
+    pub fn subtle() -> Self {
+        let mut theme = Self::classic();
+        for style in &mut theme.styles {
+            style.glyphs = style.glyphs.iter().copied().chain(['.']).collect();
+            style.lifetime /= 2;
+            style.density = 50;
+        }
+        theme
+    }
+
+    // No particles at all.
+    pub fn off() -> Self {
+        let mut theme = Self::classic();
+        for style in &mut theme.styles {
+            style.density = 0;
+        }
+        theme
+    }
+
+    pub fn parse_name(name: &str) -> Option<Self> {
+        match name {
+            "classic" => Some(Self::classic()),
+            "subtle" => Some(Self::subtle()),
+            "off" => Some(Self::off()),
+            _ => None,
+        }
+    }
+
+    pub fn style(&self, effect: Effect) -> &EffectStyle {
+        &self.styles[effect.index()]
+    }
+
+    // How many particles an effect that normally spawns `base` of them spawns in this theme.
+    pub fn count(&self, effect: Effect, base: usize) -> usize {
+        (base as u64 * self.style(effect).density as u64).div_ceil(100) as usize
+    }
+
+    pub fn particle(&self, effect: Effect, position: Vector2D, velocity: Vector2D) -> Particle {
+        let style = self.style(effect);
+        Particle::with_glyphs(position, velocity, style.lifetime, style.glyphs.clone())
+    }
+
+    // Location of the theme: beside the config profile, as `effects`.
+    pub fn path() -> Option<PathBuf> {
+        Some(Config::path()?.with_file_name("effects"))
+    }
+
+    // A missing file means the classic theme.
+    pub fn load() -> io::Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::classic());
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::classic()),
+            Err(e) => return Err(e),
+        };
+        Self::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        let mut lines = contents.lines();
+        if lines.next() != Some(EFFECTS_HEADER) {
+            return Err(invalid_effects("missing effects header"));
+        }
+        let mut theme = Self::classic();
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["theme", name] => theme = Self::parse_name(name).ok_or_else(|| invalid_effects("unknown effects theme"))?,
+                ["density", percent] => {
+                    let density = parse_density(percent)?;
+                    for style in &mut theme.styles {
+                        style.density = density;
+                    }
+                }
+                [effect, setting, value] => {
+                    let effect = Effect::parse(effect).ok_or_else(|| invalid_effects("unknown effect"))?;
+                    let style = &mut theme.styles[effect.index()];
+                    match *setting {
+                        "glyphs" => style.glyphs = value.chars().collect(),
+                        "lifetime" => style.lifetime = Duration::from_millis(value.parse().map_err(|_| invalid_effects("expected a lifetime in milliseconds"))?),
+                        "density" => style.density = parse_density(value)?,
+                        _ => return Err(invalid_effects("unknown effect setting")),
+                    }
+                }
+                _ => return Err(invalid_effects("unrecognised effects line")),
+            }
+        }
+        Ok(theme)
+    }
+}
+
+impl Default for EffectsTheme {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+// Up to four times the usual count, so a typo can't flood the screen.
+fn parse_density(value: &str) -> io::Result<u32> {
+    Ok(value.parse::<u32>().map_err(|_| invalid_effects("expected a density percentage"))?.min(400))
+}
+
+fn invalid_effects(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -0,0 +1,99 @@
+use rand::Rng;
+
+use crate::clock::ticks_for;
+use crate::constants::*;
+use crate::entities::{Bullet, Entity, Saucer, UpdateContext};
+use crate::entity_id::EntityAllocator;
+use crate::rendering::GameGrid;
+use crate::types::Vector2D;
+
+// --- SaucerEncounter: spawns the enemy saucer and tracks the shots it fires ---
+#[derive(Clone)]
+pub struct SaucerEncounter {
+    pub saucer: Option<Saucer>,
+    pub bullets: Vec<Bullet>,
+}
+
+impl SaucerEncounter {
+    pub fn new() -> Self {
+        SaucerEncounter { saucer: None, bullets: Vec::new() }
+    }
+
+    // Advances the saucer and its shots by one tick, aiming at `ship_position`. Returns which of the ships
+    // (given by their cells) a shot hit.
+    pub fn update(
+        &mut self,
+        ctx: &UpdateContext,
+        tick: u64,
+        ship_position: Vector2D,
+        ship_coords: &[Vec<(u16, u16)>],
+        rng: &mut impl Rng,
+        entity_ids: &mut EntityAllocator,
+    ) -> Vec<bool> {
+        let (terminal_width, terminal_height) = (ctx.world_width, ctx.world_height);
+        if self.saucer.is_none() && tick > 0 && tick.is_multiple_of(ticks_for(SAUCER_SPAWN_INTERVAL)) {
+            // Enter from the left or right edge and head across the field
+            let from_left = rng.gen_bool(0.5);
+            let x = if from_left { 0.0 } else { terminal_width.saturating_sub(1) as f64 };
+            // Clear of the top and bottom rows; a field shrunk to a few rows still leaves a non-empty range
+            let y = rng.gen_range(2.0..(terminal_height.max(5) - 2) as f64);
+            let velocity = Vector2D::new(if from_left { SAUCER_SPEED } else { -SAUCER_SPEED }, 0.0);
+            self.saucer = Some(Saucer::new(entity_ids.allocate(), Vector2D::new(x, y), velocity));
+        }
+
+        if let Some(saucer) = &mut self.saucer {
+            saucer.status.tick();
+            saucer.update(ctx.dt, ship_position, terminal_width, terminal_height);
+            if saucer.ready_to_fire() {
+                let aim = (ship_position.y - saucer.position.y).atan2(ship_position.x - saucer.position.x)
+                    + rng.gen_range(-SAUCER_AIM_ERROR..SAUCER_AIM_ERROR);
+                let velocity = Vector2D::new(aim.cos() * SAUCER_BULLET_SPEED, aim.sin() * SAUCER_BULLET_SPEED);
+                let mut bullet = Bullet::new(entity_ids.allocate(), saucer.position, velocity, 1.0, SAUCER_BULLET_LIFETIME);
+                bullet.color = SAUCER_BULLET_COLOR;
+                self.bullets.push(bullet);
+            }
+            if saucer.time_left <= 0.0 {
+                entity_ids.release(saucer.id);
+                self.saucer = None;
+            }
+        }
+
+        let mut ship_hits = vec![false; ship_coords.len()];
+        self.bullets.retain_mut(|bullet| {
+            bullet.update(ctx);
+            let position = (bullet.position.x.round() as u16, bullet.position.y.round() as u16);
+            let hit = ship_coords.iter().position(|coords| coords.contains(&position));
+            if let Some(index) = hit {
+                ship_hits[index] = true;
+            }
+            let keep = hit.is_none() && !bullet.is_expired();
+            if !keep {
+                entity_ids.release(bullet.id);
+            }
+            keep
+        });
+        ship_hits
+    }
+
+    // Destroys the saucer if `point` lies on it, returning where it was.
+    pub fn hit_by(&mut self, point: (u16, u16), entity_ids: &mut EntityAllocator) -> Option<Vector2D> {
+        let saucer = self.saucer.take_if(|saucer| saucer.get_absolute_coords().contains(&point))?;
+        entity_ids.release(saucer.id);
+        Some(saucer.position)
+    }
+
+    pub fn draw(&self, game_grid: &mut GameGrid) {
+        if let Some(saucer) = &self.saucer {
+            saucer.draw(game_grid);
+        }
+        for bullet in &self.bullets {
+            bullet.draw(game_grid);
+        }
+    }
+}
+
+impl Default for SaucerEncounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::constants::*;
+
+// --- Energy: the one resource every ship ability draws from, refilling over time ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Energy {
+    pub current: f64,
+    pub capacity: f64,
+}
+
+impl Energy {
+    pub fn new() -> Self {
+        Energy { current: SHIP_ENERGY_CAPACITY, capacity: SHIP_ENERGY_CAPACITY }
+    }
+
+    // `dt` is in seconds.
+    pub fn regenerate(&mut self, dt: f64) {
+        self.current = (self.current + ENERGY_REGEN_PER_SECOND * dt).min(self.capacity);
+    }
+
+    pub fn can_afford(&self, cost: f64) -> bool {
+        self.current >= cost
+    }
+
+    // Spends `cost` if there is enough; an ability that can't be paid for doesn't happen.
+    pub fn try_spend(&mut self, cost: f64) -> bool {
+        if !self.can_afford(cost) {
+            return false;
+        }
+        self.current -= cost;
+        true
+    }
+
+    // A bigger tank also comes full of the extra energy.
+    pub fn expand(&mut self) {
+        let capacity = (self.capacity + ENERGY_CAPACITY_PER_UPGRADE).min(MAX_ENERGY_CAPACITY);
+        self.current += capacity - self.capacity;
+        self.capacity = capacity;
+    }
+
+    // Seconds until `cost` is affordable again.
+    pub fn seconds_until(&self, cost: f64) -> f64 {
+        (cost - self.current).max(0.0) / ENERGY_REGEN_PER_SECOND
+    }
+}
+
+impl Default for Energy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,205 @@
+use crate::constants::*;
+use crate::clock::tick_seconds;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::rendering::{GameGrid, polygon_cells};
+use crate::entity_id::{EntityId, Identified};
+use rand::Rng;
+
+use super::{Entity, UpdateContext};
+
+// --- Asteroid: a bumpy rock that takes a few hits and splits into smaller ones ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AsteroidSize {
+    Large,
+    Medium,
+    Small,
+}
+
+impl AsteroidSize {
+    // Size of the pieces an asteroid breaks into; small asteroids are destroyed outright.
+    pub fn fragment_size(self) -> Option<AsteroidSize> {
+        match self {
+            AsteroidSize::Large => Some(AsteroidSize::Medium),
+            AsteroidSize::Medium => Some(AsteroidSize::Small),
+            AsteroidSize::Small => None,
+        }
+    }
+
+    // Collision circle in cells, matching the extent of the shape.
+    pub fn radius(self) -> f64 {
+        match self {
+            AsteroidSize::Large => 2.0,
+            AsteroidSize::Medium => 1.0,
+            AsteroidSize::Small => 0.75,
+        }
+    }
+
+    // Damage it takes to destroy: a standard shot splits a small asteroid, while larger ones need more hits or
+    // bigger shots.
+    pub fn hit_points(self) -> f64 {
+        match self {
+            AsteroidSize::Large => 3.0,
+            AsteroidSize::Medium => 2.0,
+            AsteroidSize::Small => 1.0,
+        }
+    }
+
+    // A large asteroid weighs what its four small descendants do.
+    pub fn mass(self) -> f64 {
+        match self {
+            AsteroidSize::Large => 4.0,
+            AsteroidSize::Medium => 2.0,
+            AsteroidSize::Small => 1.0,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Asteroid {
+    pub id: EntityId,
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub size: AsteroidSize,
+    pub shape: Vec<(f64, f64)>, // Relative coordinates for bumpy shape
+    pub display_char: char,
+    pub parent: Option<EntityId>, // The asteroid this one split from; None for spawned asteroids
+    pub volatile: bool, // Sets nearby ships burning when it explodes
+    pub hp: f64,        // Damage left to take before it breaks up
+}
+
+impl Asteroid {
+    pub fn new(id: EntityId, x: f64, y: f64, rng: &mut impl Rng, size: AsteroidSize, game_speed_multiplier: f64) -> Self {
+        let (shape, display_char) = match size {
+            AsteroidSize::Large => (
+                vec![
+                    (0.0, 0.0), (-2.0, -1.0), (-1.0, -2.0), (1.0, -2.0), (2.0, -1.0),
+                    (2.0, 1.0), (1.0, 2.0), (-1.0, 2.0), (-2.0, 1.0),
+                ],
+                '@',
+            ),
+            AsteroidSize::Medium => (
+                vec![
+                    (0.0, 0.0), (-1.0, -1.0), (0.0, -1.0), (1.0, -1.0),
+                    (-1.0, 0.0), (1.0, 0.0), (-1.0, 1.0), (0.0, 1.0), (1.0, 1.0),
+                ],
+                'O',
+            ),
+            AsteroidSize::Small => (vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)], 'o'),
+        };
+        let angle = rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+        let speed = match size {
+            AsteroidSize::Large => rng.gen_range(0.3..0.8),
+            AsteroidSize::Medium => rng.gen_range(0.8..1.5),
+            AsteroidSize::Small => rng.gen_range(1.5..2.5),
+        } * game_speed_multiplier;
+        let velocity = Vector2D::new(angle.cos() * speed, angle.sin() * speed);
+
+        // Picked by ID rather than drawn from the RNG, so seeds produce the same fields as before volatiles existed
+        let volatile = size == AsteroidSize::Large && id.index().is_multiple_of(VOLATILE_ASTEROID_EVERY);
+        Asteroid { id, position: Vector2D::new(x, y), velocity, size, shape, display_char, parent: None, volatile, hp: size.hit_points() }
+    }
+
+    // Takes `damage` off the asteroid's hit points. Returns true once they're used up.
+    pub fn take_damage(&mut self, damage: f64) -> bool {
+        self.hp -= damage;
+        self.hp <= 0.0
+    }
+
+    // The corners of the asteroid's rim: the shape's points around the centre, in order of their angle from it.
+    pub fn rim(&self) -> Vec<(f64, f64)> {
+        let mut rim: Vec<(f64, f64)> = self.shape.iter().copied().filter(|&point| point != (0.0, 0.0)).collect();
+        rim.sort_by(|a, b| a.1.atan2(a.0).total_cmp(&b.1.atan2(b.0)));
+        rim.iter().map(|&(dx, dy)| (self.position.x + dx, self.position.y + dy)).collect()
+    }
+
+    // The cells of the rim, joined into a closed outline.
+    pub fn outline_cells(&self) -> Vec<(u16, u16)> {
+        let corners: Vec<(u16, u16)> = self.rim().iter().map(|&(x, y)| (x.round() as u16, y.round() as u16)).collect();
+        polygon_cells(&corners)
+    }
+
+    // Points the asteroid along `angle` while keeping its speed.
+    pub fn set_heading(&mut self, angle: f64) {
+        let speed = (self.velocity.x.powi(2) + self.velocity.y.powi(2)).sqrt();
+        self.velocity = Vector2D::new(angle.cos() * speed, angle.sin() * speed);
+    }
+
+    // Breaks the asteroid in two. The pieces fly off at right angles to the bullet that hit it,
+    // one to each side, so a well-placed shot steers them away from the ship.
+    pub fn split(&self, ids: [EntityId; 2], bullet_velocity: Vector2D, rng: &mut impl Rng, game_speed_multiplier: f64) -> Vec<Asteroid> {
+        let Some(fragment_size) = self.size.fragment_size() else {
+            return Vec::new();
+        };
+        let bullet_heading = bullet_velocity.y.atan2(bullet_velocity.x);
+        ids.into_iter().zip([1.0, -1.0]).map(|(id, side)| {
+            let mut fragment = Asteroid::new(id, self.position.x, self.position.y, rng, fragment_size, game_speed_multiplier);
+            let jitter = rng.gen_range(-SPLIT_ANGLE_JITTER..SPLIT_ANGLE_JITTER);
+            fragment.set_heading(bullet_heading + side * std::f64::consts::FRAC_PI_2 + jitter);
+            fragment.parent = Some(self.id);
+            fragment
+        }).collect()
+    }
+}
+
+impl Entity for Asteroid {
+    fn update(&mut self, ctx: &UpdateContext) {
+        self.position = self.position.add(self.velocity.scale(ctx.dt / tick_seconds()));
+
+        // Screen wrapping
+        self.position.x = wrap_coordinate(self.position.x, ctx.world_width as f64);
+        self.position.y = wrap_coordinate(self.position.y, ctx.world_height as f64);
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        let color = if self.volatile { VOLATILE_ASTEROID_COLOR } else { ASTEROID_COLOR };
+        if game_grid.hires {
+            let rim = self.rim();
+            for (i, &from) in rim.iter().enumerate() {
+                game_grid.plot_line(from, rim[(i + 1) % rim.len()], color);
+            }
+            return;
+        }
+        for (x, y) in self.outline_cells().into_iter().chain(self.coords()) {
+            game_grid.set_cell(x, y, self.display_char, color);
+        }
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        self.shape.iter().map(|&(dx, dy)| {
+            ((self.position.x + dx).round() as u16, (self.position.y + dy).round() as u16)
+        }).collect()
+    }
+}
+
+impl Identified for Asteroid {
+    fn id(&self) -> EntityId {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_id::EntityAllocator;
+    use crate::entities::{Bullet, Ship};
+
+    #[test]
+    fn large_asteroid_takes_three_standard_shots() {
+        let mut ids = EntityAllocator::new();
+        let mut asteroid = Asteroid::new(ids.allocate(), 10.0, 10.0, &mut rand::thread_rng(), AsteroidSize::Large, 1.0);
+        let shot = Bullet::new(ids.allocate(), Vector2D::new(10.0, 10.0), Vector2D::new(1.0, 0.0), 1.0, BULLET_LIFETIME);
+        assert!(!asteroid.take_damage(shot.damage()));
+        assert!(!asteroid.take_damage(shot.damage()));
+        assert!(asteroid.take_damage(shot.damage()));
+    }
+
+    #[test]
+    fn shot_size_scales_damage() {
+        let mut ids = EntityAllocator::new();
+        let mut asteroid = Asteroid::new(ids.allocate(), 10.0, 10.0, &mut rand::thread_rng(), AsteroidSize::Medium, 1.0);
+        let mut ship = Ship::new(10.0, 10.0);
+        ship.bullet_size_multiplier += 1.0;
+        let shot = Bullet::new(ids.allocate(), ship.position, Vector2D::new(1.0, 0.0), ship.bullet_size_multiplier, BULLET_LIFETIME);
+        assert!(asteroid.take_damage(shot.damage()));
+    }
+}
@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use crate::constants::*;
+use crate::clock::tick_seconds;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::rendering::GameGrid;
+use crate::entity_id::{EntityId, Identified};
+use crossterm::style::Color;
+
+use super::{Entity, UpdateContext};
+
+// --- Bullet: a shot, which flies until its range runs out ---
+#[derive(Clone)]
+pub struct Bullet {
+    pub id: EntityId,
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub lifetime: Duration,           // Full flight time, set when fired (range upgrades extend it)
+    pub expires_in: Option<Duration>, // None once the bullet has expired
+    pub display_char: char,
+    pub size: f64,
+    pub color: Color,
+    pub owner: usize, // Index of the player who fired it; enemy shots leave it at 0
+    pub pierce: u32,  // Kills it can still pass through
+    pub pierced: Option<EntityId>, // The last asteroid it passed through, whose fragments it can't hit
+}
+
+impl Bullet {
+    pub fn new(id: EntityId, position: Vector2D, velocity: Vector2D, size: f64, lifetime: Duration) -> Self {
+        Bullet {
+            id,
+            position,
+            velocity,
+            lifetime,
+            expires_in: Some(lifetime).filter(|left| !left.is_zero()),
+            display_char: '*',
+            size,
+            color: BULLET_COLOR,
+            owner: 0,
+            pierce: 0,
+            pierced: None,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_in.is_none()
+    }
+
+    // Bigger shots hit harder.
+    pub fn damage(&self) -> f64 {
+        BULLET_DAMAGE * self.size
+    }
+}
+
+impl Entity for Bullet {
+    fn update(&mut self, ctx: &UpdateContext) {
+        self.position = self.position.add(self.velocity.scale(ctx.dt / tick_seconds()));
+        let elapsed = Duration::from_secs_f64(ctx.dt);
+        self.expires_in = self.expires_in.and_then(|left| left.checked_sub(elapsed)).filter(|left| !left.is_zero());
+
+        // Screen wrapping
+        self.position.x = wrap_coordinate(self.position.x, ctx.world_width as f64);
+        self.position.y = wrap_coordinate(self.position.y, ctx.world_height as f64);
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        if game_grid.hires {
+            // A square `size` dots across instead of cells; the fading glyph has no dot equivalent
+            let dots = self.size.round().max(1.0) as u32;
+            for (i, j) in (0..dots).flat_map(|i| (0..dots).map(move |j| (i, j))) {
+                game_grid.plot(self.position.x + i as f64 / 2.0, self.position.y + j as f64 / 4.0, self.color);
+            }
+            return;
+        }
+        // Fades as it nears the end of its range
+        let remaining = self.expires_in.map_or(0.0, |left| left.as_secs_f64() / self.lifetime.as_secs_f64());
+        let char_to_draw = if remaining > 2.0 / 3.0 {
+            '*'
+        } else if remaining > 1.0 / 3.0 {
+            '+'
+        } else {
+            '.'
+        };
+        for (x, y) in self.coords() {
+            game_grid.set_cell(x, y, char_to_draw, self.color);
+        }
+    }
+
+    // A square `size` cells across, from the bullet's position
+    fn coords(&self) -> Vec<(u16, u16)> {
+        let (x, y, size) = (self.position.x.round() as u16, self.position.y.round() as u16, self.size.round() as u16);
+        (0..size).flat_map(|i| (0..size).map(move |j| (x + i, y + j))).collect()
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_expired()
+    }
+}
+
+impl Identified for Bullet {
+    fn id(&self) -> EntityId {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ticks_for;
+    use crate::entity_id::EntityAllocator;
+    use crate::entities::Ship;
+
+    fn bullet_with_lifetime(lifetime: Duration) -> Bullet {
+        let id = EntityAllocator::new().allocate();
+        Bullet::new(id, Vector2D::new(10.0, 10.0), Vector2D::new(1.0, 0.0), 1.0, lifetime)
+    }
+
+    #[test]
+    fn bullet_expires_exactly_at_end_of_lifetime() {
+        let mut bullet = bullet_with_lifetime(BULLET_LIFETIME);
+        let ticks = ticks_for(BULLET_LIFETIME);
+        for _ in 0..ticks - 1 {
+            bullet.update(&UpdateContext { dt: tick_seconds(), world_width: 80, world_height: 24 });
+            assert!(!bullet.is_expired());
+        }
+        bullet.update(&UpdateContext { dt: tick_seconds(), world_width: 80, world_height: 24 });
+        assert!(bullet.is_expired());
+    }
+
+    #[test]
+    fn zero_lifetime_bullet_starts_expired() {
+        assert!(bullet_with_lifetime(Duration::ZERO).is_expired());
+    }
+
+    #[test]
+    fn updating_an_expired_bullet_stays_expired() {
+        let mut bullet = bullet_with_lifetime(SIMULATION_TICK);
+        for _ in 0..3 {
+            bullet.update(&UpdateContext { dt: tick_seconds(), world_width: 80, world_height: 24 });
+        }
+        assert!(bullet.is_expired());
+        assert_eq!(bullet.expires_in, None);
+    }
+
+    #[test]
+    fn step_longer_than_remaining_lifetime_expires_without_underflow() {
+        let mut bullet = bullet_with_lifetime(SIMULATION_TICK);
+        bullet.update(&UpdateContext { dt: tick_seconds() * 5.0, world_width: 80, world_height: 24 });
+        assert!(bullet.is_expired());
+    }
+
+    #[test]
+    fn range_upgrade_extends_flight_time() {
+        let mut ship = Ship::new(10.0, 10.0);
+        ship.bullet_range_multiplier += BULLET_RANGE_PER_UPGRADE;
+        let mut bullet = bullet_with_lifetime(ship.bullet_lifetime());
+        for _ in 0..ticks_for(BULLET_LIFETIME) {
+            bullet.update(&UpdateContext { dt: tick_seconds(), world_width: 80, world_height: 24 });
+        }
+        assert!(!bullet.is_expired());
+    }
+}
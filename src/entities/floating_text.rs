@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use crate::constants::*;
+use crate::clock::tick_seconds;
+use crate::types::Vector2D;
+use crate::rendering::GameGrid;
+
+use super::{Entity, UpdateContext};
+
+// --- FloatingText: short-lived text (damage numbers) that drifts upwards ---
+#[derive(Clone)]
+pub struct FloatingText {
+    pub position: Vector2D,
+    pub text: String,
+    pub lifetime: f64, // Seconds left
+}
+
+impl FloatingText {
+    pub fn new(position: Vector2D, text: String, lifetime: Duration) -> Self {
+        FloatingText { position, text, lifetime: lifetime.as_secs_f64() }
+    }
+
+    // Critical hits render in mathematical bold digits so they stand out without text attributes.
+    pub fn damage_number(position: Vector2D, damage: f64, critical: bool) -> Self {
+        let text = if critical {
+            let bold: String = damage.to_string().chars().map(|c| {
+                c.to_digit(10).and_then(|digit| char::from_u32('𝟎' as u32 + digit)).unwrap_or(c)
+            }).collect();
+            format!("{}!", bold)
+        } else {
+            damage.to_string()
+        };
+        FloatingText::new(position, text, DAMAGE_POPUP_LIFETIME)
+    }
+}
+
+impl Entity for FloatingText {
+    fn update(&mut self, ctx: &UpdateContext) {
+        self.position.y -= 0.2 * ctx.dt / tick_seconds();
+        self.lifetime -= ctx.dt;
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        for ((x, y), c) in self.coords().into_iter().zip(self.text.chars()) {
+            game_grid.set_char(x, y, c);
+        }
+    }
+
+    // One cell per character, centred on the position
+    fn coords(&self) -> Vec<(u16, u16)> {
+        let start_x = (self.position.x.round() as u16).saturating_sub(self.text.chars().count() as u16 / 2);
+        (0..self.text.chars().count()).map(|i| (start_x + i as u16, self.position.y.round() as u16)).collect()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.lifetime > 0.0
+    }
+}
@@ -0,0 +1,64 @@
+use crate::constants::*;
+use crate::clock::tick_seconds;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::rendering::GameGrid;
+use crate::status_effects;
+use rand::Rng;
+
+use super::{Entity, UpdateContext};
+
+// --- IonCloud: a drifting patch of charged gas that slows any ship flying through it ---
+#[derive(Clone)]
+pub struct IonCloud {
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub time_left: f64, // Seconds before it disperses
+}
+
+impl IonCloud {
+    pub fn new(position: Vector2D, rng: &mut impl Rng) -> Self {
+        let angle = rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+        let velocity = Vector2D::new(angle.cos() * ION_CLOUD_SPEED, angle.sin() * ION_CLOUD_SPEED);
+        IonCloud { position, velocity, time_left: ION_CLOUD_LIFETIME.as_secs_f64() }
+    }
+
+    pub fn contains(&self, point: Vector2D) -> bool {
+        status_effects::within(point, self.position, ION_CLOUD_RADIUS)
+    }
+}
+
+impl Entity for IonCloud {
+    fn update(&mut self, ctx: &UpdateContext) {
+        self.position = self.position.add(self.velocity.scale(ctx.dt / tick_seconds()));
+        self.position.x = wrap_coordinate(self.position.x, ctx.world_width as f64);
+        self.position.y = wrap_coordinate(self.position.y, ctx.world_height as f64);
+        self.time_left -= ctx.dt;
+    }
+
+    // A sparse speckle, so what is inside the cloud stays readable.
+    fn draw(&self, game_grid: &mut GameGrid) {
+        for (x, y) in self.coords() {
+            if (x + y).is_multiple_of(3) {
+                game_grid.set_cell(x, y, ':', ION_CLOUD_COLOR);
+            }
+        }
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        let (reach_x, reach_y) = ((ION_CLOUD_RADIUS * TERMINAL_ASPECT_RATIO_COMPENSATION) as i32, ION_CLOUD_RADIUS as i32);
+        let mut cells = Vec::new();
+        for dy in -reach_y..=reach_y {
+            for dx in -reach_x..=reach_x {
+                let cell = Vector2D::new(self.position.x.round() + dx as f64, self.position.y.round() + dy as f64);
+                if cell.x >= 0.0 && cell.y >= 0.0 && self.contains(cell) {
+                    cells.push((cell.x as u16, cell.y as u16));
+                }
+            }
+        }
+        cells
+    }
+
+    fn is_alive(&self) -> bool {
+        self.time_left > 0.0
+    }
+}
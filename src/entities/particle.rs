@@ -0,0 +1,60 @@
+use std::time::Duration;
+use std::sync::Arc;
+
+use crate::clock::tick_seconds;
+use crate::types::Vector2D;
+use crate::rendering::GameGrid;
+
+use super::{Entity, UpdateContext};
+
+// --- Particle: a fading spark, puff of smoke or piece of debris ---
+#[derive(Clone)]
+pub struct Particle {
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub lifetime: f64, // Seconds left
+    pub full_lifetime: f64,
+    pub glyphs: Arc<[char]>, // Shown in turn over the particle's life, first to last
+}
+
+impl Particle {
+    pub fn new(position: Vector2D, velocity: Vector2D, lifetime: Duration, display_char: char) -> Self {
+        Self::with_glyphs(position, velocity, lifetime, Arc::from([display_char]))
+    }
+
+    pub fn with_glyphs(position: Vector2D, velocity: Vector2D, lifetime: Duration, glyphs: Arc<[char]>) -> Self {
+        Particle {
+            position,
+            velocity,
+            lifetime: lifetime.as_secs_f64(),
+            full_lifetime: lifetime.as_secs_f64(),
+            glyphs,
+        }
+    }
+
+    // The glyph for how far through its life the particle is.
+    pub fn glyph(&self) -> char {
+        let age = 1.0 - self.lifetime / self.full_lifetime.max(f64::EPSILON);
+        let index = (age * self.glyphs.len() as f64).floor() as usize;
+        self.glyphs.get(index.min(self.glyphs.len().saturating_sub(1))).copied().unwrap_or(' ')
+    }
+}
+
+impl Entity for Particle {
+    fn update(&mut self, ctx: &UpdateContext) {
+        self.position = self.position.add(self.velocity.scale(ctx.dt / tick_seconds()));
+        self.lifetime -= ctx.dt;
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        game_grid.set_char(self.position.x.round() as u16, self.position.y.round() as u16, self.glyph());
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        vec![(self.position.x.round() as u16, self.position.y.round() as u16)]
+    }
+
+    fn is_alive(&self) -> bool {
+        self.lifetime > 0.0
+    }
+}
@@ -0,0 +1,87 @@
+use crate::constants::*;
+use crate::clock::tick_seconds;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::rendering::GameGrid;
+use crate::entity_id::{EntityId, Identified};
+use crate::status_effects::{StatusEffects, StatusKind};
+
+// --- Saucer: an enemy UFO that drifts after the player and shoots back ---
+#[derive(Clone)]
+pub struct Saucer {
+    pub id: EntityId,
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub shape: Vec<(f64, f64, char)>,
+    pub time_left: f64,    // Seconds before it leaves the field
+    pub next_shot_in: f64, // Seconds until it fires again
+    pub status: StatusEffects,
+}
+
+impl Saucer {
+    pub fn new(id: EntityId, position: Vector2D, velocity: Vector2D) -> Self {
+        let mut shape = Vec::new();
+        for (dy, row) in [(-1.0, " _-_ "), (0.0, "<=O=>")] {
+            for (i, c) in row.chars().enumerate().filter(|&(_, c)| c != ' ') {
+                shape.push((i as f64 - 2.0, dy, c));
+            }
+        }
+        Saucer {
+            id,
+            position,
+            velocity,
+            shape,
+            time_left: SAUCER_LIFETIME.as_secs_f64(),
+            next_shot_in: SAUCER_FIRE_INTERVAL.as_secs_f64(),
+            status: StatusEffects::new(),
+        }
+    }
+
+    // Steers gently towards `target` rather than homing in, so it can be outmanoeuvred. A stunned saucer
+    // hangs where it is and its gun doesn't reload.
+    pub fn update(&mut self, dt: f64, target: Vector2D, terminal_width: u16, terminal_height: u16) {
+        self.time_left -= dt;
+        if self.status.has(StatusKind::Stunned) {
+            return;
+        }
+        let ticks = dt / tick_seconds() * self.status.speed_factor();
+        let to_target = Vector2D::new(target.x - self.position.x, target.y - self.position.y);
+        let distance = (to_target.x.powi(2) + to_target.y.powi(2)).sqrt().max(1.0);
+        let desired = to_target.scale(SAUCER_SPEED / distance);
+        let steering = Vector2D::new(desired.x - self.velocity.x, desired.y - self.velocity.y);
+        self.velocity = self.velocity.add(steering.scale((SAUCER_TRACKING * ticks).min(1.0)));
+        self.position = self.position.add(self.velocity.scale(ticks));
+        self.next_shot_in -= dt;
+
+        self.position.x = wrap_coordinate(self.position.x, terminal_width as f64);
+        self.position.y = wrap_coordinate(self.position.y, terminal_height as f64);
+    }
+
+    pub fn ready_to_fire(&mut self) -> bool {
+        if self.next_shot_in > 0.0 {
+            return false;
+        }
+        self.next_shot_in += SAUCER_FIRE_INTERVAL.as_secs_f64();
+        true
+    }
+
+    pub fn get_absolute_coords(&self) -> Vec<(u16, u16)> {
+        self.shape.iter()
+            .map(|&(dx, dy, _)| ((self.position.x + dx).round() as u16, (self.position.y + dy).round() as u16))
+            .collect()
+    }
+
+    pub fn draw(&self, game_grid: &mut GameGrid) {
+        for &(dx, dy, c) in &self.shape {
+            let draw_x = (self.position.x + dx).round() as u16;
+            let draw_y = (self.position.y + dy).round() as u16;
+            game_grid.set_cell(draw_x, draw_y, c, SAUCER_COLOR);
+        }
+        self.status.draw_indicator(game_grid, self.position);
+    }
+}
+
+impl Identified for Saucer {
+    fn id(&self) -> EntityId {
+        self.id
+    }
+}
@@ -0,0 +1,283 @@
+use std::time::Duration;
+
+use crate::constants::*;
+use crate::clock::tick_seconds;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::rendering::{GameGrid, line_glyph, polygon_cells};
+use crate::energy::Energy;
+use crate::upgrades::UpgradeType;
+use crate::active_effects::ActiveEffects;
+use crate::status_effects::StatusEffects;
+use crossterm::style::Color;
+use log::info;
+
+use super::{Entity, UpdateContext};
+
+// --- Ship: the player's craft, with the upgrades and power-ups it carries ---
+#[derive(Clone)]
+pub struct Ship {
+    pub position: Vector2D,
+    pub velocity: Vector2D,
+    pub angle: f64, // Radians
+    pub rotation_speed: f64,
+    pub thrust_power: f64,
+    pub friction: f64,
+    pub angular_velocity: f64,
+    pub angular_friction: f64,
+    pub shape: Vec<(f64, f64)>, // Relative coordinates for diamond shape
+    pub fire_rate_multiplier: f64,
+    pub bullet_speed_multiplier: f64,
+    pub bullet_size_multiplier: f64,
+    pub bullet_range_multiplier: f64,
+    pub pierce: u32, // Kills each shot passes through
+    pub booster_multiplier: f64,
+    pub shield_count: u32,
+    pub bombs: u32,
+    pub ship_size_multiplier: f64,
+    pub max_health: u32,
+    pub crit_chance: f64,
+    pub energy: Energy,
+    pub color: Color,
+    pub status: StatusEffects,
+    pub power_ups: ActiveEffects,
+    pub upgrades: Vec<UpgradeType>, // Kept upgrades in the order they were picked up
+}
+
+impl Ship {
+    pub fn new(x: f64, y: f64) -> Self {
+        Ship {
+            position: Vector2D::new(x, y),
+            velocity: Vector2D::new(0.0, 0.0),
+            angle: -std::f64::consts::FRAC_PI_2, // Facing upwards initially
+            rotation_speed: SHIP_ROTATION_SPEED, 
+            thrust_power: SHIP_THRUST_POWER, 
+            friction: SHIP_FRICTION,
+            angular_velocity: 0.0,
+            angular_friction: SHIP_ANGULAR_FRICTION,
+            shape: vec![
+                (0.0, -1.0), // Top point
+                (-1.0, 0.0), (1.0, 0.0), // Base points
+            ],
+            fire_rate_multiplier: 1.0,
+            bullet_speed_multiplier: 1.0,
+            bullet_size_multiplier: 1.0,
+            bullet_range_multiplier: 1.0,
+            pierce: 0,
+            booster_multiplier: 1.0,
+            shield_count: 0,
+            bombs: 0,
+            ship_size_multiplier: 1.0,
+            max_health: MAX_HEALTH,
+            crit_chance: BASE_CRIT_CHANCE,
+            energy: Energy::new(),
+            color: SHIP_COLOR,
+            status: StatusEffects::new(),
+            power_ups: ActiveEffects::new(),
+            upgrades: Vec::new(),
+        }
+    }
+
+    // Dampeners bleed off drift each tick; without them the ship keeps its momentum.
+    pub fn set_dampeners(&mut self, enabled: bool) {
+        self.friction = if enabled { SHIP_FRICTION } else { NEWTONIAN_FRICTION };
+    }
+
+    pub fn dampeners_enabled(&self) -> bool {
+        self.friction < NEWTONIAN_FRICTION
+    }
+
+    pub fn get_scaled_shape(&self) -> Vec<(f64, f64)> {
+        self.shape.iter().map(|&(dx, dy)| {
+            (dx * self.ship_size_multiplier, dy * self.ship_size_multiplier)
+        }).collect()
+    }
+
+    // Where the hull's corners are, one per point of the shape.
+    pub fn corners(&self) -> Vec<(f64, f64)> {
+        let draw_angle = self.angle + std::f64::consts::FRAC_PI_2;
+        self.get_scaled_shape().iter().map(|&(dx, dy)| {
+            let rotated_x = dx * draw_angle.cos() - dy * draw_angle.sin();
+            let rotated_y = dx * draw_angle.sin() + dy * draw_angle.cos();
+            (self.position.x + rotated_x, self.position.y + rotated_y)
+        }).collect()
+    }
+
+    // The cells of the hull's corners.
+    pub fn drawn_coords(&self) -> Vec<(u16, u16)> {
+        self.corners().iter().map(|&(x, y)| (x.round() as u16, y.round() as u16)).collect()
+    }
+
+    // Every cell `draw` puts the hull in: the corners and the edges joining them.
+    pub fn hull_cells(&self) -> Vec<(u16, u16)> {
+        polygon_cells(&self.drawn_coords())
+    }
+
+    // Flight time of the bullets this ship fires.
+    pub fn bullet_lifetime(&self) -> Duration {
+        BULLET_LIFETIME.mul_f64(self.bullet_range_multiplier)
+    }
+
+    // Time between this ship's shots: fire-rate upgrades divide the base cooldown, down to MIN_SHOT_COOLDOWN.
+    pub fn shot_cooldown(&self) -> Duration {
+        BULLET_COOLDOWN.div_f64((self.fire_rate_multiplier * self.power_ups.fire_rate_factor()).max(f64::EPSILON)).max(MIN_SHOT_COOLDOWN)
+    }
+
+    pub fn thrust(&mut self) {
+        let thrust_power = self.thrust_power * self.booster_multiplier * self.status.speed_factor();
+        let thrust_vector = Vector2D::new(self.angle.cos(), self.angle.sin()).scale(thrust_power);
+        self.velocity = self.velocity.add(thrust_vector);
+        info!("Thrusting: Angle = {}, Thrust Vector = ({}, {})", self.angle, thrust_vector.x, thrust_vector.y);
+    }
+
+    // Pushes the ship away from `center`, fading linearly to nothing at `radius`.
+    pub fn apply_radial_impulse(&mut self, center: Vector2D, strength: f64, radius: f64) {
+        let dx = (self.position.x - center.x) / TERMINAL_ASPECT_RATIO_COMPENSATION;
+        let dy = self.position.y - center.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance == 0.0 || distance >= radius {
+            return;
+        }
+        let falloff = 1.0 - distance / radius;
+        let impulse = Vector2D::new(dx / distance * TERMINAL_ASPECT_RATIO_COMPENSATION, dy / distance).scale(strength * falloff);
+        self.velocity = self.velocity.add(impulse);
+    }
+
+    pub fn rotate(&mut self, direction: f64) {
+        self.angular_velocity += self.rotation_speed * direction;
+    }
+
+    pub fn get_rotated_char(original_dx: f64, original_dy: f64, angle: f64) -> char {
+        // Normalize angle to be between 0 and 2*PI
+        let normalized_angle = angle.rem_euclid(2.0 * std::f64::consts::PI);
+
+        // Determine the primary direction based on 8 octants
+        // 0 = East (right)
+        // PI/2 = South (down)
+        // PI = West (left)
+        // 3*PI/2 = North (up)
+
+        match (original_dx.round() as i8, original_dy.round() as i8) {
+            (0, -1) => { // Top point
+                if !(std::f64::consts::FRAC_PI_4..7.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '>' // Pointing right
+                } else if (std::f64::consts::FRAC_PI_4..3.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    'v' // Pointing down
+                } else if (3.0 * std::f64::consts::FRAC_PI_4..5.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '<' // Pointing left
+                } else { // 5*PI/4 to 7*PI/4
+                    '^' // Pointing up
+                }
+            },
+            (-1, 0) => { // Left base point
+                if !(std::f64::consts::FRAC_PI_4..7.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '\u{005C}' // Right-pointing ship, this is bottom-left
+                } else if (std::f64::consts::FRAC_PI_4..3.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '/' // Down-pointing ship, this is top-left
+                } else if (3.0 * std::f64::consts::FRAC_PI_4..5.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '\u{005C}' // Left-pointing ship, this is top-right
+                } else { // 5*PI/4 to 7*PI/4
+                    '/' // Up-pointing ship, this is bottom-left
+                }
+            },
+            (1, 0) => { // Right base point
+                if !(std::f64::consts::FRAC_PI_4..7.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '/' // Right-pointing ship, this is bottom-right
+                } else if (std::f64::consts::FRAC_PI_4..3.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '\u{005C}' // Down-pointing ship, this is top-right
+                } else if (3.0 * std::f64::consts::FRAC_PI_4..5.0 * std::f64::consts::FRAC_PI_4).contains(&normalized_angle) {
+                    '/' // Left-pointing ship, this is top-right
+                } else { // 5*PI/4 to 7*PI/4
+                    '\u{005C}' // Up-pointing ship, this is bottom-right
+                }
+            },
+            _ => ' ', // Should not happen for a triangle
+        }
+    }
+}
+
+impl Entity for Ship {
+    // `dt` is in seconds; velocities and friction are tuned per simulation tick.
+    fn update(&mut self, ctx: &UpdateContext) {
+        let ticks = ctx.dt / tick_seconds();
+        self.position = self.position.add(self.velocity.scale(ticks * self.status.speed_factor()));
+        self.velocity = self.velocity.scale(self.friction.powf(ticks));
+
+        self.angle += self.angular_velocity * ticks;
+        self.angular_velocity *= self.angular_friction.powf(ticks);
+        self.energy.regenerate(ctx.dt);
+
+        // Screen wrapping
+        self.position.x = wrap_coordinate(self.position.x, ctx.world_width as f64);
+        self.position.y = wrap_coordinate(self.position.y, ctx.world_height as f64);
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        if game_grid.hires {
+            let corners = self.corners();
+            for (i, &from) in corners.iter().enumerate() {
+                game_grid.plot_line(from, corners[(i + 1) % corners.len()], self.color);
+            }
+        } else {
+            // Edges first, so the corners' own glyphs end up on top. At the base size the corners touch and the only
+            // edge cell is the middle of the base.
+            let corners = self.drawn_coords();
+            for (i, &from) in corners.iter().enumerate() {
+                let to = corners[(i + 1) % corners.len()];
+                game_grid.line(from, to, line_glyph(from, to), self.color);
+            }
+            // The unscaled shape picks the glyph, so a grown ship keeps its nose and wing characters
+            for (&(dx, dy), &(draw_x, draw_y)) in self.shape.iter().zip(&corners) {
+                let char_to_draw = Ship::get_rotated_char(dx, dy, self.angle);
+                game_grid.set_cell(draw_x, draw_y, char_to_draw, self.color);
+            }
+        }
+
+        // Draw aiming indicator
+        let aiming_distance = 3.0;
+        let aim_x = (self.position.x + self.angle.cos() * aiming_distance * TERMINAL_ASPECT_RATIO_COMPENSATION).round() as u16;
+        let aim_y = (self.position.y + self.angle.sin() * aiming_distance).round() as u16;
+        game_grid.set_cell(aim_x, aim_y, '●', self.color);
+
+        // Draw shield
+        if self.shield_count > 0 {
+            let shield_char = '#';
+            // For simplicity, let's draw the shield behind the ship for now
+            // We can make this more sophisticated later to cover a specific side
+            let shield_x = (self.position.x - self.angle.cos() * 2.0).round() as u16;
+            let shield_y = (self.position.y - self.angle.sin() * 2.0).round() as u16;
+            game_grid.set_cell(shield_x, shield_y, shield_char, self.color);
+        }
+        self.status.draw_indicator(game_grid, self.position);
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        self.get_scaled_shape().iter().map(|&(dx, dy)| {
+            // Rotate the relative coordinates
+            let rotated_x = dx * self.angle.cos() - dy * self.angle.sin();
+            let rotated_y = dx * self.angle.sin() + dy * self.angle.cos();
+
+            // Translate to absolute position and convert to u16
+            ((self.position.x + rotated_x).round() as u16, (self.position.y + rotated_y).round() as u16)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::active_effects::PowerUp;
+    use crate::clock::ticks_for;
+
+    #[test]
+    fn rapid_fire_wears_off_after_its_duration() {
+        let mut ship = Ship::new(10.0, 10.0);
+        let cooldown = ship.shot_cooldown();
+        ship.power_ups.activate(PowerUp::RapidFire);
+        assert!(ship.shot_cooldown() < cooldown);
+        for _ in 1..ticks_for(RAPID_FIRE_DURATION) {
+            assert!(ship.power_ups.tick().is_empty());
+        }
+        assert_eq!(ship.power_ups.tick(), vec![PowerUp::RapidFire]);
+        assert_eq!(ship.shot_cooldown(), cooldown);
+    }
+}
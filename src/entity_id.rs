@@ -0,0 +1,69 @@
+// --- Generational entity IDs: stable references that survive Vec removals ---
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EntityId {
+    index: u32,
+    generation: u32,
+}
+
+impl EntityId {
+    // Slot in the allocator; reused once the entity holding it is released.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+pub trait Identified {
+    fn id(&self) -> EntityId;
+}
+
+pub fn find_by_id<T: Identified>(items: &[T], id: EntityId) -> Option<&T> {
+    items.iter().find(|item| item.id() == id)
+}
+
+// Hands out IDs, recycling released slots with a bumped generation so stale IDs never match.
+#[derive(Clone)]
+pub struct EntityAllocator {
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    free: Vec<u32>,
+}
+
+impl EntityAllocator {
+    pub fn new() -> Self {
+        EntityAllocator { generations: Vec::new(), alive: Vec::new(), free: Vec::new() }
+    }
+
+    pub fn allocate(&mut self) -> EntityId {
+        if let Some(index) = self.free.pop() {
+            self.alive[index as usize] = true;
+            EntityId { index, generation: self.generations[index as usize] }
+        } else {
+            let index = self.generations.len() as u32;
+            self.generations.push(0);
+            self.alive.push(true);
+            EntityId { index, generation: 0 }
+        }
+    }
+
+    pub fn release(&mut self, id: EntityId) -> bool {
+        if !self.is_alive(id) {
+            return false;
+        }
+        let slot = id.index as usize;
+        self.alive[slot] = false;
+        self.generations[slot] = self.generations[slot].wrapping_add(1);
+        self.free.push(id.index);
+        true
+    }
+
+    pub fn is_alive(&self, id: EntityId) -> bool {
+        let slot = id.index as usize;
+        slot < self.generations.len() && self.alive[slot] && self.generations[slot] == id.generation
+    }
+}
+
+impl Default for EntityAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
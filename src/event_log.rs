@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+
+use crossterm::style::Color;
+
+use crate::clock;
+use crate::constants::*;
+use crate::entities::AsteroidSize;
+use crate::events::GameEvent;
+use crate::rendering::GameGrid;
+
+// --- EventLog: kill-feed style list of recent game events, newest at the bottom ---
+pub struct EventLog {
+    entries: VecDeque<(u64, String, Color)>, // Tick logged, text, colour
+    pub visible: bool,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        EventLog { entries: VecDeque::new(), visible: false }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn record(&mut self, event: &GameEvent, tick: u64) {
+        let (text, color) = match *event {
+            GameEvent::AsteroidDestroyed { size, points, .. } => {
+                let size = match size {
+                    AsteroidSize::Large => "Large",
+                    AsteroidSize::Medium => "Medium",
+                    AsteroidSize::Small => "Small",
+                };
+                (format!("{} asteroid destroyed +{}", size, points), ASTEROID_COLOR)
+            }
+            GameEvent::MultiKill { count, .. } => (format!("{}x multi-kill", count), BULLET_COLOR),
+            GameEvent::Juggle { chain, bonus, .. } => (format!("Juggle x{} +{}", chain, bonus), BULLET_COLOR),
+            GameEvent::BountyPosted { .. } => ("Bounty posted".to_string(), BOUNTY_COLOR),
+            GameEvent::BountyClaimed { bonus, .. } => (format!("Bounty claimed +{}", bonus), BOUNTY_COLOR),
+            GameEvent::BountyExpired => ("Bounty expired".to_string(), BOUNTY_COLOR),
+            GameEvent::ShipDamaged { shield: true } => ("Shield lost".to_string(), SHIP_COLOR),
+            GameEvent::ShipDamaged { shield: false } => ("Hull damaged".to_string(), Color::Red),
+            GameEvent::ShipDestroyed => ("Ship destroyed".to_string(), Color::Red),
+            GameEvent::SaucerDestroyed { points, .. } => (format!("Saucer destroyed +{}", points), SAUCER_COLOR),
+            GameEvent::BossArrived { .. } => ("Boss incoming".to_string(), BOSS_COLOR),
+            GameEvent::BossDestroyed { points, .. } => (format!("Boss destroyed +{}", points), BOSS_COLOR),
+            GameEvent::WaveStarted { wave } => (format!("Wave {}", wave), Color::White),
+            GameEvent::UpgradeBoxSpawned { .. } => ("Upgrade box spawned".to_string(), UPGRADE_COLOR),
+            GameEvent::UpgradeCollected { upgrade_type } => (format!("Upgrade: {}", upgrade_type.name()), UPGRADE_COLOR),
+            GameEvent::BombDropped { .. } => ("Bomb dropped".to_string(), SHOCKWAVE_COLOR),
+            GameEvent::PowerUpExpired { power_up } => (format!("{} wore off", power_up.label()), POWER_UP_COLOR),
+        };
+        self.entries.push_back((tick, text, color));
+        if self.entries.len() > EVENT_LOG_LINES {
+            self.entries.pop_front();
+        }
+    }
+
+    // Right-aligned against the screen edge from row `top` down; entries older than EVENT_LOG_LIFETIME drop off.
+    pub fn draw(&mut self, game_grid: &mut GameGrid, top: u16, tick: u64) {
+        let lifetime = clock::ticks_for(EVENT_LOG_LIFETIME);
+        self.entries.retain(|&(logged, _, _)| tick.saturating_sub(logged) < lifetime);
+        if !self.visible {
+            return;
+        }
+        for (i, (_, text, color)) in self.entries.iter().enumerate() {
+            let x = game_grid.width.saturating_sub(text.chars().count() as u16 + 1);
+            game_grid.write_str(x, top.saturating_add(i as u16), text, *color);
+        }
+    }
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,49 @@
+use crate::active_effects::PowerUp;
+use crate::entities::AsteroidSize;
+use crate::types::Vector2D;
+use crate::upgrades::UpgradeType;
+
+// --- Events published by the simulation for feedback and presentation systems ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameEvent {
+    AsteroidDestroyed { size: AsteroidSize, position: Vector2D, points: u32 },
+    MultiKill { count: usize, position: Vector2D },
+    Juggle { chain: u32, bonus: u32, position: Vector2D },
+    BountyPosted { position: Vector2D },
+    BountyClaimed { bonus: u32, position: Vector2D },
+    BountyExpired,
+    ShipDamaged { shield: bool }, // `shield`: a shield charge took the hit instead of health
+    ShipDestroyed,
+    SaucerDestroyed { position: Vector2D, points: u32 },
+    BossArrived { wave: usize },
+    BossDestroyed { position: Vector2D, points: u32 },
+    WaveStarted { wave: usize },
+    UpgradeBoxSpawned { position: Vector2D },
+    UpgradeCollected { upgrade_type: UpgradeType },
+    BombDropped { position: Vector2D },
+    PowerUpExpired { power_up: PowerUp },
+}
+
+pub struct EventBus {
+    queue: Vec<GameEvent>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        EventBus { queue: Vec::new() }
+    }
+
+    pub fn publish(&mut self, event: GameEvent) {
+        self.queue.push(event);
+    }
+
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.queue)
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use crate::clock::{self, GameClock, Timer};
+use crate::entities::AsteroidSize;
+use crate::events::GameEvent;
+
+pub const SCREEN_FLASH_DURATION: Duration = Duration::from_millis(150);
+pub const SCREEN_SHAKE_DURATION: Duration = Duration::from_millis(250);
+
+// --- Player feedback (screen flash, controller rumble) derived from game events ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeedbackEvent {
+    Damage,
+    BigExplosion,
+}
+
+impl FeedbackEvent {
+    pub fn from_game_event(event: &GameEvent) -> Option<Self> {
+        match event {
+            GameEvent::ShipDamaged { .. } => Some(FeedbackEvent::Damage),
+            GameEvent::AsteroidDestroyed { size: AsteroidSize::Large, .. } => Some(FeedbackEvent::BigExplosion),
+            GameEvent::MultiKill { .. } => Some(FeedbackEvent::BigExplosion),
+            GameEvent::SaucerDestroyed { .. } => Some(FeedbackEvent::BigExplosion),
+            GameEvent::BossDestroyed { .. } => Some(FeedbackEvent::BigExplosion),
+            GameEvent::BombDropped { .. } => Some(FeedbackEvent::BigExplosion),
+            _ => None,
+        }
+    }
+}
+
+pub trait FeedbackSink {
+    fn trigger(&mut self, event: FeedbackEvent, clock: &GameClock);
+}
+
+// Brief inverse-video flash of the whole screen when the ship takes damage.
+pub struct ScreenFlash {
+    timer: Timer,
+}
+
+impl ScreenFlash {
+    pub fn new() -> Self {
+        ScreenFlash { timer: Timer::default() }
+    }
+
+    pub fn is_active(&self, clock: &GameClock) -> bool {
+        self.timer.is_running(clock)
+    }
+}
+
+impl Default for ScreenFlash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedbackSink for ScreenFlash {
+    fn trigger(&mut self, event: FeedbackEvent, clock: &GameClock) {
+        if event == FeedbackEvent::Damage {
+            self.timer = clock.timer(SCREEN_FLASH_DURATION);
+        }
+    }
+}
+
+// Jolts the playfield a few cells on damage and big explosions, settling back over SCREEN_SHAKE_DURATION.
+pub struct ScreenShake {
+    pub intensity: u8, // Largest offset in cells; 0 turns shaking off
+    timer: Timer,
+    strength: f64, // Share of `intensity` the current shake started at
+}
+
+impl ScreenShake {
+    pub fn new(intensity: u8) -> Self {
+        ScreenShake { intensity, timer: Timer::default(), strength: 0.0 }
+    }
+
+    // Camera offset for the playfield at this frame. Derived from the frame number, not the game RNG,
+    // so shaking never changes how a seeded run plays out.
+    pub fn offset(&self, clock: &GameClock) -> (i16, i16) {
+        if self.intensity == 0 || !self.timer.is_running(clock) {
+            return (0, 0);
+        }
+        let frame_count = clock.frame();
+        let remaining = self.timer.remaining(clock) as f64 / clock::ticks_for(SCREEN_SHAKE_DURATION) as f64;
+        let magnitude = (self.intensity as f64 * self.strength * remaining).ceil() as i16;
+        let dx = [-1, 1, 0, 1, -1, 0][(frame_count % 6) as usize] * magnitude;
+        let dy = [0, 1, -1, 0, 1, -1][(frame_count % 6) as usize] * ((magnitude + 1) / 2);
+        (dx, dy)
+    }
+}
+
+impl FeedbackSink for ScreenShake {
+    fn trigger(&mut self, event: FeedbackEvent, clock: &GameClock) {
+        let strength = match event {
+            FeedbackEvent::Damage => 1.0,
+            FeedbackEvent::BigExplosion => 0.5,
+        };
+        // A small jolt never cuts short a bigger shake that is still settling
+        if !self.timer.is_running(clock) || strength >= self.strength {
+            self.strength = strength;
+            self.timer = clock.timer(SCREEN_SHAKE_DURATION);
+        }
+    }
+}
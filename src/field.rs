@@ -0,0 +1,97 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::constants::{FIELD_CLEAR_RADIUS, FIELD_PLACEMENT_ATTEMPTS, TERMINAL_ASPECT_RATIO_COMPENSATION, WAVE_BASE_ASTEROIDS};
+use crate::entities::{Asteroid, AsteroidSize};
+use crate::entity_id::EntityAllocator;
+use crate::types::Vector2D;
+
+// --- Field generation: a seeded starting layout of asteroids ---
+
+/// What to put in a generated field and where.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldParams {
+    /// World size in cells; positions fall inside it.
+    pub width: u16,
+    pub height: u16,
+    /// How many asteroids of each size.
+    pub large: usize,
+    pub medium: usize,
+    pub small: usize,
+    /// Scales every asteroid's speed, like `LevelManager::speed_multiplier`.
+    pub speed_multiplier: f64,
+    /// Rows kept free of asteroid centres around the middle of the world, where the ship starts.
+    pub clear_radius: f64,
+}
+
+impl FieldParams {
+    /// A first-wave field: `WAVE_BASE_ASTEROIDS` large asteroids at normal speed, clear around the centre.
+    pub fn new(width: u16, height: u16) -> Self {
+        FieldParams {
+            width,
+            height,
+            large: WAVE_BASE_ASTEROIDS,
+            medium: 0,
+            small: 0,
+            speed_multiplier: 1.0,
+            clear_radius: FIELD_CLEAR_RADIUS,
+        }
+    }
+}
+
+/// Generates a starting field: positions, sizes and velocities all follow from `seed`, so the same seed and
+/// params always give the same asteroids, in the same order (large, then medium, then small).
+///
+/// The field has its own random stream, so generating one never disturbs a game's `StdRng`. IDs come from a
+/// fresh allocator; use `generate_with_ids` to place the field in a running game.
+pub fn generate(seed: u64, params: &FieldParams) -> Vec<Asteroid> {
+    generate_with_ids(seed, params, &mut EntityAllocator::new())
+}
+
+/// `generate`, taking the asteroids' IDs from `ids`.
+pub fn generate_with_ids(seed: u64, params: &FieldParams, ids: &mut EntityAllocator) -> Vec<Asteroid> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let sizes = [(AsteroidSize::Large, params.large), (AsteroidSize::Medium, params.medium), (AsteroidSize::Small, params.small)];
+    let mut asteroids = Vec::new();
+    for (size, count) in sizes {
+        for _ in 0..count {
+            let position = place(params, &mut rng);
+            asteroids.push(Asteroid::new(ids.allocate(), position.x, position.y, &mut rng, size, params.speed_multiplier));
+        }
+    }
+    asteroids
+}
+
+// A random spot outside the clear zone. A world too small to have one gives up after a few tries and uses
+// the last spot picked.
+fn place(params: &FieldParams, rng: &mut StdRng) -> Vector2D {
+    let (width, height) = (params.width.max(1) as f64, params.height.max(1) as f64);
+    let center = Vector2D::new(width / 2.0, height / 2.0);
+    let mut position = center;
+    for _ in 0..FIELD_PLACEMENT_ATTEMPTS {
+        position = Vector2D::new(rng.gen_range(0.0..width), rng.gen_range(0.0..height));
+        let dx = (position.x - center.x) / TERMINAL_ASPECT_RATIO_COMPENSATION;
+        let dy = position.y - center.y;
+        if (dx * dx + dy * dy).sqrt() >= params.clear_radius {
+            break;
+        }
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_gives_the_same_field() {
+        let params = FieldParams { medium: 2, small: 3, ..FieldParams::new(80, 24) };
+        let layout = |asteroids: Vec<Asteroid>| -> Vec<(f64, f64, f64, f64, AsteroidSize)> {
+            asteroids.iter().map(|a| (a.position.x, a.position.y, a.velocity.x, a.velocity.y, a.size)).collect()
+        };
+        let field = layout(generate(42, &params));
+        assert_eq!(field.len(), WAVE_BASE_ASTEROIDS + 5);
+        assert_eq!(field, layout(generate(42, &params)));
+        assert_ne!(field, layout(generate(43, &params)));
+    }
+}
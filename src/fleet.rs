@@ -0,0 +1,83 @@
+use crate::constants::{FLEET_SPACING_X, FLEET_SPACING_Y};
+use crate::input::InputState;
+use crate::player::Player;
+use crate::types::Vector2D;
+
+// Which ships the keyboard steers: all of them mirroring the same input, or one while the rest hold position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FleetControl {
+    All,
+    Ship(usize),
+}
+
+// --- Fleet: --fleet experiment, one player flying two or three ships that share a health pool ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fleet {
+    pub size: usize,
+    pub control: FleetControl,
+}
+
+impl Fleet {
+    pub fn new(size: usize) -> Self {
+        Fleet { size, control: FleetControl::All }
+    }
+
+    // Tab: all ships, then each ship on its own, then back to all.
+    pub fn cycle(&mut self) {
+        self.control = match self.control {
+            FleetControl::All => FleetControl::Ship(0),
+            FleetControl::Ship(index) if index + 1 < self.size => FleetControl::Ship(index + 1),
+            FleetControl::Ship(_) => FleetControl::All,
+        };
+    }
+
+    pub fn controls(&self, index: usize) -> bool {
+        match self.control {
+            FleetControl::All => true,
+            FleetControl::Ship(controlled) => controlled == index,
+        }
+    }
+
+    // One keyboard input becomes one per ship; ships not under control get an idle input.
+    pub fn fan_out(&self, input: InputState) -> Vec<InputState> {
+        (0..self.size).map(|index| if self.controls(index) { input } else { InputState::default() }).collect()
+    }
+
+    pub fn label(&self) -> String {
+        match self.control {
+            FleetControl::All => format!("Fleet: all {} ships (Tab to switch)", self.size),
+            FleetControl::Ship(index) => format!("Fleet: {} (Tab to switch)", ship_name(index)),
+        }
+    }
+}
+
+fn ship_name(index: usize) -> &'static str {
+    match index {
+        0 => "lead",
+        1 => "left wing",
+        _ => "right wing",
+    }
+}
+
+// Where a ship sits relative to the lead: a V opening behind it, wings alternating left and right.
+pub fn formation_offset(index: usize) -> Vector2D {
+    if index == 0 {
+        return Vector2D::new(0.0, 0.0);
+    }
+    let rank = index.div_ceil(2) as f64;
+    let side = if index % 2 == 1 { -1.0 } else { 1.0 };
+    Vector2D::new(side * rank * FLEET_SPACING_X, rank * FLEET_SPACING_Y)
+}
+
+// Keeps the pool in step after anything that changes one ship's health: every ship in play takes the best value.
+pub fn share_health(players: &mut [Player]) {
+    let Some(pool) = pool(players) else { return };
+    for player in players.iter_mut().filter(|player| player.in_play()) {
+        player.health = pool;
+    }
+}
+
+// The pool's current health, or None while no ship is in play.
+pub fn pool(players: &[Player]) -> Option<u32> {
+    players.iter().filter(|player| player.in_play()).map(|player| player.health).max()
+}
@@ -0,0 +1,95 @@
+use rand::Rng;
+
+use crate::constants::TERMINAL_ASPECT_RATIO_COMPENSATION;
+use crate::types::{Vector2D, wrap_coordinate};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FormationKind {
+    Edge,   // One asteroid from a random edge in a random direction
+    Pincer, // Two asteroids from opposite edges heading at each other
+    Ring,   // A ring around the centre closing in
+    Stream, // A line of asteroids travelling along the same lane
+}
+
+// --- Formation table: which patterns the spawner may pick and when ---
+pub struct Formation {
+    pub name: &'static str,
+    pub kind: FormationKind,
+    pub count: usize,
+    pub min_level: usize,
+    pub weight: u32,
+}
+
+pub const FORMATIONS: &[Formation] = &[
+    Formation { name: "edge", kind: FormationKind::Edge, count: 1, min_level: 1, weight: 6 },
+    Formation { name: "pincer", kind: FormationKind::Pincer, count: 2, min_level: 2, weight: 2 },
+    Formation { name: "stream", kind: FormationKind::Stream, count: 3, min_level: 2, weight: 2 },
+    Formation { name: "ring", kind: FormationKind::Ring, count: 6, min_level: 3, weight: 1 },
+];
+
+pub struct SpawnOrder {
+    pub position: Vector2D,
+    pub heading: Option<f64>, // Radians; None keeps the asteroid's random direction
+}
+
+// Weighted pick among formations unlocked at `level` that fit in the remaining asteroid budget.
+pub fn choose(level: usize, budget: usize, rng: &mut impl Rng) -> &'static Formation {
+    let eligible: Vec<&Formation> = FORMATIONS.iter()
+        .filter(|formation| formation.min_level <= level && formation.count <= budget)
+        .collect();
+    let total_weight: u32 = eligible.iter().map(|formation| formation.weight).sum();
+    if total_weight == 0 {
+        return &FORMATIONS[0];
+    }
+    let mut roll = rng.gen_range(0..total_weight);
+    for formation in &eligible {
+        if roll < formation.weight {
+            return formation;
+        }
+        roll -= formation.weight;
+    }
+    &FORMATIONS[0]
+}
+
+pub fn plan(formation: &Formation, width: u16, height: u16, rng: &mut impl Rng) -> Vec<SpawnOrder> {
+    let (w, h) = (width as f64, height as f64);
+    match formation.kind {
+        FormationKind::Edge => {
+            let position = match rng.gen_range(0..4) {
+                0 => Vector2D::new(rng.gen_range(0.0..w), 0.0),
+                1 => Vector2D::new(w - 1.0, rng.gen_range(0.0..h)),
+                2 => Vector2D::new(rng.gen_range(0.0..w), h - 1.0),
+                _ => Vector2D::new(0.0, rng.gen_range(0.0..h)),
+            };
+            vec![SpawnOrder { position, heading: None }]
+        }
+        FormationKind::Pincer => {
+            let y = rng.gen_range(0.0..h);
+            vec![
+                SpawnOrder { position: Vector2D::new(0.0, y), heading: Some(0.0) },
+                SpawnOrder { position: Vector2D::new(w - 1.0, y), heading: Some(std::f64::consts::PI) },
+            ]
+        }
+        FormationKind::Ring => {
+            let center = Vector2D::new(w / 2.0, h / 2.0);
+            let radius = (h / 2.0 - 1.0).min(w / (2.0 * TERMINAL_ASPECT_RATIO_COMPENSATION) - 1.0).max(1.0);
+            let offset = rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+            (0..formation.count).map(|i| {
+                let angle = offset + i as f64 * 2.0 * std::f64::consts::PI / formation.count as f64;
+                let position = Vector2D::new(
+                    center.x + angle.cos() * radius * TERMINAL_ASPECT_RATIO_COMPENSATION,
+                    center.y + angle.sin() * radius,
+                );
+                SpawnOrder { position, heading: Some(angle + std::f64::consts::PI) }
+            }).collect()
+        }
+        FormationKind::Stream => {
+            let lane_y = rng.gen_range(0.0..h);
+            let spacing = 6.0 * TERMINAL_ASPECT_RATIO_COMPENSATION;
+            (0..formation.count).map(|i| {
+                let x = wrap_coordinate(-(i as f64) * spacing, w);
+                SpawnOrder { position: Vector2D::new(x, lane_y), heading: Some(0.0) }
+            }).collect()
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::info;
+
+// --- FramePacer: holds live play to a steady frame rate ---
+// Each frame gets an equal slice of a second. Whatever the frame didn't use is slept off at its end, and input
+// that arrives meanwhile is picked up at the start of the next one. The simulation follows the wall clock on its
+// own (see `GameClock`), so the frame rate changes how smooth the game looks, not how fast it plays.
+pub struct FramePacer {
+    budget: Duration,
+    deadline: Instant,
+    dropped: u64,
+}
+
+impl FramePacer {
+    pub fn new(fps: u32) -> Self {
+        let budget = Duration::from_secs(1) / fps.max(1);
+        FramePacer { budget, deadline: Instant::now() + budget, dropped: 0 }
+    }
+
+    // Sleeps out the rest of the frame's budget. A frame that overran it drops every frame slot it ate into, and
+    // the schedule restarts from now instead of rushing the following frames to catch up.
+    pub fn wait(&mut self, frame: u64) {
+        let now = Instant::now();
+        if now <= self.deadline {
+            thread::sleep(self.deadline - now);
+            self.deadline += self.budget;
+            return;
+        }
+        let late = now - self.deadline;
+        self.dropped += (late.as_nanos() / self.budget.as_nanos()) as u64 + 1;
+        info!("Frame {} ran {:?} over its {:?} budget ({} dropped so far).", frame, late, self.budget, self.dropped);
+        self.deadline = now + self.budget;
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
@@ -1,26 +1,145 @@
-use std::io::{self, Read, Write};
-use std::time::Duration;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
 use crossterm::{ 
-    cursor::{MoveTo},
-    event::{self, Event, KeyCode},
+    event::{self, Event, KeyCode, KeyEventKind},
+    style::Color,
 };
-use rand::Rng;
-use log::error;
+use log::{error, info};
 
 use crate::constants::*;
-use crate::types::Vector2D;
-use crate::rendering::{GameGrid, Minimap, OutputTarget};
-use crate::entities::{Asteroid, Bullet, Particle, Ship, AsteroidSize};
-use crate::upgrades::{Upgrade, UpgradeBox, UpgradeType};
+use crate::rendering::{GameGrid, Minimap, OutputTarget, ScreenBuffer};
+use crate::effects::EffectsTheme;
 use crate::terminal_io::SimulatedInput;
+use crate::pilot::Pilot;
+use crate::profiler::{Phase, Profiler};
+use crate::frame_pacer::FramePacer;
+use crate::input::{HeldKeys, InputThread};
+use crate::gamepad::GamepadBackend;
+use crate::observer::Observer;
+#[cfg(feature = "overlays")]
+use crate::director_stats::DirectorStats;
+use crate::replay::{ReplayRecorder, RunSettings};
+use crate::events::EventBus;
+use crate::clock::{self, GameClock};
+use crate::input_macros::InputMacros;
+use crate::assist::AssistSettings;
+use crate::versus::RoundSummary;
+use crate::mode_rules::{Goal, ModeRules};
+use crate::balance::UpgradeBalance;
+use crate::loadout::Loadout;
+use crate::preview::SeedPreview;
+use crate::practice::{Practice, Snapshot};
+use crate::quick_save::{QuickSave, SavedGame};
+use crate::death_map::DeathMap;
+use crate::world::World;
+use crate::high_scores::HighScores;
+use crate::run_stats::RunStats;
+use crate::cutscene::Cutscene;
+use crate::credits::Credits;
+use crate::backdrop::Backdrop;
+#[cfg(feature = "score-stream")]
+use crate::score_stream::ScoreStream;
+use crate::tournament::Ruleset;
+use crate::config::Config;
+use crate::difficulty::Difficulty;
+use crate::hud::{self, Panel};
+use crate::scoring::ScoringMode;
+use crate::event_log::EventLog;
+use crate::feedback::{ScreenFlash, ScreenShake};
+#[cfg(feature = "audio")]
+use crate::audio::{AudioBackend, Mixer, SilentAudio};
+use crate::idle_decay::IdleDecay;
+use crate::terminal_status::TerminalStatus;
+
+mod attract;
+mod bullet_hits;
+mod controls;
+mod game_over;
+mod hud_lines;
+mod quick_saves;
+mod session;
+mod systems;
+
+pub struct RunSummary {
+    pub score: u32,
+    pub frames: u64,
+    pub wave: usize, // The wave the run ended on
+    pub stats: RunStats,
+    pub state_hash: u64,
+}
 
 pub struct Game {
-    pub terminal_width: u16,
+    pub terminal_width: u16, // Playfield size: the whole terminal unless it is letterboxed
     pub terminal_height: u16,
+    screen_width: u16,
+    screen_height: u16,
+    playfield_origin: (u16, u16),
     pub stdout_target: OutputTarget,
     simulated_input: Option<SimulatedInput>,
     debug_mode_active: bool,
     max_frames: Option<u64>,
+    #[cfg(feature = "overlays")]
+    show_density_heatmap: bool,
+    #[cfg(feature = "overlays")]
+    show_difficulty_chart: bool,
+    #[cfg(feature = "overlays")]
+    director_stats: DirectorStats,
+    seed: u64,
+    pub headless: bool, // Skip all rendering and interstitial screens (replay verification)
+    pub replay_recorder: Option<ReplayRecorder>,
+    event_bus: EventBus,
+    clock: GameClock,
+    input: Option<InputThread>, // Live runs read the terminal through this
+    held_keys: HeldKeys,
+    pub gamepad: Option<Box<dyn GamepadBackend>>,
+    observers: Vec<Box<dyn Observer>>,
+    input_macros: InputMacros,
+    pub assist: AssistSettings,
+    screen_flash: ScreenFlash,
+    screen_shake: ScreenShake,
+    #[cfg(feature = "audio")]
+    pub audio: Box<dyn AudioBackend>,
+    #[cfg(feature = "audio")]
+    mixer: Mixer, // Music and effect levels for `audio`, reset each session
+    pub idle_decay: Option<IdleDecay>,
+    pub terminal_status: Option<TerminalStatus>,
+    pub backdrop: Backdrop,
+    #[cfg(feature = "score-stream")]
+    pub score_stream: Option<ScoreStream>,
+    pub step_script: Option<HashMap<u64, u32>>, // Recorded ticks per frame when replaying
+    pub ruleset: Option<Ruleset>, // Tournament mode: seed and mutators are locked
+    pub config: Config,
+    pub seed_locked: bool, // --seed or a tournament ruleset: restarts replay the same seed
+    pub starting_lives: u32,
+    pub scoring_mode: ScoringMode, // --scoring: rule set for kills
+    pub world_scale: u16, // --world-scale: the world is this many screens across and down
+    pub fixed_world: bool, // --fixed-world: simulate FIXED_WORLD_WIDTH x FIXED_WORLD_HEIGHT and scale it to the screen
+    pub hires: bool, // --hires: Braille dots for this session only; `hires true` in the profile makes it stick
+    pub coop: bool, // --coop: a second player shares the field
+    pub versus: Option<u32>, // --versus: two players duel to this many kills
+    pub fleet: Option<usize>, // --fleet: one player flies this many ships
+    versus_round: Option<RoundSummary>, // How the last versus round ended, for the summary screen
+    pub goal: Goal, // --target-score / --waves; versus and tournaments set their own
+    event_log: EventLog,
+    pub upgrade_balance: UpgradeBalance,
+    pub loadouts: Vec<Loadout>, // Presets from the profile, offered after Start Game
+    pub effects: EffectsTheme, // How particle effects look, from the profile's effects file
+    pub loadout: Option<Loadout>, // The preset this run was started with
+    pub preview: Option<SeedPreview>, // `preview` subcommand: the ship can't be hit and the run is logged as a timeline
+    pub practice: Option<Practice>, // --practice: F5/F9 save and restore a checkpoint
+    pub quick_save: Option<QuickSave>, // Live runs outside practice: F5/F9 save and load the game on disk
+    resume: Option<SavedGame>, // A quick save loaded with F9, which the next session starts from
+    pending_resize: Option<(u16, u16)>, // The screen size a quick-loaded game moves to on its first frame
+    pub final_snapshot: Option<Snapshot>, // Headless sessions leave their last moment here, for quick-load
+    pub practice_field: Option<(usize, usize, usize)>, // --practice-field: large, medium and small asteroids to start with
+    pub high_scores: Option<HighScores>, // Live, non-practice runs enter the local table
+    pub death_map: Option<DeathMap>, // Live runs log every lost life; practice mode can overlay them with F4
+    #[cfg(feature = "overlays")]
+    show_death_map: bool,
+    pub pilot: Option<Box<dyn Pilot>>, // Flies player one instead of the keyboard; any key ends the session
+    pub profiler: Option<Profiler>, // --bench / --profile-log / --profile-flame: times each phase of every frame
+    pub fps: u32, // --fps: frame rate live play is paced to
 }
 
 impl Game {
@@ -31,475 +150,418 @@ impl Game {
         simulated_input: Option<SimulatedInput>,
         debug_mode_active: bool,
         max_frames: Option<u64>,
+        seed: u64,
     ) -> Self {
         Game {
             terminal_width,
             terminal_height,
+            screen_width: terminal_width,
+            screen_height: terminal_height,
+            playfield_origin: (0, 0),
             stdout_target,
             simulated_input,
             debug_mode_active,
             max_frames,
+            #[cfg(feature = "overlays")]
+            show_density_heatmap: false,
+            #[cfg(feature = "overlays")]
+            show_difficulty_chart: false,
+            #[cfg(feature = "overlays")]
+            director_stats: DirectorStats::new(),
+            seed,
+            headless: false,
+            replay_recorder: None,
+            event_bus: EventBus::new(),
+            clock: GameClock::new(),
+            input: (!debug_mode_active).then(InputThread::spawn),
+            held_keys: HeldKeys::new(),
+            gamepad: None,
+            observers: Vec::new(),
+            input_macros: InputMacros::new(),
+            assist: AssistSettings::default(),
+            screen_flash: ScreenFlash::new(),
+            screen_shake: ScreenShake::new(0),
+            #[cfg(feature = "audio")]
+            audio: Box::new(SilentAudio),
+            #[cfg(feature = "audio")]
+            mixer: Mixer::default(),
+            idle_decay: None,
+            terminal_status: None,
+            backdrop: Backdrop::new(false),
+            #[cfg(feature = "score-stream")]
+            score_stream: None,
+            step_script: None,
+            ruleset: None,
+            config: Config::default(),
+            seed_locked: false,
+            starting_lives: STARTING_LIVES,
+            scoring_mode: ScoringMode::Classic,
+            world_scale: 1,
+            fixed_world: false,
+            hires: false,
+            coop: false,
+            versus: None,
+            fleet: None,
+            versus_round: None,
+            goal: Goal::Endless,
+            event_log: EventLog::new(),
+            practice: None,
+            quick_save: None,
+            resume: None,
+            pending_resize: None,
+            final_snapshot: None,
+            practice_field: None,
+            upgrade_balance: UpgradeBalance::new(),
+            loadouts: Vec::new(),
+            effects: EffectsTheme::classic(),
+            loadout: None,
+            preview: None,
+            high_scores: None,
+            death_map: None,
+            #[cfg(feature = "overlays")]
+            show_death_map: false,
+            pilot: None,
+            profiler: None,
+            fps: DEFAULT_FPS,
         }
     }
 
-    pub fn run(&mut self) -> io::Result<()> {
-        if !self.debug_mode_active {
-            self.show_title_screen()?;
+    // Adds the time since `started` to `phase`'s share of this frame, when profiling.
+    fn profile(&mut self, phase: Phase, started: Instant) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record(phase, started.elapsed());
         }
+    }
 
-        let mut ship = Ship::new(self.terminal_width as f64 / 2.0, self.terminal_height as f64 / 2.0);
-        let mut asteroids: Vec<Asteroid> = Vec::new();
-        let mut bullets: Vec<Bullet> = Vec::new();
-        let mut particles: Vec<Particle> = Vec::new();
-        let mut upgrade_boxes: Vec<UpgradeBox> = Vec::new();
-        let mut upgrades: Vec<Upgrade> = Vec::new();
-        let mut player_health = ship.max_health;
-        let mut last_shot_frame = 0;
-        let mut last_hit_frame = 0;
-        let mut rng = rand::thread_rng();
-
-        let mut running = true;
-        let mut frame_count = 0;
-        let mut score = 0;
-        let mut asteroid_spawn_rate = INITIAL_ASTEROID_SPAWN_RATE;
-        let mut max_asteroids = INITIAL_MAX_ASTEROIDS;
-        let mut difficulty_increase_timer = 0;
-        let mut game_speed_multiplier = INITIAL_GAME_SPEED_MULTIPLIER;
+    // The simulated world: `world_scale` viewports across and down, wrapping at its edges.
+    // A fixed world is the same size whatever the terminal.
+    pub fn world_size(&self) -> (u16, u16) {
+        if self.run_fixed_world() {
+            return (FIXED_WORLD_WIDTH, FIXED_WORLD_HEIGHT);
+        }
+        let scale = self.run_world_scale();
+        (self.terminal_width.saturating_mul(scale), self.terminal_height.saturating_mul(scale))
+    }
 
-        let mut game_grid = GameGrid::new(self.terminal_width, self.terminal_height);
-        let mut minimap = Minimap::new(20, 20, self.terminal_width);
+    // How much of the world is on screen at once. A fixed world is all in view, scaled to the playfield.
+    fn viewport(&self) -> (u16, u16) {
+        if self.run_fixed_world() { self.world_size() } else { (self.terminal_width, self.terminal_height) }
+    }
 
-        let mut current_banner: Option<(String, u64)> = None;
+    // Tournaments, co-op and versus are always played on a single-screen world.
+    pub fn run_world_scale(&self) -> u16 {
+        if self.ruleset.is_some() || self.coop || self.versus.is_some() || self.run_fixed_world() { 1 } else { self.world_scale }
+    }
 
-        while running && (self.max_frames.is_none() || frame_count < self.max_frames.unwrap()) {
-            game_grid.clear();
-            minimap.clear();
-
-            self.handle_input(&mut running, &mut ship, &mut bullets, &mut particles, frame_count, &mut last_shot_frame)?;
-
-            self.update_game_state(
-                &mut ship,
-                &mut asteroids,
-                &mut bullets,
-                &mut particles,
-                &mut upgrade_boxes,
-                &mut upgrades,
-                &mut player_health,
-                &mut last_hit_frame,
-                &mut score,
-                &mut asteroid_spawn_rate,
-                &mut max_asteroids,
-                &mut difficulty_increase_timer,
-                &mut game_speed_multiplier,
-                &mut running,
-                &mut rng,
-                frame_count,
-                &mut current_banner,
-            );
-
-            // Draw game state onto GameGrid
-            ship.draw(&mut game_grid);
-            for asteroid in &asteroids {
-                asteroid.draw(&mut game_grid);
-            }
-            for bullet in &bullets {
-                bullet.draw(&mut game_grid);
-            }
-            for particle in &particles {
-                particle.draw(&mut game_grid);
-            }
-            for upgrade_box in &upgrade_boxes {
-                upgrade_box.draw(&mut game_grid);
-            }
-            for upgrade in &upgrades {
-                upgrade.draw(&mut game_grid);
-            }
+    // Tournaments are always played in the fixed world, so entrants on big terminals don't see more of the
+    // field or face a thinner one.
+    pub fn run_fixed_world(&self) -> bool {
+        self.ruleset.is_some() || self.fixed_world
+    }
 
-            self.render(&game_grid, &minimap, score, player_health, ship.max_health, &current_banner)?;
+    // Co-op and versus put a second player on the keyboard; a fleet is still one player.
+    fn two_player(&self) -> bool {
+        self.run_coop() || self.run_versus().is_some()
+    }
 
-            frame_count += 1;
-        }
+    // Ships in the fleet, for a --fleet run. Tournaments are always flown with one ship.
+    pub fn run_fleet(&self) -> Option<usize> {
+        self.fleet.filter(|_| self.ruleset.is_none())
+    }
 
-        self.show_game_over_screen(score)?; 
-        Ok(())
+    // Tournaments are always single-player.
+    pub fn run_coop(&self) -> bool {
+        self.coop && self.ruleset.is_none()
     }
 
-    fn handle_input(
-        &mut self,
-        running: &mut bool,
-        ship: &mut Ship,
-        bullets: &mut Vec<Bullet>,
-        particles: &mut Vec<Particle>,
-        frame_count: u64,
-        last_shot_frame: &mut u64,
-    ) -> io::Result<()> {
-        let mut current_event: Option<Event> = None;
-        if self.debug_mode_active {
-            if let Some(sim_input) = &mut self.simulated_input {
-                if sim_input.poll(frame_count)? {
-                    current_event = Some(sim_input.read()?);
-                }
-            }
-        } else {
-            if event::poll(Duration::from_millis(50)).map_err(|e| { error!("Failed to poll event: {}", e); e })? {
-                current_event = Some(event::read().map_err(|e| { error!("Failed to read event: {}", e); e })?);
-            }
+    // How this run can be won: versus plays to its kill target, and tournaments are always endless.
+    pub fn run_rules(&self) -> ModeRules {
+        match (self.run_versus(), &self.ruleset) {
+            (Some(kills), _) => ModeRules::new(Goal::Kills(kills)),
+            (None, Some(_)) => ModeRules::default(),
+            (None, None) => ModeRules::new(self.goal),
         }
+    }
 
-        if let Some(event) = current_event {
-            match event {
-                Event::Key(key_event) => match key_event.code {
-                    KeyCode::Char('q') => *running = false,
-                    KeyCode::Up => {
-                        ship.thrust();
-                        let smoke_velocity = Vector2D::new(-ship.angle.cos() * 0.5, -ship.angle.sin() * 0.5);
-                        particles.push(Particle::new(ship.position, smoke_velocity, 10, '.'));
-                    }
-                    KeyCode::Left => ship.rotate(-1.0),
-                    KeyCode::Right => ship.rotate(1.0),
-                    KeyCode::Char(' ') => {
-                        if frame_count - *last_shot_frame >= BULLET_COOLDOWN {
-                            let bullet_speed = BULLET_SPEED * ship.bullet_speed_multiplier;
-                            let bullet_velocity = Vector2D::new(ship.angle.cos() * bullet_speed, ship.angle.sin() * bullet_speed);
-                            bullets.push(Bullet::new(ship.position, bullet_velocity, ship.bullet_size_multiplier));
-                            *last_shot_frame = frame_count;
-                        }
-                    }
-                    _ => {}
-                },
-                Event::Resize(new_width, new_height) => {
-                    self.terminal_width = new_width;
-                    self.terminal_height = new_height;
-                }
-                _ => {} 
-            }
-        }
-        Ok(())
+    // The kills needed to win, for a versus run.
+    pub fn run_versus(&self) -> Option<u32> {
+        self.versus.filter(|_| self.ruleset.is_none())
     }
 
-    fn update_game_state(
-        &mut self,
-        ship: &mut Ship,
-        asteroids: &mut Vec<Asteroid>,
-        bullets: &mut Vec<Bullet>,
-        particles: &mut Vec<Particle>,
-        upgrade_boxes: &mut Vec<UpgradeBox>,
-        upgrades: &mut Vec<Upgrade>,
-        player_health: &mut u32,
-        last_hit_frame: &mut u64,
-        score: &mut u32,
-        asteroid_spawn_rate: &mut u64,
-        max_asteroids: &mut usize,
-        difficulty_increase_timer: &mut u64,
-        game_speed_multiplier: &mut f64,
-        running: &mut bool,
-        rng: &mut impl Rng,
-        frame_count: u64,
-        current_banner: &mut Option<(String, u64)>,
-    ) {
-        ship.update(self.terminal_width, self.terminal_height);
-
-        if asteroids.len() < *max_asteroids && frame_count % *asteroid_spawn_rate == 0 {
-            let side = rng.gen_range(0..4);
-            let (x, y) = match side {
-                0 => (rng.gen_range(0.0..self.terminal_width as f64), 0.0),
-                1 => (self.terminal_width as f64 - 1.0, rng.gen_range(0.0..self.terminal_height as f64)),
-                2 => (rng.gen_range(0.0..self.terminal_width as f64), self.terminal_height as f64 - 1.0),
-                _ => (0.0, rng.gen_range(0.0..self.terminal_height as f64)),
-            };
-            asteroids.push(Asteroid::new(x, y, rng, AsteroidSize::Large, *game_speed_multiplier));
+    // Tournaments are always scored by the classic rules.
+    pub fn run_scoring(&self) -> ScoringMode {
+        if self.ruleset.is_some() { ScoringMode::Classic } else { self.scoring_mode }
+    }
+
+    // Tournaments are flown without a loadout, so every entrant starts equal.
+    pub fn run_loadout(&self) -> Option<&Loadout> {
+        self.loadout.as_ref().filter(|_| self.ruleset.is_none())
+    }
+
+    // Tournaments are always played on the default difficulty.
+    pub fn run_difficulty(&self) -> Difficulty {
+        if self.ruleset.is_some() { Difficulty::Normal } else { self.config.difficulty }
+    }
+
+    // The settings this run is played under, for the replay to repeat.
+    pub fn run_settings(&self) -> RunSettings {
+        RunSettings {
+            dampeners: self.config.dampeners,
+            difficulty: self.run_difficulty(),
+            scoring: self.run_scoring(),
+            world_scale: self.run_world_scale(),
+            fixed_world: self.run_fixed_world(),
+            lives: self.starting_lives,
+            coop: self.run_coop(),
+            versus: self.run_versus(),
+            fleet: self.run_fleet(),
+            goal: self.run_rules().goal,
+            loadout: self.run_loadout().cloned(),
+            idle_decay: self.idle_decay.is_some(),
         }
+    }
+
+    // Sets up the next run to be played as `settings` say, for replays and quick loads.
+    pub fn apply_run_settings(&mut self, settings: &RunSettings) {
+        self.config.dampeners = settings.dampeners;
+        self.config.difficulty = settings.difficulty;
+        self.scoring_mode = settings.scoring;
+        self.world_scale = settings.world_scale;
+        self.fixed_world = settings.fixed_world;
+        self.starting_lives = settings.lives;
+        self.coop = settings.coop;
+        self.versus = settings.versus;
+        self.fleet = settings.fleet;
+        self.goal = settings.goal;
+        self.loadout = settings.loadout.clone();
+        self.idle_decay = settings.idle_decay.then(|| IdleDecay::new(IDLE_DECAY_GRACE, IDLE_DECAY_INTERVAL, IDLE_DECAY_POINTS));
+    }
 
-        if frame_count % UPGRADE_BOX_SPAWN_RATE == 0 {
-            let x = rng.gen_range(0.0..self.terminal_width as f64);
-            let y = rng.gen_range(0.0..self.terminal_height as f64);
-            upgrade_boxes.push(UpgradeBox::new(x, y));
+    fn save_settings(&self) {
+        if !self.debug_mode_active
+            && let Err(e) = self.config.save()
+        {
+            error!("Failed to save settings: {}", e);
         }
+    }
 
-        *difficulty_increase_timer += 1;
-        if *difficulty_increase_timer >= DIFFICULTY_INCREASE_INTERVAL_FRAMES {
-            *max_asteroids += 1;
-            *asteroid_spawn_rate = (*asteroid_spawn_rate as f64 * ASTEROID_SPAWN_RATE_DECREASE_FACTOR).round() as u64;
-            if *asteroid_spawn_rate < MIN_ASTEROID_SPAWN_RATE {
-                *asteroid_spawn_rate = MIN_ASTEROID_SPAWN_RATE;
-            }
-            *game_speed_multiplier += GAME_SPEED_MULTIPLIER_INCREASE;
-            *difficulty_increase_timer = 0;
+    // Draws the HUD into the grid so it is diffed along with the playfield.
+    fn draw_hud(&self, game_grid: &mut GameGrid, minimap: &Minimap, world: &World) {
+        let (players, current_banner) = (&world.players, &world.banner);
+        if self.config.minimap {
+            minimap.draw(game_grid, self.config.minimap_corner);
         }
 
-        asteroids.retain_mut(|asteroid| {
-            asteroid.update(self.terminal_width, self.terminal_height);
-            let ship_coords = ship.get_absolute_coords();
-            let asteroid_coords = asteroid.get_absolute_coords();
-            let mut collision = false;
-            for ship_point in &ship_coords {
-                if asteroid_coords.contains(ship_point) {
-                    collision = true;
-                    break;
-                }
-            }
-            if collision && frame_count - *last_hit_frame > INVINCIBILITY_FRAMES {
-                if ship.shield_count > 0 {
-                    ship.shield_count -= 1;
-                } else {
-                    *player_health = player_health.saturating_sub(1);
-                }
-                *last_hit_frame = frame_count;
-                if *player_health == 0 {
-                    *running = false;
-                }
-            }
-            true
-        });
-
-        bullets.retain_mut(|bullet| {
-            bullet.update(self.terminal_width, self.terminal_height);
-            let mut hit_asteroid = false;
-            let mut new_asteroids_to_add: Vec<Asteroid> = Vec::new();
-            let bullet_pos = (bullet.position.x.round() as u16, bullet.position.y.round() as u16);
-            asteroids.retain_mut(|asteroid| {
-                let asteroid_coords = asteroid.get_absolute_coords();
-                if asteroid_coords.contains(&bullet_pos) {
-                    hit_asteroid = true;
-                    match asteroid.size {
-                        AsteroidSize::Large => {
-                            *score += SCORE_LARGE_ASTEROID;
-                            let new_x = asteroid.position.x;
-                            let new_y = asteroid.position.y;
-                            new_asteroids_to_add.push(Asteroid::new(new_x, new_y, rng, AsteroidSize::Medium, *game_speed_multiplier));
-                            new_asteroids_to_add.push(Asteroid::new(new_x, new_y, rng, AsteroidSize::Medium, *game_speed_multiplier));
-                        }
-                        AsteroidSize::Medium => {
-                            *score += SCORE_MEDIUM_ASTEROID;
-                            let new_x = asteroid.position.x;
-                            let new_y = asteroid.position.y;
-                            new_asteroids_to_add.push(Asteroid::new(new_x, new_y, rng, AsteroidSize::Small, *game_speed_multiplier));
-                            new_asteroids_to_add.push(Asteroid::new(new_x, new_y, rng, AsteroidSize::Small, *game_speed_multiplier));
-                        }
-                        AsteroidSize::Small => {
-                            *score += SCORE_SMALL_ASTEROID;
-                        }
-                    }
-                    for _ in 0..5 {
-                        let angle = rng.gen_range(0.0..2.0 * std::f64::consts::PI);
-                        let speed = rng.gen_range(0.5..1.5);
-                        let explosion_velocity = Vector2D::new(angle.cos() * speed, angle.sin() * speed);
-                        particles.push(Particle::new(asteroid.position, explosion_velocity, 15, '#'));
-                    }
-                    false
-                } else {
-                    true
-                }
+        let two_player = self.two_player();
+        let status = Panel::new("Status", self.status_lines(world), Color::Reset);
+        let upgrade_lines = if two_player || world.fleet.is_some() {
+            let prefix = if two_player { 'P' } else { 'S' };
+            let per_player = players.iter().enumerate().map(|(index, player)| {
+                self.upgrade_panel_lines(player).into_iter().map(move |line| format!("{}{} {}", prefix, index + 1, line))
             });
-            asteroids.extend(new_asteroids_to_add);
-
-            let mut hit_upgrade_box = false;
-            upgrade_boxes.retain_mut(|upgrade_box| {
-                let upgrade_box_coords = upgrade_box.get_absolute_coords();
-                if upgrade_box_coords.contains(&bullet_pos) {
-                    hit_upgrade_box = true;
-                    upgrade_box.hits_remaining -= 1;
-                    for _ in 0..3 {
-                        let angle = rng.gen_range(0.0..2.0 * std::f64::consts::PI);
-                        let speed = rng.gen_range(0.2..0.8);
-                        let explosion_velocity = Vector2D::new(angle.cos() * speed, angle.sin() * speed);
-                        particles.push(Particle::new(upgrade_box.position, explosion_velocity, 10, '+'));
-                    }
-                    if upgrade_box.hits_remaining == 0 {
-                        let num_upgrades = rng.gen_range(1..=3);
-                        for _ in 0..num_upgrades {
-                            let upgrade_type = match rng.gen_range(0..8) {
-                                0 => UpgradeType::FireRate,
-                                1 => UpgradeType::BulletSpeed,
-                                2 => UpgradeType::BulletSize,
-                                3 => UpgradeType::Booster,
-                                4 => UpgradeType::Shield,
-                                5 => UpgradeType::ShipSize,
-                                6 => UpgradeType::Health,
-                                _ => UpgradeType::HealthMax,
-                            };
-                            upgrades.push(Upgrade::new(upgrade_box.position, upgrade_type));
-                        }
-                        false
-                    } else {
-                        true
-                    }
-                } else {
-                    true
-                }
-            });
-
-            bullet.lifetime > 0 && !hit_asteroid && !hit_upgrade_box
-        });
-
-        particles.retain_mut(|particle| {
-            particle.update();
-            particle.lifetime > 0
-        });
-
-        upgrades.retain_mut(|upgrade| {
-            let distance = ((ship.position.x - upgrade.position.x).powi(2) + (ship.position.y - upgrade.position.y).powi(2)).sqrt();
-            if distance <= UPGRADE_COLLECTION_RADIUS {
-                match upgrade.upgrade_type {
-                    UpgradeType::FireRate => {
-                        ship.fire_rate_multiplier *= 1.1;
-                        *current_banner = Some(("Fire Rate Increased!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::BulletSpeed => {
-                        ship.bullet_speed_multiplier *= 1.1;
-                        *current_banner = Some(("Bullet Speed Increased!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::BulletSize => {
-                        ship.bullet_size_multiplier += 0.5;
-                        *current_banner = Some(("Bullet Size Increased!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::Booster => {
-                        ship.booster_multiplier *= 1.1;
-                        *current_banner = Some(("Booster Power Increased!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::Shield => {
-                        ship.shield_count += 1;
-                        *current_banner = Some(("Shield Added!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::ShipSize => {
-                        ship.ship_size_multiplier += 0.2;
-                        ship.max_health += 1;
-                        *player_health = (*player_health + 1).min(ship.max_health);
-                        *current_banner = Some(("Ship Size Increased!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::Health => {
-                        *player_health = (*player_health + 1).min(ship.max_health);
-                        *current_banner = Some(("Health Restored!".to_string(), frame_count + 60));
-                    }
-                    UpgradeType::HealthMax => {
-                        *player_health = ship.max_health;
-                        *current_banner = Some(("Health Maxed!".to_string(), frame_count + 60));
-                    }
-                }
-                false
-            } else {
-                true
-            }
-        });
+            per_player.flatten().collect()
+        } else {
+            self.upgrade_panel_lines(&players[0])
+        };
+        let upgrades = Panel::new("Upgrades", upgrade_lines, UPGRADE_COLOR);
+        let controls: &[&str] = if two_player {
+            &hud::COOP_CONTROLS
+        } else if world.fleet.is_some() {
+            &hud::FLEET_CONTROLS
+        } else {
+            &hud::CONTROLS
+        };
+        let minimap_corner = self.config.minimap.then_some((self.config.minimap_corner, minimap.height()));
+        hud::draw(game_grid, &status, controls, &upgrades, minimap_corner);
+
+        if let Some((message, banner_timer)) = current_banner
+            && banner_timer.is_running(&self.clock)
+        {
+            let banner_x = (game_grid.width / 2).saturating_sub(message.len() as u16 / 2);
+            let banner_y = (game_grid.height / 2).saturating_sub(5);
+            game_grid.write_str(banner_x, banner_y, message, Color::Reset);
+        }
     }
 
-    fn render(
-        &mut self,
-        game_grid: &GameGrid,
-        minimap: &Minimap,
-        score: u32,
-        player_health: u32,
-        max_health: u32,
-        current_banner: &Option<(String, u64)>,
-    ) -> io::Result<()> {
+    fn render(&mut self, game_grid: &mut GameGrid) -> io::Result<()> {
         if !self.debug_mode_active {
             game_grid.render(&mut self.stdout_target)?;
-        } else {
-            if let OutputTarget::ScreenBuffer(ref mut sb) = self.stdout_target {
-                sb.clear();
-                for y in 0..self.terminal_height {
-                    for x in 0..self.terminal_width {
-                        sb.buffer[y as usize][x as usize] = game_grid.grid[y as usize][x as usize];
-                    }
+        } else if let OutputTarget::ScreenBuffer(ref mut sb) = self.stdout_target {
+            // The simulated terminal follows scripted resizes the way a real one would
+            if (sb.width, sb.height) != (self.screen_width, self.screen_height) {
+                *sb = ScreenBuffer::new(self.screen_width, self.screen_height);
+            }
+            sb.clear();
+            let (origin_x, origin_y) = game_grid.origin;
+            for y in 0..game_grid.height.min(sb.height.saturating_sub(origin_y)) {
+                for x in 0..game_grid.width.min(sb.width.saturating_sub(origin_x)) {
+                    sb.buffer[(origin_y + y) as usize][(origin_x + x) as usize] = game_grid.grid[y as usize][x as usize];
                 }
-                sb.print_to_log();
             }
+            sb.print_to_log();
         }
+        self.stdout_target.flush()
+    }
+
+    // The terminal is now `width` x `height`; the playfield shrinks to the letterbox if one is configured.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        (self.screen_width, self.screen_height) = (width, height);
+        let ((playfield_width, playfield_height), origin) =
+            self.config.letterbox.map_or(((width, height), (0, 0)), |letterbox| letterbox.fit(width, height));
+        (self.terminal_width, self.terminal_height) = (playfield_width, playfield_height);
+        self.playfield_origin = origin;
+    }
+
+    fn new_grid(&self) -> GameGrid {
+        let mut game_grid = GameGrid::new(self.terminal_width, self.terminal_height);
+        game_grid.color = self.config.color;
+        game_grid.unicode = self.config.unicode;
+        game_grid.hires = (self.config.hires || self.hires) && self.config.unicode;
+        game_grid.origin = self.playfield_origin;
+        game_grid
+    }
+
+    // Observers see every frame, event and rendered playfield from now on, in the order they were added.
+    pub fn add_observer(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
 
-        minimap.render(&mut self.stdout_target)?;
-
-        self.stdout_target.execute_move_to(MoveTo(0, 0))?;
-        write!(self.stdout_target, "Score: {}  Health: {}/{}", score, player_health, max_health)?;
-
-        let controls_text = [
-            "Controls:",
-            r"  Up Arrow : Thrust",
-            r"  Left Arrow : Rotate Left",
-            r"  Right Arrow: Rotate Right",
-            r"  Spacebar : Fire Laser",
-            r"  q        : Quit",
-        ];
-        let controls_box_height = controls_text.len() as u16;
-        let controls_start_y = self.terminal_height.saturating_sub(controls_box_height);
-
-        for (i, line) in controls_text.iter().enumerate() {
-            self.stdout_target.execute_move_to(MoveTo(0, controls_start_y.saturating_add(i as u16)))?;
-            write!(self.stdout_target, "{}", line)?;
+    // Live terminal events arriving over the next `wait`.
+    fn wait_for_events(&self, wait: Duration) -> io::Result<Vec<Event>> {
+        match &self.input {
+            Some(input) => input.events_until(Instant::now() + wait),
+            None if event::poll(wait)? => Ok(vec![event::read()?]),
+            None => Ok(Vec::new()),
         }
+    }
 
-        if let Some((message, display_until_frame)) = current_banner {
-            if self.max_frames.is_none() || *display_until_frame > 0 {
-                let banner_x = self.terminal_width / 2 - message.len() as u16 / 2;
-                let banner_y = self.terminal_height / 2 - 5;
-                self.stdout_target.execute_move_to(MoveTo(banner_x, banner_y))?;
-                write!(self.stdout_target, "{}", message)?;
+    // Plays a cutscene over the backdrop in real time; any key press ends it early.
+    fn play_cutscene(&mut self, cutscene: &Cutscene) -> io::Result<()> {
+        let mut game_grid = self.new_grid();
+        let started_at = Instant::now();
+        loop {
+            game_grid.clear();
+            self.backdrop.draw(&mut game_grid, clock::ticks_for(started_at.elapsed()));
+            if !cutscene.draw(&mut game_grid, started_at.elapsed()) {
+                break;
+            }
+            self.render(&mut game_grid)?;
+            let events = self.wait_for_events(SIMULATION_TICK)?;
+            if events.iter().any(|event| matches!(event, Event::Key(key_event) if key_event.kind == KeyEventKind::Press)) {
+                info!("Cutscene skipped after {:?} of {:?}.", started_at.elapsed(), cutscene.duration());
+                break;
             }
         }
-
-        self.stdout_target.flush()?;
         Ok(())
     }
 
-    fn show_title_screen(&mut self) -> io::Result<()> {
-        let title_art = [
-            r"VIBE-ASTEROID",
-            r" _   _ _____ _____ ____  _____ ____  _   _ ____  _",
-            r"| | | | ____|_   _|  _ \| ____|  _ \| | | |  _ \| |",
-            r"| |_| |  _|   | | | |_) |  _| | |_) | |_| | |_) | |",
-            r"|  _  | |___  | | |  _ <| |___|  _ <|  _  |  _ <| |",
-            r"|_| |_|_____| |_| |_| \|_____|_| \|_| |_|_| \|_|",
-        ];
-
-        let title_start_y = self.terminal_height / 2 - title_art.len() as u16 / 2;
-        for (i, line) in title_art.iter().enumerate() {
-            let x = self.terminal_width / 2 - line.len() as u16 / 2;
-            self.stdout_target.execute_move_to(MoveTo(x, title_start_y + i as u16))?;
-            write!(self.stdout_target, "{}", line)?;
+    // The About screen: scrolls the credits at a steady pace, Up and Down nudge them, and any other key goes back.
+    fn show_credits(&mut self) -> io::Result<()> {
+        let credits = Credits::new(&self.config, (self.terminal_width, self.terminal_height));
+        let mut game_grid = self.new_grid();
+        let mut pacer = FramePacer::new(self.fps);
+        let started_at = Instant::now();
+        let mut nudge = 0.0;
+        loop {
+            game_grid.color = self.config.color;
+            game_grid.unicode = self.config.unicode;
+            game_grid.clear();
+            credits.draw(&mut game_grid, started_at.elapsed().as_secs_f64() * CREDITS_SCROLL_SPEED + nudge);
+            self.render(&mut game_grid)?;
+            pacer.wait(0);
+            for event in self.wait_for_events(Duration::ZERO)? {
+                match event {
+                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => match key_event.code {
+                        KeyCode::Up => nudge -= CREDITS_SCROLL_STEP,
+                        KeyCode::Down => nudge += CREDITS_SCROLL_STEP,
+                        _ => return Ok(()),
+                    },
+                    Event::Resize(new_width, new_height) => {
+                        self.resize(new_width, new_height);
+                        game_grid = self.new_grid();
+                    }
+                    _ => {}
+                }
+            }
         }
+    }
 
-        let press_any_key_msg = "Press any key to start...";
-        let msg_x = self.terminal_width / 2 - press_any_key_msg.len() as u16 / 2;
-        self.stdout_target.execute_move_to(MoveTo(msg_x, self.terminal_height - 5))?;
-        write!(self.stdout_target, "{}", press_any_key_msg)?;
-        self.stdout_target.flush()?;
-
-        let _ = io::stdin().read(&mut [0u8]).unwrap();
+}
 
-        let game_grid_dummy = GameGrid::new(self.terminal_width, self.terminal_height);
-        game_grid_dummy.clear_screen_manual(&mut self.stdout_target, self.terminal_width, self.terminal_height)?;
-        self.stdout_target.flush()?;
-        std::thread::sleep(Duration::from_millis(100));
-        Ok(())
-    }
 
-    fn show_game_over_screen(&mut self, score: u32) -> io::Result<()> {
-        let game_grid_dummy = GameGrid::new(self.terminal_width, self.terminal_height);
-        game_grid_dummy.clear_screen_manual(&mut self.stdout_target, self.terminal_width, self.terminal_height)?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::FrameInfo;
 
-        let game_over_msg = "GAME OVER!";
-        let score_msg = format!("Final Score: {}", score);
-        let exit_msg = "Press any key to exit...";
+    fn debug_game(width: u16, height: u16, events: HashMap<u64, Event>, frames: u64) -> Game {
+        let stdout_target = OutputTarget::ScreenBuffer(ScreenBuffer::new(width, height));
+        Game::new(width, height, stdout_target, Some(SimulatedInput::new(events)), true, Some(frames), 7)
+    }
 
-        let go_x = self.terminal_width / 2 - game_over_msg.len() as u16 / 2;
-        let score_x = self.terminal_width / 2 - score_msg.len() as u16 / 2;
-        let exit_x = self.terminal_width / 2 - exit_msg.len() as u16 / 2;
+    #[test]
+    fn scripted_resizes_rebuild_the_playfield() {
+        let events = HashMap::from([(2, Event::Resize(20, 8)), (4, Event::Resize(200, 50)), (6, Event::Resize(1, 1))]);
+        let mut game = debug_game(80, 24, events, 8);
+        game.play_session().unwrap();
+        assert_eq!((game.terminal_width, game.terminal_height), (1, 1));
+        let OutputTarget::ScreenBuffer(sb) = &game.stdout_target else {
+            panic!("debug game should render into a screen buffer");
+        };
+        assert_eq!((sb.width, sb.height), (1, 1));
+    }
 
-        let go_y = self.terminal_height / 2 - 2;
-        let score_y = self.terminal_height / 2;
-        let exit_y = self.terminal_height / 2 + 2;
+    #[test]
+    fn every_debug_preset_survives_a_short_run() {
+        for (_, width, height) in crate::terminal_io::DEBUG_PRESETS {
+            debug_game(width, height, HashMap::new(), 5).play_session().unwrap();
+        }
+    }
 
-        self.stdout_target.execute_move_to(MoveTo(go_x, go_y))?;
-        write!(self.stdout_target, "{}", game_over_msg)?;
+    #[test]
+    fn observers_see_every_frame_and_render() {
+        #[derive(Default)]
+        struct Counts {
+            frames: Vec<u64>,
+            renders: usize,
+        }
+        struct Counter(std::rc::Rc<std::cell::RefCell<Counts>>);
+        impl Observer for Counter {
+            fn on_frame(&mut self, info: &FrameInfo) {
+                self.0.borrow_mut().frames.push(info.frame);
+            }
+            fn on_render(&mut self, _grid: &GameGrid) {
+                self.0.borrow_mut().renders += 1;
+            }
+        }
 
-        self.stdout_target.execute_move_to(MoveTo(score_x, score_y))?;
-        write!(self.stdout_target, "{}", score_msg)?;
+        let counts = std::rc::Rc::new(std::cell::RefCell::new(Counts::default()));
+        let mut game = debug_game(80, 24, HashMap::new(), 5);
+        game.add_observer(Box::new(Counter(counts.clone())));
+        game.play_session().unwrap();
+        assert_eq!(counts.borrow().frames, vec![0, 1, 2, 3, 4]);
+        assert_eq!(counts.borrow().renders, 5);
+    }
 
-        self.stdout_target.execute_move_to(MoveTo(exit_x, exit_y))?;
-        write!(self.stdout_target, "{}", exit_msg)?;
-        self.stdout_target.flush()?;
+    #[test]
+    fn fixed_world_plays_the_same_on_any_terminal() {
+        let run = |width, height| {
+            let mut game = debug_game(width, height, HashMap::new(), 600);
+            game.headless = true;
+            game.fixed_world = true;
+            game.pilot = Some(Box::new(crate::autopilot::Autopilot::new()));
+            let summary = game.run().unwrap();
+            (summary.score, summary.frames, summary.state_hash)
+        };
+        assert_eq!(run(80, 24), run(200, 60));
+    }
 
-        let _ = io::stdin().read(&mut [0u8]).unwrap();
-        Ok(())
+    // Plays a whole seeded run with the autopilot until its last life is gone. Any change to spawning, movement,
+    // collisions, scoring or the wave director shows up here; if one was meant to, update the golden values.
+    #[test]
+    fn seeded_bot_run_ends_the_same_way() {
+        let summary = crate::pilot::run_bot(Box::new(crate::autopilot::Autopilot::new()), 7, BOT_MINUTES).unwrap();
+        assert!(summary.frames < crate::clock::ticks_for(Duration::from_secs(BOT_MINUTES * 60)), "the ship should die before the time limit");
+        assert_eq!((summary.score, summary.wave, summary.frames), (3155, 1, 1278));
     }
 }
-
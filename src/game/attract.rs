@@ -0,0 +1,98 @@
+use std::io;
+use std::time::Instant;
+use crossterm::event::{Event, KeyEventKind};
+use log::info;
+
+use crate::autopilot::Autopilot;
+use crate::constants::{ATTRACT_IDLE, TITLE_POLL_INTERVAL};
+use crate::high_scores::HighScores;
+use crate::mode_rules::Goal;
+use crate::title_menu::{self, TitleScreen};
+
+use super::Game;
+
+// --- The title menu and the attract demo it falls back to when left idle ---
+
+impl Game {
+    // Runs the title menu until the player starts a game (true) or quits from it (false). Left idle on the menu,
+    // it plays the attract demo and comes back.
+    pub(super) fn show_title_screen(&mut self) -> io::Result<bool> {
+        let empty_scores = HighScores::default();
+        let mut screen = TitleScreen::Menu { selected: 0 };
+        let mut game_grid = self.new_grid();
+        let mut idle_since = Instant::now();
+        loop {
+            if matches!(screen, TitleScreen::Menu { .. }) && idle_since.elapsed() >= ATTRACT_IDLE {
+                self.play_demo()?;
+                game_grid = self.new_grid();
+                idle_since = Instant::now();
+            }
+            game_grid.color = self.config.color;
+            game_grid.unicode = self.config.unicode;
+            game_grid.clear();
+            title_menu::draw(&mut game_grid, screen, self.high_scores.as_ref().unwrap_or(&empty_scores), &self.config, &self.loadouts);
+            self.render(&mut game_grid)?;
+
+            for event in self.wait_for_events(TITLE_POLL_INTERVAL)? {
+                match event {
+                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                        idle_since = Instant::now();
+                        let (before, previous) = (self.config, screen);
+                        screen = title_menu::handle_key(key_event.code, screen, &mut self.config, self.loadouts.len());
+                        if let (TitleScreen::Loadout { selected }, TitleScreen::Start) = (previous, screen) {
+                            // Entry 0 is the standard ship
+                            self.loadout = selected.checked_sub(1).and_then(|index| self.loadouts.get(index)).cloned();
+                        }
+                        if self.config != before {
+                            self.save_settings();
+                        }
+                    }
+                    Event::Resize(new_width, new_height) => {
+                        self.resize(new_width, new_height);
+                        game_grid = self.new_grid();
+                    }
+                    _ => {}
+                }
+            }
+            match screen {
+                TitleScreen::Start => return Ok(true),
+                TitleScreen::Quit => return Ok(false),
+                TitleScreen::About => {
+                    self.show_credits()?;
+                    screen = TitleScreen::Menu { selected: 3 };
+                    game_grid = self.new_grid();
+                    idle_since = Instant::now();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Attract mode: a solo game on a fresh seed flown by the autopilot, until a key is pressed or it ends.
+    // It's a throwaway run, so the mode flags are set aside and nothing reaches the death map, the score
+    // stream or the terminal title.
+    pub(super) fn play_demo(&mut self) -> io::Result<()> {
+        info!("Title screen idle, starting the demo.");
+        let seed = self.seed;
+        let modes = (self.coop, self.versus.take(), self.fleet.take(), self.goal);
+        let outputs = (self.death_map.take(), self.terminal_status.take(), self.idle_decay.take());
+        #[cfg(feature = "score-stream")]
+        let score_stream = self.score_stream.take();
+        (self.seed, self.coop, self.goal) = (rand::random(), false, Goal::Endless);
+        self.pilot = Some(Box::new(Autopilot::new()));
+
+        let result = self.play_session();
+
+        self.pilot = None;
+        self.seed = seed;
+        (self.coop, self.versus, self.fleet, self.goal) = modes;
+        (self.death_map, self.terminal_status, self.idle_decay) = outputs;
+        #[cfg(feature = "score-stream")]
+        {
+            self.score_stream = score_stream;
+        }
+        let (summary, _) = result?;
+        info!("Demo ended with a score of {} after {} frames.", summary.score, summary.frames);
+        Ok(())
+    }
+}
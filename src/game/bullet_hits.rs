@@ -0,0 +1,220 @@
+use std::time::Instant;
+use rand::Rng;
+
+use crate::boss::{self, BossHit};
+use crate::constants::*;
+use crate::effects::Effect;
+use crate::entities::{Asteroid, AsteroidSize, Entity, FloatingText, UpdateContext};
+use crate::entity_id::EntityId;
+use crate::events::GameEvent;
+use crate::profiler::Phase;
+use crate::scoring::ScoreTarget;
+use crate::types::Vector2D;
+use crate::upgrades::{Upgrade, UpgradeType};
+use crate::versus;
+use crate::world::World;
+
+use super::Game;
+
+// What a tick's shots, and then its shockwaves, did that the rest of the update settles.
+pub(super) struct BulletHits {
+    pub explosions: Vec<(Vector2D, AsteroidSize, bool)>, // Where, how big, and whether it was volatile
+    // Asteroids the shots destroyed, which the caller settles along with its shockwaves' kills: the asteroid,
+    // what it split from, how big it was, where, and which player gets the credit
+    pub kills: Vec<(EntityId, Option<EntityId>, AsteroidSize, Vector2D, usize)>,
+    pub shots_on_players: Vec<(usize, usize)>, // Versus: (victim, shooter)
+}
+
+impl Game {
+    // Moves every shot and resolves what it hit: asteroids, the boss, the saucer, upgrade boxes or, in versus,
+    // the other ship.
+    pub(super) fn bullet_pass(&mut self, world: &mut World, ctx: &UpdateContext, ship_coords: &[Vec<(u16, u16)>], fitted_weapons: &[UpgradeType]) -> BulletHits {
+        let World {
+            players,
+            asteroids,
+            bullets,
+            particles,
+            floating_texts,
+            upgrade_boxes,
+            upgrades,
+            levels,
+            saucer_encounter,
+            boss_encounter,
+            scoring,
+            stats,
+            favoured_upgrades,
+            entity_ids,
+            rng,
+            cosmetic_rng,
+            ..
+        } = world;
+        let tick = self.clock.tick();
+        let mut explosions = Vec::new();
+        let mut kills = Vec::new();
+        let mut shots_on_players = Vec::new();
+        let versus = self.run_versus().is_some();
+        let bullets_started = Instant::now();
+        bullets.retain_mut(|bullet| {
+            bullet.update(ctx);
+            let mut hit_asteroid = false;
+            let mut blocked = false; // Hit an asteroid without destroying it
+            let mut last_kill = None;
+            let mut bullet_kills = 0;
+            let mut new_asteroids_to_add: Vec<Asteroid> = Vec::new();
+            let mut crit_roll = None; // Rolled on the shot's first hit this tick, then shared by everything it hits
+            let crit_chance = players[bullet.owner].ship.crit_chance;
+            let (damage, pierced, first_landing) = (bullet.damage(), bullet.pierced, bullet.pierced.is_none());
+            let bullet_pos = (bullet.position.x.round() as u16, bullet.position.y.round() as u16);
+            if versus && let Some(victim) = versus::shot_victim(ship_coords, bullet.owner, bullet_pos) {
+                shots_on_players.push((victim, bullet.owner));
+                entity_ids.release(bullet.id);
+                return false;
+            }
+            asteroids.retain_mut(|asteroid| {
+                // The fragments of an asteroid a shot has just passed through start where it was
+                if pierced.is_some() && asteroid.parent == pierced {
+                    return true;
+                }
+                let asteroid_coords = asteroid.coords();
+                if asteroid_coords.contains(&bullet_pos) {
+                    hit_asteroid = true;
+                    let critical = *crit_roll.get_or_insert_with(|| rng.gen_bool(crit_chance));
+                    let damage = if critical { damage * CRIT_DAMAGE_MULTIPLIER as f64 } else { damage };
+                    // Small asteroids break at the first hit, so only the tougher ones show what a shot did
+                    if asteroid.size.hit_points() > 1.0 {
+                        let popup_position = Vector2D::new(asteroid.position.x, asteroid.position.y - 2.0);
+                        floating_texts.push(FloatingText::damage_number(popup_position, damage, critical));
+                    }
+                    if !asteroid.take_damage(damage) {
+                        blocked = true;
+                        let spark_velocity = Vector2D::new(-bullet.velocity.x * 0.2, -bullet.velocity.y * 0.2);
+                        particles.extend((0..self.effects.count(Effect::Spark, 1)).map(|_| self.effects.particle(Effect::Spark, bullet.position, spark_velocity)));
+                        return true;
+                    }
+                    bullet_kills += 1;
+                    last_kill = Some(asteroid.id);
+                    explosions.push((asteroid.position, asteroid.size, asteroid.volatile));
+                    kills.push((asteroid.id, asteroid.parent, asteroid.size, asteroid.position, bullet.owner));
+                    if asteroid.size.fragment_size().is_some() {
+                        let ids = [entity_ids.allocate(), entity_ids.allocate()];
+                        new_asteroids_to_add.extend(asteroid.split(ids, bullet.velocity, rng, levels.speed_multiplier));
+                    }
+                    for _ in 0..self.effects.count(Effect::Explosion, 5) {
+                        let angle = cosmetic_rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+                        let speed = cosmetic_rng.gen_range(0.5..1.5);
+                        let explosion_velocity = Vector2D::new(angle.cos() * speed, angle.sin() * speed);
+                        particles.push(self.effects.particle(Effect::Explosion, asteroid.position, explosion_velocity));
+                    }
+                    entity_ids.release(asteroid.id);
+                    false
+                } else {
+                    true
+                }
+            });
+            asteroids.extend(new_asteroids_to_add);
+            if bullet_kills >= MULTI_KILL_THRESHOLD {
+                self.event_bus.publish(GameEvent::MultiKill { count: bullet_kills, position: bullet.position });
+            }
+            // A piercing shot carries on through a kill, but anything left standing stops it
+            let passed_through = bullet_kills > 0 && !blocked && bullet.pierce > 0;
+            if passed_through {
+                bullet.pierce -= 1;
+                bullet.pierced = last_kill;
+            }
+
+            let mut hit_boss = false;
+            let boss_hit = if !hit_asteroid && boss_encounter.covers(bullet_pos) {
+                let critical = *crit_roll.get_or_insert_with(|| rng.gen_bool(crit_chance));
+                let damage = if critical { CRIT_DAMAGE_MULTIPLIER } else { 1 };
+                let popup_position = Vector2D::new(bullet.position.x, bullet.position.y - 1.0);
+                floating_texts.push(FloatingText::damage_number(popup_position, damage as f64, critical));
+                boss_encounter.hit_by(bullet_pos, damage, entity_ids)
+            } else {
+                None
+            };
+            if let Some(boss_hit) = boss_hit {
+                hit_boss = true;
+                let spark_velocity = Vector2D::new(-bullet.velocity.x * 0.2, -bullet.velocity.y * 0.2);
+                particles.extend((0..self.effects.count(Effect::Spark, 1)).map(|_| self.effects.particle(Effect::Spark, bullet.position, spark_velocity)));
+                if let BossHit::Destroyed { position } = boss_hit {
+                    let points = scoring.award(ScoreTarget::Boss, tick);
+                    players[bullet.owner].score += points;
+                    self.event_bus.publish(GameEvent::BossDestroyed { position, points });
+                    floating_texts.push(FloatingText::new(Vector2D::new(position.x, position.y - 2.0), format!("+{}", points), DAMAGE_POPUP_LIFETIME));
+                    asteroids.extend(boss::shatter(position, entity_ids, rng, levels.speed_multiplier));
+                    for _ in 0..BOSS_UPGRADE_DROPS {
+                        let offset = Vector2D::new(rng.gen_range(-2.0..2.0), rng.gen_range(-1.0..1.0));
+                        upgrades.push(Upgrade::new(entity_ids.allocate(), position.add(offset), UpgradeType::weighted(rng, favoured_upgrades, fitted_weapons)));
+                    }
+                    let ring = self.effects.count(Effect::Boss, 16);
+                    for i in 0..ring {
+                        let angle = i as f64 * 2.0 * std::f64::consts::PI / ring as f64;
+                        particles.push(self.effects.particle(Effect::Boss, position, Vector2D::new(angle.cos() * 1.5, angle.sin() * 0.75)));
+                    }
+                }
+            }
+
+            let mut hit_saucer = false;
+            if !hit_asteroid && !hit_boss && let Some(position) = saucer_encounter.hit_by(bullet_pos, entity_ids) {
+                hit_saucer = true;
+                let points = scoring.award(ScoreTarget::Saucer, tick);
+                players[bullet.owner].score += points;
+                self.event_bus.publish(GameEvent::SaucerDestroyed { position, points });
+                floating_texts.push(FloatingText::new(Vector2D::new(position.x, position.y - 2.0), format!("+{}", points), DAMAGE_POPUP_LIFETIME));
+                for _ in 0..self.effects.count(Effect::Wreck, 8) {
+                    let angle = cosmetic_rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+                    let speed = cosmetic_rng.gen_range(0.5..1.5);
+                    particles.push(self.effects.particle(Effect::Wreck, position, Vector2D::new(angle.cos() * speed, angle.sin() * speed)));
+                }
+            }
+
+            let mut hit_upgrade_box = false;
+            upgrade_boxes.retain_mut(|upgrade_box| {
+                let upgrade_box_coords = upgrade_box.coords();
+                if upgrade_box_coords.contains(&bullet_pos) {
+                    hit_upgrade_box = true;
+                    let critical = *crit_roll.get_or_insert_with(|| rng.gen_bool(crit_chance));
+                    let damage = if critical { CRIT_DAMAGE_MULTIPLIER } else { 1 };
+                    upgrade_box.hits_remaining = upgrade_box.hits_remaining.saturating_sub(damage);
+                    let popup_position = Vector2D::new(upgrade_box.position.x, upgrade_box.position.y - 2.0);
+                    floating_texts.push(FloatingText::damage_number(popup_position, damage as f64, critical));
+                    for _ in 0..self.effects.count(Effect::Debris, 3) {
+                        let angle = cosmetic_rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+                        let speed = cosmetic_rng.gen_range(0.2..0.8);
+                        let explosion_velocity = Vector2D::new(angle.cos() * speed, angle.sin() * speed);
+                        particles.push(self.effects.particle(Effect::Debris, upgrade_box.position, explosion_velocity));
+                    }
+                    if upgrade_box.hits_remaining == 0 {
+                        // Whoever breaks a box open also takes a bomb from it
+                        let ship = &mut players[bullet.owner].ship;
+                        ship.bombs = (ship.bombs + 1).min(MAX_BOMBS);
+                        let num_upgrades = rng.gen_range(1..=3);
+                        for _ in 0..num_upgrades {
+                            upgrades.push(Upgrade::new(entity_ids.allocate(), upgrade_box.position, UpgradeType::weighted(rng, favoured_upgrades, fitted_weapons)));
+                        }
+                        entity_ids.release(upgrade_box.id);
+                        false
+                    } else {
+                        true
+                    }
+                } else {
+                    true
+                }
+            });
+
+            // A shot that has already passed through an asteroid was counted as landing then
+            if first_landing && (hit_asteroid || hit_boss || hit_saucer || hit_upgrade_box) {
+                scoring.shot_landed();
+                stats.shot_landed();
+            }
+            let stopped = hit_asteroid && !passed_through;
+            let keep = !bullet.is_expired() && !stopped && !hit_boss && !hit_saucer && !hit_upgrade_box;
+            if !keep {
+                entity_ids.release(bullet.id);
+            }
+            keep
+        });
+        self.profile(Phase::Bullets, bullets_started);
+        BulletHits { explosions, kills, shots_on_players }
+    }
+}
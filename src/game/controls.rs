@@ -0,0 +1,371 @@
+use std::io;
+use std::time::Duration;
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use log::{error, info};
+
+use crate::constants::*;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::entities::{Bullet, Ship};
+use crate::effects::Effect;
+use crate::upgrades::Upgrade;
+use crate::weapons;
+use crate::terminal_guard;
+use crate::pilot::WorldView;
+use crate::input::{self, InputState};
+use crate::fleet::{self, FleetControl};
+use crate::events::GameEvent;
+use crate::player::{self, Player};
+use crate::practice::PracticeAction;
+use crate::quick_save::QuickSaveAction;
+use crate::shockwave::Shockwave;
+use crate::game_state::GameState;
+use crate::world::World;
+use crate::pause_menu;
+use crate::settings_menu;
+#[cfg(feature = "audio")]
+use crate::audio::{Channel, Sound};
+
+use super::Game;
+
+// --- Keyboard, gamepad and macro input, and what each control does to the ships ---
+
+impl Game {
+    pub(super) fn handle_input(&mut self, state: &mut GameState, world: &mut World) -> io::Result<()> {
+        let frame_count = self.clock.frame();
+        let mut events = Vec::new();
+        if self.debug_mode_active {
+            if let Some(sim_input) = &mut self.simulated_input {
+                while sim_input.poll(frame_count)? {
+                    events.push(sim_input.read()?);
+                }
+            }
+        } else {
+            // Whatever arrived while the last frame was drawn or slept off its remaining budget
+            events = self.wait_for_events(Duration::ZERO)?;
+            if let Some((width, height)) = self.pending_resize.take() {
+                events.insert(0, Event::Resize(width, height));
+            }
+        }
+
+        if let Some(mut pilot) = self.pilot.take() {
+            for event in events {
+                match event {
+                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => *state = GameState::Quit,
+                    Event::Resize(new_width, new_height) => self.resize(new_width, new_height),
+                    _ => {}
+                }
+            }
+            let (world_width, world_height) = self.world_size();
+            let view = WorldView {
+                tick: self.clock.tick(),
+                score: player::team_score(&world.players),
+                health: world.players[0].health,
+                wave: world.levels.wave,
+                ship: &world.players[0].ship,
+                asteroids: &world.asteroids,
+                saucer: world.saucer_encounter.saucer.as_ref().map(|saucer| saucer.position),
+                world_width,
+                world_height,
+            };
+            let input = pilot.decide(&view);
+            self.apply_input(input, 0, world);
+            self.pilot = Some(pilot);
+            return Ok(());
+        }
+
+        // Terminals that report key releases let HELD_KEYS act every frame while down, instead of on auto-repeat
+        let track_held = !self.debug_mode_active && terminal_guard::reports_key_releases();
+        let held = if track_held { self.held_keys.held() } else { Vec::new() };
+        // A fleet is flown from one keyboard; its input is fanned out to the ships below
+        let keyboards = if world.fleet.is_some() { 1 } else { world.players.len() };
+        let mut inputs = vec![InputState::default(); keyboards];
+
+        for event in events {
+            if let Event::Key(key_event) = event {
+                if track_held {
+                    self.held_keys.update(&key_event);
+                }
+                let repeat_of_held = key_event.kind == KeyEventKind::Repeat && track_held && self.held_keys.tracks(key_event.code);
+                if key_event.kind == KeyEventKind::Release || repeat_of_held {
+                    continue;
+                }
+            }
+            if let Some(recorder) = &mut self.replay_recorder {
+                // Replays play back without a letterbox, so a resize is recorded as the playfield it produced
+                match event {
+                    Event::Resize(width, height) => {
+                        let ((width, height), _) = self.config.letterbox.map_or(((width, height), (0, 0)), |letterbox| letterbox.fit(width, height));
+                        recorder.record(frame_count, &Event::Resize(width, height));
+                    }
+                    _ => recorder.record(frame_count, &event),
+                }
+            }
+            match event {
+                Event::Key(key_event) if matches!(state, GameState::Paused { .. }) => {
+                    if let GameState::Paused { selected } = *state {
+                        *state = pause_menu::handle_key(key_event.code, selected);
+                    }
+                    if *state == GameState::Playing {
+                        self.clock.resume();
+                    }
+                }
+                Event::Key(key_event) if let GameState::Settings { selected } = *state => {
+                    let before = self.config;
+                    *state = match settings_menu::handle_key(key_event.code, selected, &mut self.config) {
+                        Some(selected) => GameState::Settings { selected },
+                        None => GameState::Paused { selected: 1 },
+                    };
+                    if self.config != before {
+                        self.save_settings();
+                    }
+                    #[cfg(feature = "audio")]
+                    {
+                        // Volume changes take effect straight away, not from the next run
+                        self.mixer.set_volume(Channel::Music, self.config.music_volume);
+                        self.mixer.set_volume(Channel::Effects, self.config.effects_volume);
+                    }
+                }
+                Event::Key(key_event) => match key_event.code {
+                    KeyCode::Char('p') | KeyCode::Esc => {
+                        *state = GameState::Paused { selected: 0 };
+                        self.clock.pause();
+                    }
+                    KeyCode::F(6) => self.input_macros.toggle_recording(frame_count, self.fps),
+                    KeyCode::Char('m') => self.input_macros.start_playback(frame_count),
+                    KeyCode::F(5) if let Some(practice) = &mut self.practice => practice.request(PracticeAction::Save),
+                    KeyCode::F(9) if let Some(practice) = &mut self.practice => practice.request(PracticeAction::Restore),
+                    KeyCode::F(5) if let Some(quick_save) = &mut self.quick_save => quick_save.request(QuickSaveAction::Save),
+                    KeyCode::F(9) if let Some(quick_save) = &mut self.quick_save => quick_save.request(QuickSaveAction::Load),
+                    #[cfg(feature = "overlays")]
+                    KeyCode::F(4) if self.practice.is_some() => self.show_death_map = !self.show_death_map,
+                    code => {
+                        self.input_macros.record(frame_count, code);
+                        if !input::press(&mut inputs, code) {
+                            self.handle_key(code, state, world);
+                        }
+                    }
+                },
+                Event::Resize(new_width, new_height) => self.resize(new_width, new_height),
+                _ => {} 
+            }
+        }
+
+        if *state != GameState::Playing {
+            return Ok(());
+        }
+        if let Some(code) = self.input_macros.next_key(frame_count)
+            && !input::press(&mut inputs, code)
+        {
+            self.handle_key(code, state, world);
+        }
+        // Recorded as presses, so replays reproduce held keys without tracking releases
+        for code in held {
+            if let Some(recorder) = &mut self.replay_recorder {
+                recorder.record(frame_count, &Event::Key(code.into()));
+            }
+            input::press(&mut inputs, code);
+        }
+        // The gamepad flies player one's ship
+        if let Some(gamepad) = &mut self.gamepad {
+            let pad = gamepad.poll(frame_count, &self.config.analog);
+            if pad.is_active()
+                && let Some(recorder) = &mut self.replay_recorder
+            {
+                recorder.record_pad(frame_count, pad);
+            }
+            inputs[0].merge(pad);
+        }
+        if let Some(fleet) = &world.fleet {
+            inputs = fleet.fan_out(inputs[0]);
+        }
+        for (index, input) in inputs.into_iter().enumerate() {
+            self.apply_input(input, index, world);
+            if self.assist.auto_fire && world.fleet.is_none_or(|fleet| fleet.controls(index)) {
+                self.fire(index, world);
+            }
+        }
+        Ok(())
+    }
+
+    // Each ship control acts at most once a frame, and all of them can act in the same frame.
+    pub(super) fn apply_input(&mut self, input: InputState, index: usize, world: &mut World) {
+        if let Some(idle_decay) = &mut self.idle_decay
+            && input.is_active()
+        {
+            idle_decay.note_activity(index, self.clock.tick());
+        }
+        if input.fire {
+            self.fire(index, world);
+        }
+        let player = &mut world.players[index];
+        if !player.in_play() {
+            return;
+        }
+        let ship = &mut player.ship;
+        if input.thrust {
+            ship.thrust();
+            #[cfg(feature = "audio")]
+            self.audio.play(Sound::Thrust);
+            let smoke_velocity = Vector2D::new(-ship.angle.cos() * 0.5, -ship.angle.sin() * 0.5);
+            for _ in 0..self.effects.count(Effect::Thrust, 1) {
+                world.particles.push(self.effects.particle(Effect::Thrust, ship.position, smoke_velocity));
+            }
+        }
+        if input.rotate_left {
+            ship.rotate(-1.0);
+        }
+        if input.rotate_right {
+            ship.rotate(1.0);
+        }
+        if input.steer != 0.0 {
+            ship.rotate(input.steer);
+        }
+    }
+
+    // Keys other than the ship controls in `InputState`.
+    pub(super) fn handle_key(&mut self, code: KeyCode, state: &mut GameState, world: &mut World) {
+        let players = &mut world.players;
+        // Hyperspace: Down or h for player one, s for player two in co-op; a fleet jumps every ship under control
+        let jumpers: Vec<usize> = match code {
+            KeyCode::Down | KeyCode::Char('h') => match &world.fleet {
+                Some(fleet) => (0..players.len()).filter(|&index| fleet.controls(index)).collect(),
+                None => vec![0],
+            },
+            KeyCode::Char('s') if self.two_player() => vec![1],
+            _ => Vec::new(),
+        };
+        if !jumpers.is_empty() {
+            for index in jumpers {
+                if let Some(idle_decay) = &mut self.idle_decay {
+                    idle_decay.note_activity(index, self.clock.tick());
+                }
+                if players[index].in_play() {
+                    players[index].hyperspace.request();
+                }
+            }
+            return;
+        }
+        // Player one's upgrade keys work on the ship Tab has picked out, or the lead
+        let panel_owner = match world.fleet.map(|fleet| fleet.control) {
+            Some(FleetControl::Ship(index)) => index,
+            _ => 0,
+        };
+        match code {
+            KeyCode::Char('q') => *state = GameState::Quit,
+            #[cfg(feature = "overlays")]
+            KeyCode::F(3) => self.show_density_heatmap = !self.show_density_heatmap,
+            #[cfg(feature = "overlays")]
+            KeyCode::F(2) => self.show_difficulty_chart = !self.show_difficulty_chart,
+            KeyCode::F(7) => self.event_log.visible = !self.event_log.visible,
+            KeyCode::Tab if let Some(fleet) = &mut world.fleet => fleet.cycle(),
+            KeyCode::Char('u') => self.cycle_upgrade_cursor(&mut players[panel_owner]),
+            KeyCode::Char('e') if self.two_player() => self.cycle_upgrade_cursor(&mut players[1]),
+            KeyCode::Char('x') => self.jettison_upgrade(world, panel_owner),
+            KeyCode::Char('b') => self.drop_bomb(world, panel_owner),
+            KeyCode::Char('g') if self.two_player() => self.drop_bomb(world, 1),
+            KeyCode::Char('r') if self.two_player() => self.jettison_upgrade(world, 1),
+            KeyCode::Char('d') => {
+                self.toggle_dampeners(&mut players[0].ship);
+                // Fleet ships fly as one: the whole formation follows the lead's flight model
+                if world.fleet.is_some() {
+                    for player in &mut players[1..] {
+                        player.ship.set_dampeners(self.config.dampeners);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // b (g for player two): set off one of the ship's bombs where it is.
+    pub(super) fn drop_bomb(&mut self, world: &mut World, index: usize) {
+        let player = &mut world.players[index];
+        if !player.in_play() || player.ship.bombs == 0 {
+            return;
+        }
+        if let Some(idle_decay) = &mut self.idle_decay {
+            idle_decay.note_activity(index, self.clock.tick());
+        }
+        player.ship.bombs -= 1;
+        let position = player.ship.position;
+        info!("Ship {} dropped a bomb, {} left.", index + 1, player.ship.bombs);
+        world.shockwaves.push(Shockwave::new(position, index));
+        self.event_bus.publish(GameEvent::BombDropped { position });
+    }
+
+    // u (e for player two): step the panel marker down the upgrades, then off again.
+    pub(super) fn cycle_upgrade_cursor(&self, player: &mut Player) {
+        let count = self.upgrade_balance.active_upgrades(&player.ship).len();
+        player.upgrade_cursor = match player.upgrade_cursor {
+            None if count > 0 => Some(0),
+            Some(cursor) if cursor + 1 < count => Some(cursor + 1),
+            _ => None,
+        };
+    }
+
+    // Drops the upgrade picked in `index`'s panel back into the field behind the ship, where anyone can collect it.
+    pub(super) fn jettison_upgrade(&mut self, world: &mut World, index: usize) {
+        let (world_width, world_height) = self.world_size();
+        let player = &mut world.players[index];
+        let Some(cursor) = player.upgrade_cursor else { return };
+        if !player.in_play() {
+            return;
+        }
+        let active = self.upgrade_balance.active_upgrades(&player.ship);
+        let Some(&(upgrade_type, _)) = active.get(cursor) else { return };
+        if !self.upgrade_balance.jettison(&mut player.ship, upgrade_type) {
+            return;
+        }
+        player.health = player.health.min(player.ship.max_health);
+        let ship = &player.ship;
+        let behind = Vector2D::new(
+            ship.position.x - ship.angle.cos() * JETTISON_DISTANCE * TERMINAL_ASPECT_RATIO_COMPENSATION,
+            ship.position.y - ship.angle.sin() * JETTISON_DISTANCE,
+        );
+        let position = Vector2D::new(wrap_coordinate(behind.x, world_width as f64), wrap_coordinate(behind.y, world_height as f64));
+        let remaining = active.len() - 1;
+        player.upgrade_cursor = if remaining == 0 { None } else { Some(cursor.min(remaining - 1)) };
+        world.upgrades.push(Upgrade::new(world.entity_ids.allocate(), position, upgrade_type));
+        world.banner = Some((format!("{} jettisoned", upgrade_type.name()), self.clock.timer(BANNER_DURATION)));
+        info!("Player {} jettisoned {}.", index + 1, upgrade_type.name());
+        if world.fleet.is_some() {
+            fleet::share_health(&mut world.players);
+        }
+    }
+
+    // The flight model is a profile preference, so a toggle is saved for the next launch too.
+    pub(super) fn toggle_dampeners(&mut self, ship: &mut Ship) {
+        self.config.dampeners = !ship.dampeners_enabled();
+        ship.set_dampeners(self.config.dampeners);
+        info!("Dampeners {}.", if self.config.dampeners { "on" } else { "off" });
+        if !self.debug_mode_active
+            && let Err(e) = self.config.save()
+        {
+            error!("Failed to save dampeners setting: {}", e);
+        }
+    }
+
+    // Player `index` shoots, cooldown allowing.
+    pub(super) fn fire(&mut self, index: usize, world: &mut World) {
+        let player = &mut world.players[index];
+        if player.shot_cooldown.is_running(&self.clock) || !player.in_play() {
+            return;
+        }
+        let ship = &player.ship;
+        let bullet_speed = BULLET_SPEED * ship.bullet_speed_multiplier;
+        for angle in weapons::volley_angles(ship, player.volleys) {
+            let bullet_velocity = Vector2D::new(angle.cos() * bullet_speed, angle.sin() * bullet_speed);
+            let mut bullet = Bullet::new(world.entity_ids.allocate(), ship.position, bullet_velocity, ship.bullet_size_multiplier, ship.bullet_lifetime());
+            bullet.color = player.bullet_color;
+            bullet.owner = index;
+            bullet.pierce = ship.pierce;
+            world.bullets.push(bullet);
+            world.scoring.shot_fired();
+            world.stats.shot_fired();
+        }
+        player.volleys += 1;
+        player.shot_cooldown = self.clock.timer(player.ship.shot_cooldown());
+        #[cfg(feature = "audio")]
+        self.audio.play(Sound::Shoot);
+    }
+}
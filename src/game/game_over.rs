@@ -0,0 +1,86 @@
+use std::io::{self, Read, Write};
+use crossterm::{ 
+    cursor::{MoveTo},
+    event::{Event, KeyCode, KeyEventKind},
+};
+
+use crate::constants::*;
+use crate::rendering::GameGrid;
+use crate::high_scores::{ScoreSubmission, SubmissionStatus};
+
+use super::{Game, RunSummary};
+
+// --- The game over screen: the run's summary and the high score entry ---
+
+impl Game {
+    // `won`: the run ended on its goal rather than on lost lives or a quit. Returns whether the player chose to
+    // play again. A high score `submission` still being saved is shown as it goes, and the screen never waits on it.
+    pub(super) fn show_game_over_screen(&mut self, summary: &RunSummary, won: bool, mut submission: Option<&mut ScoreSubmission>) -> io::Result<bool> {
+        // Drawn straight to the terminal, so this is centred on the screen rather than the playfield
+        let (screen_width, screen_height) = (self.screen_width, self.screen_height);
+        let game_grid_dummy = GameGrid::new(screen_width, screen_height);
+        game_grid_dummy.clear_screen_manual(&mut self.stdout_target, screen_width, screen_height)?;
+
+        // Versus: who won, then each player's line, above the usual summary
+        let mut lines = self.versus_round.as_ref().map_or_else(Vec::new, |round| {
+            let mut lines = round.lines();
+            lines.push(String::new());
+            lines
+        });
+        lines.push(if won { "VICTORY!" } else { "GAME OVER!" }.to_string());
+        lines.push(String::new());
+        lines.push(format!("Final Score: {}", summary.score));
+        if self.assist.is_active() {
+            lines.push(format!("Assist mode: {}", self.assist.summary()));
+        }
+        lines.push(String::new());
+        lines.extend(summary.stats.lines(summary.wave, self.clock.elapsed()));
+        lines.push(String::new());
+        if let Some(ruleset) = &self.ruleset {
+            lines.push(format!("Tournament ruleset: {:016x}", ruleset.hash()));
+        }
+        let status_row = lines.len();
+        lines.push(String::new()); // Filled in by the high score submission
+        lines.push("Press r to restart or q to quit".to_string());
+
+        let top = (screen_height / 2).saturating_sub(lines.len() as u16 / 2);
+        for (row, line) in lines.iter().enumerate() {
+            let line_x = (screen_width / 2).saturating_sub(line.len() as u16 / 2);
+            self.stdout_target.execute_move_to(MoveTo(line_x, top + row as u16))?;
+            write!(self.stdout_target, "{}", line)?;
+        }
+        self.stdout_target.flush()?;
+
+        let status_y = top + status_row as u16;
+        let mut shown = None;
+        loop {
+            let status = match (&mut submission, self.input.is_some()) {
+                (Some(submission), true) => submission.poll(),
+                (Some(submission), false) => submission.finish(),
+                (None, _) => SubmissionStatus::Unranked,
+            };
+            if status != SubmissionStatus::Unranked && shown != Some(status) {
+                let message = status.message().unwrap_or_default();
+                self.stdout_target.execute_move_to(MoveTo(0, status_y))?;
+                write!(self.stdout_target, "{:^width$}", message, width = screen_width as usize)?;
+                self.stdout_target.flush()?;
+                shown = Some(status);
+            }
+            if self.input.is_none() {
+                let mut key = [0u8];
+                let _ = io::stdin().read(&mut key).unwrap();
+                return Ok(key[0] == b'r');
+            }
+            for event in self.wait_for_events(SUBMISSION_POLL_INTERVAL)? {
+                match event {
+                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => match key_event.code {
+                        KeyCode::Char('r') => return Ok(true),
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use crate::clock;
+use crate::constants::{ENERGY_BAR_WIDTH, HEALTH_BAR_WIDTH, HYPERSPACE_ENERGY_COST, PRESSURE_BAR_WIDTH};
+use crate::fleet;
+use crate::hud;
+use crate::levels::NextStep;
+use crate::player::{self, Player};
+use crate::scoring::ScoringMode;
+use crate::weapons;
+use crate::world::World;
+
+use super::Game;
+
+// --- The text of the HUD's Status and Upgrades panels ---
+
+impl Game {
+    // Scores, health and energy for every player, then whatever modes and tools are switched on.
+    pub(super) fn status_lines(&self, world: &World) -> Vec<String> {
+        let flight_model = if self.config.dampeners { "Dampened" } else { "Newtonian" };
+        let two_player = self.two_player();
+        let players = &world.players;
+        let mut status_lines = Vec::new();
+        if two_player {
+            status_lines.push(format!("Wave {}  {}", world.levels.wave, flight_model));
+        }
+        // A fleet shares one score line and one health bar; each ship keeps its own lives and energy
+        if let Some(fleet) = &world.fleet {
+            let lives: Vec<String> = players.iter().map(|player| player.lives.remaining.to_string()).collect();
+            status_lines.push(format!("Score {}  Wave {}  Lives {}  {}", player::team_score(players), world.levels.wave, lives.join("/"), flight_model));
+            status_lines.push(fleet.label());
+        }
+        for (index, player) in players.iter().enumerate() {
+            let ship = &player.ship;
+            let jump = if player.hyperspace.is_ready(ship) {
+                "ready".to_string()
+            } else {
+                format!("{:.0}s", ship.energy.seconds_until(HYPERSPACE_ENERGY_COST).ceil())
+            };
+            if let Some(kills_to_win) = self.run_versus() {
+                status_lines.push(format!("P{} Kills {}/{}  Score {}", index + 1, player.kills, kills_to_win, player.score));
+            } else if two_player {
+                status_lines.push(format!("P{} Score {}  Lives {}", index + 1, player.score, player.lives.remaining));
+            } else if world.fleet.is_some() {
+                if index == 0 {
+                    let pool = fleet::pool(players).unwrap_or(0);
+                    status_lines.push(format!("Health {} {}/{}", hud::meter(pool as f64, ship.max_health as f64, HEALTH_BAR_WIDTH), pool, ship.max_health));
+                }
+                status_lines.push(format!("S{} Energy {} {:.0}  Jump {}", index + 1, hud::meter(ship.energy.current, ship.energy.capacity, ENERGY_BAR_WIDTH), ship.energy.current, jump));
+                continue;
+            } else {
+                status_lines.push(format!("Score {}  Wave {}  Lives {}  {}", player.score, world.levels.wave, player.lives.remaining, flight_model));
+                if let Some(progress) = self.run_rules().progress_line(players, world.levels.waves_cleared()) {
+                    status_lines.push(progress);
+                }
+            }
+            status_lines.push(format!(
+                "Health {} {}/{}  Shields {}  Bombs {}",
+                hud::meter(player.health as f64, ship.max_health as f64, HEALTH_BAR_WIDTH),
+                player.health,
+                ship.max_health,
+                hud::pips(ship.shield_count),
+                hud::pips(ship.bombs)
+            ));
+            status_lines.push(format!("Energy {} {:.0}  Jump {}", hud::meter(ship.energy.current, ship.energy.capacity, ENERGY_BAR_WIDTH), ship.energy.current, jump));
+            if let Some(weapon) = weapons::label(ship, player.volleys) {
+                status_lines.push(format!("Weapon {}", weapon));
+            }
+            if let Some(power_ups) = ship.power_ups.label() {
+                status_lines.push(format!("Power {}", power_ups));
+            }
+        }
+        if world.levels.wave > 0 {
+            status_lines.push(self.pressure_line(world));
+        }
+        if self.assist.is_active() {
+            status_lines.push("[ASSIST]".to_string());
+        }
+        if self.run_scoring() != ScoringMode::Classic {
+            status_lines.push(format!("[SCORING: {}]", self.run_scoring().name()));
+        }
+        if let Some(ruleset) = &self.ruleset {
+            status_lines.push(format!("[TOURNAMENT {:016x}]", ruleset.hash()));
+        }
+        if self.practice.is_some() {
+            let death_map_key = if cfg!(feature = "overlays") { ", F4 death map" } else { "" };
+            status_lines.push(format!("[PRACTICE: F5 save, F9 restore{}]", death_map_key));
+        }
+        #[cfg(feature = "overlays")]
+        if self.show_death_map
+            && let Some(death_map) = &self.death_map
+        {
+            let average = death_map.average_survival_ticks().map_or("-".to_string(), |ticks| format!("{}s", ticks / clock::ticks_for(Duration::from_secs(1))));
+            status_lines.push(format!("[DEATHS: {}, avg time {}]", death_map.deaths.len(), average));
+        }
+        if self.pilot.is_some() {
+            status_lines.push("[DEMO: press any key]".to_string());
+        }
+        if self.input_macros.is_recording() {
+            status_lines.push("[REC MACRO]".to_string());
+        } else if self.input_macros.is_playing() {
+            status_lines.push("[MACRO]".to_string());
+        }
+        status_lines
+    }
+
+    // The director's load on the field against the wave's cap, and how long until it steps things up.
+    fn pressure_line(&self, world: &World) -> String {
+        let pressure = world.levels.pressure(&world.asteroids, &self.clock);
+        let seconds = |ticks: u64| ticks.div_ceil(clock::ticks_for(Duration::from_secs(1)));
+        let next = match pressure.next_step {
+            NextStep::WaveStarts(ticks) => format!("Wave in {}s", seconds(ticks)),
+            NextStep::Spawn(ticks) => format!("Spawn in {}s", seconds(ticks)),
+            NextStep::FieldClear => format!("Clear for wave {}", world.levels.wave + 1),
+        };
+        format!("Pressure {} {}/{}  {}", hud::meter(pressure.load as f64, pressure.cap as f64, PRESSURE_BAR_WIDTH), pressure.load, pressure.cap, next)
+    }
+
+    // A player's upgrade panel, with a marker on the row picked for jettisoning while they're choosing.
+    pub(super) fn upgrade_panel_lines(&self, player: &Player) -> Vec<String> {
+        let active = self.upgrade_balance.active_upgrades(&player.ship).into_iter().map(|(_, line)| line);
+        match player.upgrade_cursor {
+            Some(cursor) => active.enumerate().map(|(row, line)| format!("{}{}", if row == cursor { '>' } else { ' ' }, line)).collect(),
+            None => active.collect(),
+        }
+    }
+}
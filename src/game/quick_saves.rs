@@ -0,0 +1,68 @@
+use log::{error, info};
+
+use crate::constants::BANNER_DURATION;
+use crate::game_state::GameState;
+use crate::player;
+use crate::quick_save::{self, QuickSave, QuickSaveAction, SavedGame};
+use crate::replay;
+use crate::world::World;
+
+use super::Game;
+
+// --- F5/F9 in live runs: writing the run so far to disk, and carrying on from a loaded save ---
+
+impl Game {
+    // Acts on a quick save or load requested since the last frame.
+    pub(super) fn handle_quick_save(&mut self, world: &mut World, state: &mut GameState) {
+        match self.quick_save.as_mut().and_then(QuickSave::take_request) {
+            Some(QuickSaveAction::Save) if *state == GameState::Playing => {
+                // Saved as a replay ending with this frame, so its hash is taken as the frame count will be then
+                let (score, frames) = (player::team_score(&world.players), self.clock.frame() + 1);
+                let state_hash = replay::state_hash(&world.players[0].ship, &world.asteroids, score, frames);
+                let saved = match &self.replay_recorder {
+                    Some(recorder) => quick_save::prepare_path().and_then(|path| recorder.save_progress(&path.to_string_lossy(), frames, score, state_hash)),
+                    None => Ok(()),
+                };
+                let banner = match saved {
+                    Ok(()) => {
+                        info!("Quick save written at frame {}.", frames);
+                        "GAME SAVED"
+                    }
+                    Err(e) => {
+                        error!("Failed to write the quick save: {}", e);
+                        "SAVE FAILED"
+                    }
+                };
+                world.banner = Some((banner.to_string(), self.clock.timer(BANNER_DURATION)));
+            }
+            Some(QuickSaveAction::Load) => match quick_save::load() {
+                Ok(saved) => {
+                    self.resume = Some(saved);
+                    *state = GameState::Restarting;
+                }
+                Err(e) => {
+                    error!("Failed to load the quick save: {}", e);
+                    world.banner = Some(("LOAD FAILED".to_string(), self.clock.timer(BANNER_DURATION)));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    // A loaded quick save carries on its own run, settings and recorded input included.
+    pub(super) fn resume_quick_save(&mut self, saved: SavedGame) {
+        let replay = &saved.replay;
+        info!("Resuming the quick save on seed {} at frame {}.", replay.seed, replay.frames);
+        self.seed = replay.seed;
+        self.assist = replay.assist;
+        self.apply_run_settings(&replay.settings);
+        if let Some(recorder) = &mut self.replay_recorder {
+            recorder.resume(replay);
+        }
+        // The game resumes on the playfield it was saved on; today's terminal size then arrives as
+        // a resize on the first frame, recorded like any other so later saves play back the same way
+        self.pending_resize = Some((self.screen_width, self.screen_height));
+        (self.terminal_width, self.terminal_height) = replay.final_size();
+        self.resume = Some(saved);
+    }
+}
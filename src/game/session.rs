@@ -0,0 +1,491 @@
+use std::io;
+use std::time::{Duration, Instant};
+use crossterm::style::{Attribute, Color, SetAttribute};
+use log::info;
+
+use crate::constants::*;
+use crate::types::Vector2D;
+use crate::rendering::Minimap;
+use crate::entities::{Entity, Ship, AsteroidSize};
+use crate::profiler::{EntityCounts, Phase};
+use crate::frame_pacer::FramePacer;
+use crate::input::PLAYER_TWO_KEYS;
+use crate::observer::FrameInfo;
+use crate::spatial::SpatialHash;
+use crate::field::{self, FieldParams};
+use crate::fleet::{self, Fleet};
+#[cfg(feature = "overlays")]
+use crate::overlays;
+#[cfg(feature = "overlays")]
+use crate::director_stats::{DirectorSample, DirectorStats};
+use crate::replay;
+use crate::events::GameEvent;
+use crate::clock::{self, GameClock};
+use crate::levels::LevelManager;
+use crate::player::{self, Player};
+use crate::versus::RoundSummary;
+use crate::entity_id;
+use crate::practice::{PracticeAction, Snapshot};
+use crate::game_state::GameState;
+use crate::world::World;
+use crate::pause_menu;
+use crate::high_scores::ScoreSubmission;
+use crate::run_stats::RunStats;
+use crate::cutscene::Cutscene;
+#[cfg(feature = "score-stream")]
+use crate::score_stream::StreamSnapshot;
+use crate::settings_menu;
+use crate::camera::Camera;
+use crate::feedback::{FeedbackEvent, FeedbackSink};
+#[cfg(feature = "audio")]
+use crate::audio::{Mixer, Sound};
+
+use super::{Game, RunSummary};
+
+// --- The run: title screen, one session per game, restarts and the game over screen in between ---
+
+impl Game {
+    pub fn run(&mut self) -> io::Result<RunSummary> {
+        if !self.debug_mode_active && !self.show_title_screen()? {
+            info!("Quit from the title screen.");
+            return Ok(RunSummary { score: 0, frames: 0, wave: 0, stats: RunStats::new(), state_hash: 0 });
+        }
+        if !self.debug_mode_active {
+            self.play_cutscene(&Cutscene::intro())?;
+        }
+        // Settings may have been changed on the title screen
+        let settings = self.run_settings();
+        if let Some(recorder) = &mut self.replay_recorder {
+            recorder.restart(self.seed, settings);
+        }
+
+        let (mut summary, mut state) = self.play_session()?;
+        loop {
+            while state == GameState::Restarting {
+                if let Some(saved) = self.resume.take() {
+                    self.resume_quick_save(saved);
+                } else {
+                    if !self.seed_locked {
+                        self.seed = rand::random();
+                    }
+                    info!("Restarting with seed {}.", self.seed);
+                    let settings = self.run_settings();
+                    if let Some(recorder) = &mut self.replay_recorder {
+                        recorder.restart(self.seed, settings);
+                    }
+                }
+                (summary, state) = self.play_session()?;
+            }
+
+            // A versus round's points aren't a solo or team score, and a fleet's extra ships would skew the table
+            let eligible = self.practice.is_none() && self.versus_round.is_none() && self.run_fleet().is_none();
+            let mut submission = match &mut self.high_scores {
+                Some(high_scores) if eligible => Some(ScoreSubmission::start(high_scores, summary.score, self.seed, self.assist.is_active())),
+                _ => None,
+            };
+
+            let restart = !self.headless && self.show_game_over_screen(&summary, state == GameState::Won, submission.as_mut())?;
+            if let Some(submission) = &mut submission {
+                submission.finish();
+            }
+            if !restart {
+                break;
+            }
+            // Back round the loop like a restart from the pause menu; the replay keeps only the last game
+            state = GameState::Restarting;
+        }
+
+        if let Some(terminal_status) = &self.terminal_status {
+            terminal_status.clear(&mut self.stdout_target)?;
+        }
+        if let Some(profiler) = &mut self.profiler {
+            profiler.flush()?;
+        }
+        if let Some(recorder) = self.replay_recorder.take()
+            && recorder.records_to_file()
+        {
+            recorder.finish(&summary)?;
+            info!("Replay saved (seed {}, {} frames).", self.seed, summary.frames);
+        }
+        Ok(summary)
+    }
+
+    // Plays one game from a fresh field until it ends, is quit or is restarted.
+    pub(super) fn play_session(&mut self) -> io::Result<(RunSummary, GameState)> {
+        let (world_width, world_height) = self.world_size();
+        let mut state = GameState::Playing;
+
+        let mut game_grid = self.new_grid();
+        let mut minimap = Minimap::new(MINIMAP_SIZE, MINIMAP_SIZE);
+        let mut was_flashing = false;
+        let mut spatial_hash = SpatialHash::new(world_width, world_height, SPATIAL_HASH_CELL_WIDTH, SPATIAL_HASH_CELL_HEIGHT);
+
+        self.clock = if let Some(steps) = &self.step_script {
+            GameClock::scripted(steps.clone())
+        } else if self.debug_mode_active {
+            GameClock::fixed()
+        } else {
+            GameClock::new()
+        };
+        let levels = LevelManager::new(self.assist.enemy_speed_factor(), self.run_difficulty());
+        self.upgrade_balance = self.run_difficulty().upgrade_balance();
+        let mut world = World::new(self.new_players(), levels, self.run_scoring().rules(), self.seed);
+        world.fleet = self.run_fleet().map(Fleet::new);
+        world.favoured_upgrades = self.run_loadout().map_or_else(Vec::new, |loadout| loadout.favoured.clone());
+        // Practice setups start from a generated field on top of the first wave
+        if let Some((large, medium, small)) = self.practice_field {
+            let params = FieldParams { large, medium, small, speed_multiplier: world.levels.speed_multiplier, ..FieldParams::new(world_width, world_height) };
+            world.asteroids = field::generate_with_ids(self.seed, &params, &mut world.entity_ids);
+        }
+        if let Some(saved) = self.resume.take() {
+            self.clock.restore_frame(saved.replay.frames);
+            self.clock.restore_tick(saved.snapshot.tick);
+            world = saved.snapshot.world;
+            world.banner = Some(("GAME LOADED".to_string(), self.clock.timer(BANNER_DURATION)));
+        }
+        #[cfg(feature = "overlays")]
+        {
+            self.director_stats = DirectorStats::new();
+        }
+        #[cfg(feature = "audio")]
+        {
+            self.mixer = Mixer::new(self.config.music_volume, self.config.effects_volume);
+        }
+        self.event_log.clear();
+        let rules = self.run_rules();
+        if self.two_player() {
+            self.held_keys.track(&PLAYER_TWO_KEYS);
+        }
+        // Debug runs, replays and benchmarks go as fast as they can
+        let mut pacer = (!self.debug_mode_active).then(|| FramePacer::new(self.fps));
+
+        while state.is_active() && (self.max_frames.is_none() || self.clock.frame() < self.max_frames.unwrap()) {
+            // The terminal was resized last frame: rebuild everything sized to the playfield
+            if (game_grid.width, game_grid.height, game_grid.origin) != (self.terminal_width, self.terminal_height, self.playfield_origin) {
+                game_grid = self.new_grid();
+                let (world_width, world_height) = self.world_size();
+                spatial_hash = SpatialHash::new(world_width, world_height, SPATIAL_HASH_CELL_WIDTH, SPATIAL_HASH_CELL_HEIGHT);
+            }
+            let draw_started = Instant::now();
+            game_grid.clear();
+            game_grid.color = self.config.color;
+            minimap.clear();
+            #[cfg(feature = "overlays")]
+            let show_backdrop = !self.show_density_heatmap;
+            #[cfg(not(feature = "overlays"))]
+            let show_backdrop = true;
+            if show_backdrop {
+                self.backdrop.draw(&mut game_grid, self.clock.tick());
+            }
+            self.profile(Phase::Draw, draw_started);
+
+            let input_started = Instant::now();
+            self.handle_input(&mut state, &mut world)?;
+            self.profile(Phase::Input, input_started);
+
+            let simulation_started = Instant::now();
+            let steps = self.clock.steps_this_frame();
+            if let Some(recorder) = &mut self.replay_recorder {
+                recorder.record_steps(self.clock.frame(), steps);
+            }
+            for _ in 0..steps {
+                self.update_game_state(clock::tick_seconds(), &mut world, &mut state, &mut spatial_hash);
+                if let Some(idle_decay) = &self.idle_decay {
+                    for (index, player) in world.players.iter_mut().enumerate() {
+                        idle_decay.apply(index, &mut player.score, self.clock.tick());
+                    }
+                }
+                if state == GameState::Playing && rules.is_won(&world.players, world.levels.waves_cleared()) {
+                    info!("Goal reached at tick {}: {:?}.", self.clock.tick(), rules.goal);
+                    state = GameState::Won;
+                }
+                #[cfg(feature = "overlays")]
+                if self.clock.tick().is_multiple_of(clock::ticks_for(DIFFICULTY_SAMPLE_INTERVAL)) {
+                    self.director_stats.record(DirectorSample {
+                        spawn_interval: world.levels.spawn_interval(),
+                        asteroid_cap: world.levels.quota(),
+                        speed_multiplier: world.levels.speed_multiplier,
+                        asteroid_count: world.asteroids.len(),
+                    });
+                }
+                self.clock.advance_tick();
+            }
+            self.profile(Phase::Simulation, simulation_started);
+
+            // Practice mode: a death with a checkpoint saved goes back to it instead of ending the run
+            let practice_action = match &mut self.practice {
+                Some(practice) if state == GameState::GameOver && practice.checkpoint.is_some() => Some(PracticeAction::Restore),
+                Some(practice) => practice.take_request(),
+                None => None,
+            };
+            match practice_action {
+                Some(PracticeAction::Save) if state == GameState::Playing => {
+                    if let Some(practice) = &mut self.practice {
+                        practice.checkpoint = Some(Snapshot { tick: self.clock.tick(), world: world.clone() });
+                    }
+                    info!("Practice checkpoint saved at tick {}.", self.clock.tick());
+                    world.banner = Some(("CHECKPOINT SAVED".to_string(), self.clock.timer(BANNER_DURATION)));
+                }
+                Some(PracticeAction::Restore) => {
+                    if let Some(snapshot) = self.practice.as_ref().and_then(|practice| practice.checkpoint.clone()) {
+                        info!("Practice checkpoint from tick {} restored.", snapshot.tick);
+                        self.clock.restore_tick(snapshot.tick);
+                        world = snapshot.world;
+                        state = GameState::Playing;
+                        world.banner = Some(("CHECKPOINT RESTORED".to_string(), self.clock.timer(BANNER_DURATION)));
+                    }
+                }
+                _ => {}
+            }
+            self.handle_quick_save(&mut world, &mut state);
+
+            if let Some(preview) = &mut self.preview {
+                preview.sample(self.clock.tick(), &world.asteroids, world.players[0].ship.position, world.saucer_encounter.saucer.is_some());
+            }
+
+            for event in self.event_bus.drain() {
+                for observer in &mut self.observers {
+                    observer.on_event(self.clock.tick(), &event);
+                }
+                if let Some(preview) = &mut self.preview {
+                    preview.record_event(self.clock.tick(), &event);
+                }
+                self.event_log.record(&event, self.clock.tick());
+                world.stats.record_event(&event);
+                #[cfg(feature = "audio")]
+                {
+                    if let Some(sound) = Sound::from_game_event(&event) {
+                        self.audio.play(sound);
+                    }
+                    self.mixer.on_event(&event);
+                }
+                if let Some(feedback) = FeedbackEvent::from_game_event(&event) {
+                    self.screen_shake.trigger(feedback, &self.clock);
+                    self.screen_flash.trigger(feedback, &self.clock);
+                }
+                if let GameEvent::MultiKill { count, .. } = event {
+                    self.clock.slow_down(BULLET_TIME_SCALE, BULLET_TIME_DURATION);
+                    world.banner = Some((format!(">>> {}x MULTI-KILL - BULLET TIME <<<", count), self.clock.timer(BANNER_DURATION)));
+                }
+            }
+            #[cfg(feature = "audio")]
+            {
+                self.mixer.update(steps as f64 * clock::tick_seconds());
+                self.audio.set_levels(self.mixer.levels());
+            }
+
+            spatial_hash.clear();
+            for (index, asteroid) in world.asteroids.iter().enumerate() {
+                spatial_hash.insert(index, asteroid.position);
+            }
+
+            // Draw game state onto GameGrid, through the camera following player one's ship plus any shake
+            let draw_started = Instant::now();
+            let (world_size, viewport) = (self.world_size(), self.viewport());
+            let (camera_x, camera_y) = Camera::follow(world.players[0].ship.position, viewport, world_size).offset();
+            self.screen_shake.intensity = self.config.screen_shake;
+            let (shake_x, shake_y) = self.screen_shake.offset(&self.clock);
+            game_grid.camera = (camera_x + shake_x as i32, camera_y + shake_y as i32);
+            game_grid.world = (world_size != viewport).then_some(world_size);
+            game_grid.fit = self.run_fixed_world().then_some(world_size);
+            let blink_phase = !(self.clock.tick() / clock::ticks_for(INVINCIBILITY_BLINK)).is_multiple_of(2);
+            let visible_ships: Vec<&Ship> = world.players
+                .iter()
+                .filter(|player| player.in_play() && !(player.invincibility.is_running(&self.clock) && blink_phase))
+                .map(|player| &player.ship)
+                .collect();
+            // With outlines on, particles go under the outline layer, which sits just below the ship and large asteroids
+            if self.config.outlines {
+                for particle in &world.particles {
+                    particle.draw(&mut game_grid);
+                }
+                for asteroid in world.asteroids.iter().filter(|asteroid| asteroid.size == AsteroidSize::Large) {
+                    game_grid.outline(&asteroid.outline_cells(), OUTLINE_CHAR, OUTLINE_COLOR);
+                }
+                for ship in &visible_ships {
+                    game_grid.outline(&ship.hull_cells(), OUTLINE_CHAR, OUTLINE_COLOR);
+                }
+            }
+            // Drawn back to front: ion clouds, shockwaves, ships and asteroids, the saucer and boss, then shots, pickups and effects on top
+            let mut field: Vec<&dyn Entity> = world.ion_clouds.iter().map(|cloud| cloud as &dyn Entity).collect();
+            field.extend(world.shockwaves.iter().map(|shockwave| shockwave as &dyn Entity));
+            field.extend(visible_ships.iter().map(|ship| *ship as &dyn Entity));
+            field.extend(world.asteroids.iter().map(|asteroid| asteroid as &dyn Entity));
+            for entity in field {
+                entity.draw(&mut game_grid);
+            }
+            if let Some((id, timer)) = world.bounty.target
+                && (self.clock.tick() / clock::ticks_for(BOUNTY_BLINK)).is_multiple_of(2)
+                && let Some(asteroid) = entity_id::find_by_id(&world.asteroids, id)
+            {
+                // "$" and the seconds left, just above the asteroid
+                let seconds = timer.remaining(&self.clock).div_ceil(clock::ticks_for(Duration::from_secs(1)));
+                let (x, y) = (asteroid.position.x - 1.0, asteroid.position.y - asteroid.size.radius() - 1.0);
+                game_grid.write_str(x.round().max(0.0) as u16, y.round().max(0.0) as u16, &format!("${}", seconds), BOUNTY_COLOR);
+            }
+            world.saucer_encounter.draw(&mut game_grid);
+            world.boss_encounter.draw(&mut game_grid);
+            let mut overlay: Vec<&dyn Entity> = world.bullets.iter().map(|bullet| bullet as &dyn Entity).collect();
+            if !self.config.outlines {
+                overlay.extend(world.particles.iter().map(|particle| particle as &dyn Entity));
+            }
+            overlay.extend(world.upgrade_boxes.iter().map(|upgrade_box| upgrade_box as &dyn Entity));
+            overlay.extend(world.upgrades.iter().map(|upgrade| upgrade as &dyn Entity));
+            overlay.extend(world.floating_texts.iter().map(|floating_text| floating_text as &dyn Entity));
+            for entity in overlay {
+                entity.draw(&mut game_grid);
+            }
+            #[cfg(feature = "overlays")]
+            if self.show_density_heatmap {
+                overlays::draw_density_heatmap(&mut game_grid, &spatial_hash);
+            }
+            (game_grid.camera, game_grid.world, game_grid.fit) = ((0, 0), None, None);
+            #[cfg(feature = "overlays")]
+            {
+                if self.show_difficulty_chart {
+                    overlays::draw_difficulty_chart(&mut game_grid, &self.director_stats);
+                }
+                if self.show_death_map
+                    && let Some(death_map) = &self.death_map
+                {
+                    overlays::draw_death_map(&mut game_grid, death_map);
+                }
+            }
+
+            if !self.headless {
+                if self.clock.is_paused() {
+                    game_grid.dim(Color::DarkGrey);
+                }
+                if self.config.minimap {
+                    let mut markers: Vec<(Vector2D, char)> = world.asteroids.iter().map(|asteroid| (asteroid.position, '.')).collect();
+                    markers.extend(world.upgrade_boxes.iter().map(|upgrade_box| (upgrade_box.position, 'U')));
+                    let bounty = world.bounty.target.and_then(|(id, _)| entity_id::find_by_id(&world.asteroids, id));
+                    markers.extend(bounty.map(|asteroid| (asteroid.position, '$')));
+                    markers.extend(world.saucer_encounter.saucer.iter().map(|saucer| (saucer.position, '!')));
+                    markers.extend(world.boss_encounter.boss.iter().map(|boss| (boss.position, 'O')));
+                    markers.extend(world.players.iter().filter(|player| player.in_play()).map(|player| (player.ship.position, '@')));
+                    let (world_width, world_height) = self.world_size();
+                    minimap.plot_world(&markers, world_width, world_height);
+                }
+                self.draw_hud(&mut game_grid, &minimap, &world);
+                let log_top = if self.config.minimap { MINIMAP_SIZE } else { 0 };
+                self.event_log.draw(&mut game_grid, log_top, self.clock.tick());
+                match state {
+                    GameState::Paused { selected } => pause_menu::draw(&mut game_grid, selected),
+                    GameState::Settings { selected } => settings_menu::draw(&mut game_grid, selected, &self.config),
+                    _ => {}
+                }
+
+                // Reverse video applies to whatever is written, so flash on/off repaints the whole screen
+                let flashing = self.config.hit_flash && self.screen_flash.is_active(&self.clock);
+                if flashing != was_flashing {
+                    game_grid.invalidate();
+                    was_flashing = flashing;
+                }
+                self.profile(Phase::Draw, draw_started);
+                let output_started = Instant::now();
+                if flashing {
+                    self.stdout_target.execute_other_command(SetAttribute(Attribute::Reverse))?;
+                }
+                for observer in &mut self.observers {
+                    observer.on_render(&game_grid);
+                }
+                self.render(&mut game_grid)?;
+                if flashing {
+                    self.stdout_target.execute_other_command(SetAttribute(Attribute::NoReverse))?;
+                }
+                if let Some(terminal_status) = &mut self.terminal_status {
+                    let progress_percent = world.levels.progress_percent(&world.asteroids);
+                    terminal_status.update(&mut self.stdout_target, player::team_score(&world.players), world.levels.wave, progress_percent)?;
+                }
+                self.profile(Phase::Output, output_started);
+            }
+
+            #[cfg(feature = "score-stream")]
+            if let Some(score_stream) = &mut self.score_stream {
+                let wave = world.levels.wave;
+                let ruleset_hash = self.ruleset.map(|ruleset| ruleset.hash());
+                let (score, player) = (player::team_score(&world.players), &world.players[0]);
+                let snapshot = StreamSnapshot { score, health: player.health, max_health: player.ship.max_health, wave, ruleset_hash };
+                score_stream.publish(snapshot, self.clock.frame());
+            }
+
+            if !self.observers.is_empty() {
+                let info = FrameInfo {
+                    frame: self.clock.frame(),
+                    tick: self.clock.tick(),
+                    score: player::team_score(&world.players),
+                    health: world.players[0].health,
+                    wave: world.levels.wave,
+                    game_over: matches!(state, GameState::GameOver | GameState::Won),
+                    ship: &world.players[0].ship,
+                    asteroids: &world.asteroids,
+                };
+                for observer in &mut self.observers {
+                    observer.on_frame(&info);
+                }
+                if self.observers.iter_mut().any(|observer| !observer.keep_running()) {
+                    state = GameState::Quit;
+                }
+            }
+
+            if let Some(profiler) = &mut self.profiler {
+                profiler.end_frame(self.clock.frame(), EntityCounts::of(&world))?;
+            }
+            if let Some(pacer) = &mut pacer {
+                pacer.wait(self.clock.frame());
+            }
+            self.clock.end_frame();
+        }
+        if let Some(pacer) = &pacer {
+            info!("Session ended after {} frames at {} fps, {} dropped.", self.clock.frame(), self.fps, pacer.dropped());
+        }
+
+        self.versus_round = self.run_versus().map(|kills_to_win| RoundSummary::new(&world.players, kills_to_win));
+        let score = player::team_score(&world.players);
+        let summary = RunSummary {
+            score,
+            frames: self.clock.frame(),
+            wave: world.levels.wave,
+            stats: world.stats,
+            state_hash: replay::state_hash(&world.players[0].ship, &world.asteroids, score, self.clock.frame()),
+        };
+        if self.headless {
+            self.final_snapshot = Some(Snapshot { tick: self.clock.tick(), world });
+        }
+        Ok((summary, state))
+    }
+
+    // Player one, plus player two in co-op or versus, each starting out invincible (spawn protection).
+    // Versus ships respawn for as long as the round lasts; a fleet starts in formation around the centre.
+    pub(super) fn new_players(&self) -> Vec<Player> {
+        let (world_width, world_height) = self.world_size();
+        let (center_x, center_y) = (world_width as f64 / 2.0, world_height as f64 / 2.0);
+        let invincibility = self.clock.timer(self.assist.invincibility(INVINCIBILITY_DURATION));
+        let new_ship = |x: f64, y: f64| {
+            let mut ship = Ship::new(x, y);
+            ship.max_health += self.assist.extra_starting_health + self.run_difficulty().extra_starting_health();
+            ship.set_dampeners(self.config.dampeners);
+            if let Some(loadout) = self.run_loadout() {
+                loadout.equip(&mut ship, &self.upgrade_balance);
+            }
+            ship
+        };
+        let lives = if self.run_versus().is_some() { u32::MAX } else { self.starting_lives };
+        if let Some(size) = self.run_fleet() {
+            return (0..size)
+                .map(|index| {
+                    let offset = fleet::formation_offset(index);
+                    Player::new(new_ship(center_x + offset.x, center_y + offset.y), lives, invincibility)
+                })
+                .collect();
+        }
+        if !self.two_player() {
+            return vec![Player::new(new_ship(center_x, center_y), lives, invincibility)];
+        }
+        vec![
+            Player::new(new_ship(center_x - COOP_SPAWN_SPACING, center_y), lives, invincibility),
+            Player::player_two(new_ship(center_x + COOP_SPAWN_SPACING, center_y), lives, invincibility),
+        ]
+    }
+}
@@ -0,0 +1,459 @@
+use std::time::Instant;
+use rand::Rng;
+use log::{error, info};
+
+use crate::constants::*;
+use crate::types::{Vector2D, wrap_coordinate};
+use crate::entities::{self, Asteroid, Entity, IonCloud, Particle, AsteroidSize, UpdateContext};
+use crate::status_effects::{self, StatusKind};
+use crate::active_effects::PowerUp;
+use crate::effects::Effect;
+use crate::upgrades::{Upgrade, UpgradeBox, UpgradeType};
+use crate::weapons;
+use crate::profiler::Phase;
+use crate::spatial::SpatialHash;
+use crate::fleet;
+use crate::events::GameEvent;
+use crate::clock::{self, Timer};
+use crate::hyperspace::JumpOutcome;
+use crate::player::Player;
+use crate::collisions;
+use crate::boss::BossEncounter;
+use crate::bounty::BountyChange;
+use crate::game_state::GameState;
+use crate::world::World;
+use crate::scoring::ScoreTarget;
+use crate::camera::Camera;
+use crate::entity_id::EntityId;
+
+use super::Game;
+use super::bullet_hits::BulletHits;
+
+// --- The simulation tick: each system that moves the world on by one step ---
+
+impl Game {
+    // One simulation step: each system below runs in this order, which replays depend on.
+    pub(super) fn update_game_state(&mut self, dt: f64, world: &mut World, state: &mut GameState, spatial_hash: &mut SpatialHash) {
+        let (world_width, world_height) = self.world_size();
+        let ctx = UpdateContext { dt, world_width, world_height };
+        let ships_started = Instant::now();
+        self.update_ships(world, &ctx, state);
+        self.profile(Phase::Ships, ships_started);
+
+        self.advance_waves(world, spatial_hash);
+        self.spawn_upgrade_boxes(world);
+        self.update_bounty(world);
+
+        let collisions_started = Instant::now();
+        let ship_coords = self.hazard_pass(world, &ctx, state, spatial_hash);
+        self.profile(Phase::Collisions, collisions_started);
+
+        let fitted_weapons = weapons::fitted_on_all(world.players.iter().filter(|player| player.in_play()).map(|player| &player.ship));
+        let mut hits = self.bullet_pass(world, &ctx, &ship_coords, &fitted_weapons);
+        self.settle_shots_on_players(world, &hits.shots_on_players, state);
+        self.shockwave_pass(world, &ctx, &mut hits);
+        self.settle_kills(world, &hits.kills, &fitted_weapons);
+        apply_explosions(&mut world.players, &hits.explosions);
+
+        let particles_started = Instant::now();
+        entities::update_all(&mut world.particles, &ctx);
+        entities::update_all(&mut world.ion_clouds, &ctx);
+        entities::update_all(&mut world.floating_texts, &ctx);
+        self.profile(Phase::Particles, particles_started);
+
+        self.collect_upgrades(world);
+    }
+
+    // Moves the ships and runs their respawns, hyperspace jumps, burns and power-up timers.
+    fn update_ships(&mut self, world: &mut World, ctx: &UpdateContext, state: &mut GameState) {
+        let World {
+            players,
+            asteroids,
+            particles,
+            ion_clouds,
+            fleet: fleet_control,
+            banner: current_banner,
+            saucer_encounter,
+            rng,
+            cosmetic_rng,
+            ..
+        } = world;
+        let (world_width, world_height) = (ctx.world_width, ctx.world_height);
+        // A fleet ship coming back rejoins the pool rather than refilling it
+        let fleet_pool = if fleet_control.is_some() { fleet::pool(players) } else { None };
+        for index in 0..players.len() {
+            let player = &mut players[index];
+            player.ship.update(ctx);
+            if player.in_play() && ion_clouds.iter().any(|cloud| cloud.contains(player.ship.position)) {
+                player.ship.status.apply(StatusKind::Slowed, ION_CLOUD_SLOW_DURATION);
+            }
+
+            if player.lives.try_respawn(&self.clock, &mut player.ship, asteroids, world_width, world_height) {
+                info!("Ship {} respawned, {} lives left.", index + 1, player.lives.remaining);
+                player.health = fleet_pool.unwrap_or(player.ship.max_health);
+                player.ship.status.clear();
+                player.ship.power_ups.clear();
+                if fleet_control.is_some() {
+                    let position = player.ship.position.add(fleet::formation_offset(index));
+                    player.ship.position = Vector2D::new(wrap_coordinate(position.x, world_width as f64), wrap_coordinate(position.y, world_height as f64));
+                }
+                player.invincibility = self.clock.timer(self.assist.invincibility(INVINCIBILITY_DURATION));
+            }
+
+            let jump = player.hyperspace.update(&mut player.ship, asteroids, particles, &self.effects, rng, cosmetic_rng, world_width, world_height);
+            if jump == Some(JumpOutcome::Destroyed) {
+                info!("Hyperspace malfunction destroyed ship {}.", index + 1);
+                player.health = 0;
+                self.event_bus.publish(GameEvent::ShipDamaged { shield: false });
+                self.ship_destroyed(players, index, particles, state, current_banner, "HYPERSPACE MALFUNCTION");
+            } else if jump == Some(JumpOutcome::Arrived)
+                && let Some(saucer) = &mut saucer_encounter.saucer
+                && status_effects::within(saucer.position, player.ship.position, HYPERSPACE_EMP_RADIUS)
+            {
+                // Arriving out of hyperspace sends out a pulse that knocks out a nearby saucer's systems
+                info!("Hyperspace EMP stunned the saucer.");
+                saucer.status.apply(StatusKind::Stunned, EMP_STUN_DURATION);
+            }
+
+            if players[index].in_play() && players[index].ship.status.tick() > 0 {
+                self.hit_player(players, index, particles, state, current_banner, "BURNED UP");
+            }
+            for power_up in players[index].ship.power_ups.tick() {
+                info!("{} wore off ship {}.", power_up.label(), index + 1);
+                self.event_bus.publish(GameEvent::PowerUpExpired { power_up });
+            }
+        }
+    }
+
+    // Lets the wave director spawn, and opens each new wave with its ion cloud and, on boss waves, the boss.
+    fn advance_waves(&mut self, world: &mut World, spatial_hash: &SpatialHash) {
+        let World { players, asteroids, ion_clouds, banner: current_banner, levels, boss_encounter, entity_ids, rng, .. } = world;
+        let (world_width, world_height) = self.world_size();
+        let Some(wave) = levels.update(
+            &self.clock,
+            asteroids,
+            entity_ids,
+            rng,
+            players[0].ship.position,
+            spatial_hash,
+            boss_encounter.is_active(),
+            world_width,
+            world_height,
+        ) else {
+            return;
+        };
+        self.event_bus.publish(GameEvent::WaveStarted { wave });
+        if wave >= ION_CLOUD_FIRST_WAVE {
+            let position = Vector2D::new(rng.gen_range(0.0..world_width as f64), rng.gen_range(0.0..world_height as f64));
+            ion_clouds.push(IonCloud::new(position, rng));
+        }
+        if BossEncounter::is_boss_wave(wave) {
+            boss_encounter.spawn(entity_ids, rng, world_width);
+            self.event_bus.publish(GameEvent::BossArrived { wave });
+            *current_banner = Some((format!("WAVE {} - BOSS INCOMING", wave), self.clock.timer(WAVE_INTERSTITIAL)));
+        } else {
+            *current_banner = Some((format!("WAVE {}", wave), self.clock.timer(WAVE_INTERSTITIAL)));
+        }
+    }
+
+    // Drops an upgrade box somewhere on the field every upgrade box interval.
+    fn spawn_upgrade_boxes(&mut self, world: &mut World) {
+        if !self.clock.tick().is_multiple_of(clock::ticks_for(self.run_difficulty().upgrade_box_interval())) {
+            return;
+        }
+        let (world_width, world_height) = self.world_size();
+        let x = world.rng.gen_range(0.0..world_width as f64);
+        let y = world.rng.gen_range(0.0..world_height as f64);
+        world.upgrade_boxes.push(UpgradeBox::new(world.entity_ids.allocate(), x, y));
+        self.event_bus.publish(GameEvent::UpgradeBoxSpawned { position: Vector2D::new(x, y) });
+    }
+
+    // Posts a bounty on an asteroid in view, or takes down one that has run out or lost its asteroid.
+    fn update_bounty(&mut self, world: &mut World) {
+        let World { players, asteroids, banner: current_banner, boss_encounter, bounty, entity_ids, rng, .. } = world;
+        let world_size = self.world_size();
+        let viewport = self.viewport();
+        let camera = Camera::follow(players[0].ship.position, viewport, world_size);
+        let in_view = |asteroid: &Asteroid| camera.sees(asteroid.position, viewport, world_size);
+        match bounty.update(&self.clock, asteroids, entity_ids, in_view, boss_encounter.is_active(), rng) {
+            Some(BountyChange::Posted { position }) => {
+                self.event_bus.publish(GameEvent::BountyPosted { position });
+                if current_banner.as_ref().is_none_or(|(_, timer)| !timer.is_running(&self.clock)) {
+                    *current_banner = Some(("BOUNTY POSTED".to_string(), self.clock.timer(BANNER_DURATION)));
+                }
+            }
+            Some(BountyChange::Expired) => self.event_bus.publish(GameEvent::BountyExpired),
+            None => {}
+        }
+    }
+
+    // Moves the asteroids, the boss and the saucer and hits every ship one of them touches. Returns the cells
+    // each ship can be hit in, for the shots to use.
+    fn hazard_pass(&mut self, world: &mut World, ctx: &UpdateContext, state: &mut GameState, spatial_hash: &mut SpatialHash) -> Vec<Vec<(u16, u16)>> {
+        let World { players, asteroids, particles, banner: current_banner, saucer_encounter, boss_encounter, entity_ids, rng, .. } = world;
+        let ship_coords: Vec<Vec<(u16, u16)>> = players.iter().map(|player| {
+            if !player.in_play() || self.preview.is_some() {
+                Vec::new()
+            } else if self.assist.forgiving_hitbox {
+                vec![(player.ship.position.x.round() as u16, player.ship.position.y.round() as u16)]
+            } else {
+                player.ship.coords()
+            }
+        }).collect();
+        // Any ship with time slowed holds back every hazard on the field
+        let hazard_ctx = if players.iter().any(|player| player.in_play() && player.ship.power_ups.has(PowerUp::TimeSlow)) {
+            UpdateContext { dt: ctx.dt * TIME_SLOW_FACTOR, ..*ctx }
+        } else {
+            *ctx
+        };
+        let mut ship_hits = vec![false; players.len()];
+        for asteroid in asteroids.iter_mut() {
+            asteroid.update(&hazard_ctx);
+            let asteroid_coords = asteroid.coords();
+            for (hit, coords) in ship_hits.iter_mut().zip(&ship_coords) {
+                *hit |= coords.iter().any(|ship_point| asteroid_coords.contains(ship_point));
+            }
+        }
+        collisions::resolve_asteroid_collisions(asteroids, spatial_hash);
+        let boss_hits = boss_encounter.update(&hazard_ctx, &ship_coords);
+        // The saucer hunts whichever ship is flying, player one's first
+        let target = players.iter().find(|player| player.in_play()).unwrap_or(&players[0]).ship.position;
+        let saucer_hits = saucer_encounter.update(&hazard_ctx, self.clock.tick(), target, &ship_coords, rng, entity_ids);
+        for index in 0..players.len() {
+            if ship_hits[index] || boss_hits[index] || saucer_hits[index] {
+                self.hit_player(players, index, particles, state, current_banner, "SHIP DESTROYED");
+            }
+        }
+        ship_coords
+    }
+
+    // Versus: each shot that hit the other ship, and the kill it scores if that brought the ship down.
+    fn settle_shots_on_players(&mut self, world: &mut World, shots_on_players: &[(usize, usize)], state: &mut GameState) {
+        let Some(kills_to_win) = self.run_versus() else {
+            return;
+        };
+        let World { players, particles, banner: current_banner, .. } = world;
+        for &(victim, shooter) in shots_on_players {
+            if !self.hit_player(players, victim, particles, state, current_banner, "SHOT DOWN") {
+                continue;
+            }
+            players[shooter].kills += 1;
+            let kills = players[shooter].kills;
+            info!("Player {} shot down player {} ({}/{} kills).", shooter + 1, victim + 1, kills, kills_to_win);
+            *current_banner = Some((format!("P{} SCORES A KILL - {}/{}", shooter + 1, kills, kills_to_win), self.clock.timer(BANNER_DURATION)));
+        }
+    }
+
+    // A bomb's shockwave breaks or shoves each asteroid its front reaches. What it breaks is added to `hits`, so
+    // it is scored along with the shots' kills.
+    fn shockwave_pass(&mut self, world: &mut World, ctx: &UpdateContext, hits: &mut BulletHits) {
+        let World { asteroids, particles, shockwaves, levels, entity_ids, rng, cosmetic_rng, .. } = world;
+        for shockwave in shockwaves.iter_mut() {
+            shockwave.update(ctx);
+            let earlier_kills = shockwave.kills;
+            let mut fragments: Vec<Asteroid> = Vec::new();
+            asteroids.retain_mut(|asteroid| {
+                if !shockwave.strike(asteroid.id, asteroid.parent, asteroid.position) {
+                    return true;
+                }
+                let push = shockwave.push_direction(asteroid.position);
+                if !asteroid.take_damage(SHOCKWAVE_DAMAGE) {
+                    asteroid.velocity = asteroid.velocity.add(push.scale(SHOCKWAVE_PUSH));
+                    return true;
+                }
+                hits.explosions.push((asteroid.position, asteroid.size, asteroid.volatile));
+                hits.kills.push((asteroid.id, asteroid.parent, asteroid.size, asteroid.position, shockwave.owner));
+                shockwave.kills += 1;
+                if asteroid.size.fragment_size().is_some() {
+                    let ids = [entity_ids.allocate(), entity_ids.allocate()];
+                    fragments.extend(asteroid.split(ids, push, rng, levels.speed_multiplier));
+                }
+                for _ in 0..self.effects.count(Effect::Explosion, 5) {
+                    let angle = cosmetic_rng.gen_range(0.0..2.0 * std::f64::consts::PI);
+                    let speed = cosmetic_rng.gen_range(0.5..1.5);
+                    particles.push(self.effects.particle(Effect::Explosion, asteroid.position, Vector2D::new(angle.cos() * speed, angle.sin() * speed)));
+                }
+                entity_ids.release(asteroid.id);
+                false
+            });
+            asteroids.extend(fragments);
+            // A bomb counts as one shot: bullet time the moment its front has broken enough asteroids
+            if earlier_kills < MULTI_KILL_THRESHOLD && shockwave.kills >= MULTI_KILL_THRESHOLD {
+                self.event_bus.publish(GameEvent::MultiKill { count: shockwave.kills, position: shockwave.center });
+            }
+        }
+        shockwaves.retain(|shockwave| shockwave.is_alive());
+    }
+
+    // Scores each destroyed asteroid for whoever broke it, along with any juggle chain or bounty it completes.
+    fn settle_kills(&mut self, world: &mut World, kills: &[(EntityId, Option<EntityId>, AsteroidSize, Vector2D, usize)], fitted_weapons: &[UpgradeType]) {
+        let World { players, upgrades, banner: current_banner, scoring, juggling, bounty, favoured_upgrades, entity_ids, rng, .. } = world;
+        let tick = self.clock.tick();
+        for &(id, parent, size, position, owner) in kills {
+            let points = scoring.award(ScoreTarget::Asteroid(size), tick);
+            players[owner].score += points;
+            self.event_bus.publish(GameEvent::AsteroidDestroyed { size, position, points });
+            if let Some(bonus) = juggling.asteroid_destroyed(id, parent, tick) {
+                players[owner].score += bonus;
+                let chain = juggling.chain;
+                self.event_bus.publish(GameEvent::Juggle { chain, bonus, position });
+                *current_banner = Some((format!("JUGGLE x{} +{}", chain, bonus), self.clock.timer(BANNER_DURATION)));
+            }
+            // A claimed bounty always drops an upgrade where the asteroid was
+            if bounty.claim(id, &self.clock) {
+                players[owner].score += BOUNTY_BONUS;
+                upgrades.push(Upgrade::new(entity_ids.allocate(), position, UpgradeType::weighted(rng, favoured_upgrades, fitted_weapons)));
+                self.event_bus.publish(GameEvent::BountyClaimed { bonus: BOUNTY_BONUS, position });
+                *current_banner = Some((format!("BOUNTY +{}", BOUNTY_BONUS), self.clock.timer(BANNER_DURATION)));
+            }
+        }
+    }
+
+    // The first ship in reach of an upgrade picks it up.
+    fn collect_upgrades(&mut self, world: &mut World) {
+        let World { players, upgrades, fleet: fleet_control, banner: current_banner, entity_ids, .. } = world;
+        upgrades.retain_mut(|upgrade| {
+            let collector = players.iter_mut().find(|player| {
+                let ship = &player.ship;
+                let distance = ((ship.position.x - upgrade.position.x).powi(2) + (ship.position.y - upgrade.position.y).powi(2)).sqrt();
+                distance <= UPGRADE_COLLECTION_RADIUS && player.in_play()
+            });
+            if let Some(Player { ship, health: player_health, .. }) = collector {
+                let upgrade_type = upgrade.upgrade_type;
+                if upgrade_type.is_kept() {
+                    ship.upgrades.push(upgrade_type);
+                    self.upgrade_balance.apply(ship, upgrade_type);
+                }
+                match upgrade_type {
+                    UpgradeType::Shield => ship.shield_count += 1,
+                    UpgradeType::ShipSize | UpgradeType::Health => *player_health = (*player_health + 1).min(ship.max_health),
+                    UpgradeType::HealthMax => *player_health = ship.max_health,
+                    _ => {}
+                }
+                if let Some(power_up) = upgrade_type.power_up() {
+                    ship.power_ups.activate(power_up);
+                }
+                *current_banner = Some((upgrade_type.pickup_banner().to_string(), self.clock.timer(BANNER_DURATION)));
+                entity_ids.release(upgrade.id);
+                self.event_bus.publish(GameEvent::UpgradeCollected { upgrade_type: upgrade.upgrade_type });
+                false
+            } else {
+                true
+            }
+        });
+        // A health pickup by any ship of a fleet tops up the pool
+        if fleet_control.is_some() {
+            fleet::share_health(players);
+        }
+    }
+
+    // Damages player `index`'s ship unless it is invincible: a shield absorbs the hit, otherwise it costs health.
+    // Returns true if that destroyed the ship. A fleet takes every hit together, and goes down together.
+    pub(super) fn hit_player(
+        &mut self,
+        players: &mut [Player],
+        index: usize,
+        particles: &mut Vec<Particle>,
+        state: &mut GameState,
+        current_banner: &mut Option<(String, Timer)>,
+        cause: &str,
+    ) -> bool {
+        let player = &mut players[index];
+        if player.invincibility.is_running(&self.clock) || player.ship.power_ups.has(PowerUp::Invincibility) {
+            return false;
+        }
+        let shield = player.ship.shield_count > 0;
+        if shield {
+            player.ship.shield_count -= 1;
+        } else {
+            player.health = player.health.saturating_sub(1);
+        }
+        player.invincibility = self.clock.timer(self.assist.invincibility(INVINCIBILITY_DURATION));
+        self.event_bus.publish(GameEvent::ShipDamaged { shield });
+        if self.run_fleet().is_some() {
+            let (health, invincibility) = (player.health, player.invincibility);
+            for player in players.iter_mut().filter(|player| player.in_play()) {
+                (player.health, player.invincibility) = (health, invincibility);
+            }
+            if health > 0 {
+                return false;
+            }
+            for index in 0..players.len() {
+                if players[index].in_play() {
+                    self.ship_destroyed(players, index, particles, state, current_banner, cause);
+                }
+            }
+            return true;
+        }
+        if player.health > 0 {
+            return false;
+        }
+        self.ship_destroyed(players, index, particles, state, current_banner, cause);
+        true
+    }
+
+    // Health ran out: spend a life and wait to respawn, or end the game on the last one.
+    pub(super) fn ship_destroyed(
+        &mut self,
+        players: &mut [Player],
+        index: usize,
+        particles: &mut Vec<Particle>,
+        state: &mut GameState,
+        current_banner: &mut Option<(String, Timer)>,
+        cause: &str,
+    ) {
+        players[index].deaths += 1;
+        players[index].ship.status.clear();
+        players[index].ship.power_ups.clear();
+        let ship = &players[index].ship;
+        let ring = self.effects.count(Effect::Wreck, 12);
+        for i in 0..ring {
+            let angle = i as f64 * 2.0 * std::f64::consts::PI / ring as f64;
+            let velocity = Vector2D::new(angle.cos(), angle.sin() * 0.5);
+            particles.push(self.effects.particle(Effect::Wreck, ship.position, velocity));
+        }
+        self.event_bus.publish(GameEvent::ShipDestroyed);
+        let (world_width, world_height) = self.world_size();
+        if let Some(death_map) = &mut self.death_map
+            && let Err(e) = death_map.record(ship.position, world_width, world_height, self.clock.tick())
+        {
+            error!("Failed to record death: {}", e);
+        }
+        // Two-player banners say whose ship it was
+        let cause = if self.two_player() { format!("P{} {}", index + 1, cause) } else { cause.to_string() };
+        if self.run_versus().is_some() {
+            players[index].lives.lose_life(&self.clock);
+            *current_banner = Some((cause, self.clock.timer(BANNER_DURATION)));
+            return;
+        }
+        let lives_left = if players[index].lives.lose_life(&self.clock) {
+            match players[index].lives.remaining {
+                1 => "1 LIFE LEFT".to_string(),
+                n => format!("{} LIVES LEFT", n),
+            }
+        } else if players.iter().all(|player| player.lives.remaining == 0) {
+            *state = GameState::GameOver;
+            return;
+        } else {
+            "OUT OF LIVES".to_string()
+        };
+        *current_banner = Some((format!("{} - {}", cause, lives_left), self.clock.timer(BANNER_DURATION)));
+    }
+}
+
+// Every explosion knocks nearby ships back, and a volatile asteroid's also sets them alight.
+fn apply_explosions(players: &mut [Player], explosions: &[(Vector2D, AsteroidSize, bool)]) {
+    for &(center, size, volatile) in explosions {
+        let strength = match size {
+            AsteroidSize::Large => EXPLOSION_KNOCKBACK_LARGE,
+            AsteroidSize::Medium => EXPLOSION_KNOCKBACK_MEDIUM,
+            AsteroidSize::Small => EXPLOSION_KNOCKBACK_SMALL,
+        };
+        for player in players.iter_mut() {
+            player.ship.apply_radial_impulse(center, strength, EXPLOSION_KNOCKBACK_RADIUS);
+            if volatile && player.in_play() && status_effects::within(player.ship.position, center, VOLATILE_BURN_RADIUS) {
+                player.ship.status.apply(StatusKind::Burning, BURN_DURATION);
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// --- GameState: what the main loop is currently doing ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameState {
+    Playing,
+    Paused { selected: usize }, // Index into the pause menu
+    Settings { selected: usize }, // Settings opened from the pause menu; the game stays paused
+    Restarting,
+    GameOver,
+    Won, // The mode's goal was reached
+    Quit,
+}
+
+impl GameState {
+    // Whether the current session's loop should keep running.
+    pub fn is_active(&self) -> bool {
+        matches!(self, GameState::Playing | GameState::Paused { .. } | GameState::Settings { .. })
+    }
+}
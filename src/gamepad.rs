@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use crate::analog::AnalogSettings;
+use crate::input::InputState;
+
+// --- GamepadBackend: a controller's contribution to each frame's ship controls ---
+// Live backends shape their sticks with `analog` (the player's dead zone, curve and sensitivity).
+pub trait GamepadBackend {
+    fn poll(&mut self, frame: u64, analog: &AnalogSettings) -> InputState;
+}
+
+// Plays back the `pad` lines of a replay, which were shaped when they were recorded.
+pub struct ScriptedGamepad {
+    frames: HashMap<u64, InputState>,
+}
+
+impl ScriptedGamepad {
+    pub fn new(frames: impl IntoIterator<Item = (u64, InputState)>) -> Self {
+        ScriptedGamepad { frames: frames.into_iter().collect() }
+    }
+}
+
+impl GamepadBackend for ScriptedGamepad {
+    fn poll(&mut self, frame: u64, _analog: &AnalogSettings) -> InputState {
+        self.frames.remove(&frame).unwrap_or_default()
+    }
+}
+
+#[cfg(all(feature = "gamepad", target_os = "linux"))]
+pub use joystick::Joystick;
+
+// Linux joystick API (`/dev/input/jsN`): no extra libraries, and it covers the Steam Deck's built-in pad.
+#[cfg(all(feature = "gamepad", target_os = "linux"))]
+mod joystick {
+    use std::fs::File;
+    use std::io::{self, Read};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use log::{error, info};
+
+    use super::GamepadBackend;
+    use crate::analog::AnalogSettings;
+    use crate::input::InputState;
+
+    const EVENT_BUTTON: u8 = 0x01;
+    const EVENT_AXIS: u8 = 0x02;
+    const EVENT_INIT: u8 = 0x80; // Set on the synthetic events that report the state at open time
+    const AXIS_STICK_X: u8 = 0; // Left stick
+    const AXIS_STICK_Y: u8 = 1;
+    const BUTTON_FIRE: u8 = 0; // A / Cross / South
+    const BUTTON_THRUST: u8 = 1; // B / Circle / East, for players who'd rather not push the stick up
+
+    #[derive(Default)]
+    struct PadState {
+        stick_x: f64, // -1.0 to 1.0
+        stick_y: f64,
+        fire: bool,
+        thrust: bool,
+    }
+
+    // Reads the device on its own thread; each poll takes the latest stick position and buttons.
+    pub struct Joystick {
+        state: Arc<Mutex<PadState>>,
+    }
+
+    impl Joystick {
+        pub fn open(path: &str) -> io::Result<Self> {
+            let mut device = File::open(path)?;
+            let state = Arc::new(Mutex::new(PadState::default()));
+            let shared = Arc::clone(&state);
+            thread::spawn(move || {
+                let mut event = [0u8; 8]; // u32 time, i16 value, u8 type, u8 number
+                loop {
+                    if let Err(e) = device.read_exact(&mut event) {
+                        error!("Gamepad disconnected: {}", e);
+                        *shared.lock().unwrap() = PadState::default();
+                        return;
+                    }
+                    let value = i16::from_le_bytes([event[4], event[5]]);
+                    let (kind, number) = (event[6] & !EVENT_INIT, event[7]);
+                    let mut pad = shared.lock().unwrap();
+                    match (kind, number) {
+                        (EVENT_AXIS, AXIS_STICK_X) => pad.stick_x = value as f64 / i16::MAX as f64,
+                        (EVENT_AXIS, AXIS_STICK_Y) => pad.stick_y = value as f64 / i16::MAX as f64,
+                        (EVENT_BUTTON, BUTTON_FIRE) => pad.fire = value != 0,
+                        (EVENT_BUTTON, BUTTON_THRUST) => pad.thrust = value != 0,
+                        _ => {}
+                    }
+                }
+            });
+            info!("Gamepad opened at {}.", path);
+            Ok(Joystick { state })
+        }
+    }
+
+    impl GamepadBackend for Joystick {
+        fn poll(&mut self, _frame: u64, analog: &AnalogSettings) -> InputState {
+            let pad = self.state.lock().unwrap();
+            // Past the dead zone, rotation speed follows the response curve; pushing up past it thrusts
+            InputState {
+                thrust: pad.thrust || analog.normalize(pad.stick_y) < 0.0,
+                fire: pad.fire,
+                steer: analog.normalize(pad.stick_x),
+                ..InputState::default()
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread::{self, JoinHandle};
+use log::error;
+
+use crate::analog::AnalogSettings;
+use crate::constants::{GYM_NEAREST_ASTEROIDS, TERMINAL_ASPECT_RATIO_COMPENSATION};
+use crate::game::Game;
+use crate::gamepad::GamepadBackend;
+use crate::input::InputState;
+use crate::observer::{FrameInfo, Observer};
+use crate::rendering::{OutputTarget, ScreenBuffer};
+use crate::terminal_io::SimulatedInput;
+
+// Ship values, then five per asteroid slot; see `Gym` for the layout.
+pub const OBSERVATION_LEN: usize = 7 + 5 * GYM_NEAREST_ASTEROIDS;
+
+pub type Observation = Vec<f64>;
+
+// What the agent does for one frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Action {
+    pub thrust: bool,
+    pub steer: f64, // -1.0 (full left) to 1.0, like a gamepad stick
+    pub fire: bool,
+}
+
+// --- Gym: the game as a reinforcement-learning environment, one frame per step ---
+// The game runs headless on a worker thread in lockstep with the caller: each action is one frame's input,
+// and each step waits for the observation that frame produced.
+//
+// Observation layout (OBSERVATION_LEN values):
+//   ship x / width, ship y / height, velocity x, velocity y, cos(angle), sin(angle), health / max health,
+//   then for the GYM_NEAREST_ASTEROIDS nearest asteroids (nearest first, zeros where there are fewer):
+//   dx / width, dy / height (shortest way round the wrapping world), velocity x, velocity y, radius.
+// The reward for a step is the points scored during it.
+pub struct Gym {
+    width: u16,
+    height: u16,
+    actions: Option<SyncSender<Action>>,
+    frames: Option<Receiver<(Observation, u32, bool)>>, // Observation, score, game over
+    worker: Option<JoinHandle<()>>,
+    last: (Observation, u32),
+    done: bool,
+}
+
+impl Gym {
+    pub fn new(width: u16, height: u16) -> Self {
+        Gym { width, height, actions: None, frames: None, worker: None, last: (vec![0.0; OBSERVATION_LEN], 0), done: true }
+    }
+
+    // Starts a fresh run from `seed` and returns its first observation.
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        self.close();
+        let (action_sender, action_receiver) = mpsc::sync_channel(0);
+        let (frame_sender, frame_receiver) = mpsc::sync_channel(0);
+        let (width, height) = (self.width, self.height);
+        self.worker = Some(thread::spawn(move || {
+            let stdout_target = OutputTarget::ScreenBuffer(ScreenBuffer::new(width, height));
+            let mut game = Game::new(width, height, stdout_target, Some(SimulatedInput::new(HashMap::new())), true, None, seed);
+            game.headless = true;
+            game.gamepad = Some(Box::new(AgentGamepad { actions: action_receiver }));
+            game.add_observer(Box::new(AgentObserver { frames: frame_sender, width, height, connected: true }));
+            if let Err(e) = game.run() {
+                error!("Gym run failed: {}", e);
+            }
+        }));
+        self.actions = Some(action_sender);
+        self.frames = Some(frame_receiver);
+        self.done = false;
+        self.last = (vec![0.0; OBSERVATION_LEN], 0);
+        // The first frame runs on a no-op so there is something to observe before the agent acts
+        self.step(Action::default()).0
+    }
+
+    // Plays one frame. Once the run is over, every step returns the final observation, no reward and done.
+    pub fn step(&mut self, action: Action) -> (Observation, f64, bool) {
+        if self.done {
+            return (self.last.0.clone(), 0.0, true);
+        }
+        let frame = match (&self.actions, &self.frames) {
+            (Some(actions), Some(frames)) if actions.send(action).is_ok() => frames.recv().ok(),
+            _ => None,
+        };
+        let Some((observation, score, game_over)) = frame else {
+            self.done = true;
+            return (self.last.0.clone(), 0.0, true);
+        };
+        let reward = score.saturating_sub(self.last.1) as f64;
+        self.last = (observation.clone(), score);
+        self.done = game_over;
+        (observation, reward, game_over)
+    }
+
+    // Ends the current run, if any: the worker sees the channels close, quits and is joined.
+    fn close(&mut self) {
+        self.actions = None;
+        self.frames = None;
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+        self.done = true;
+    }
+}
+
+impl Drop for Gym {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+// Feeds each action in as a frame's controls; blocks until the agent picks one. Agents steer directly, so the
+// player's stick settings don't apply.
+struct AgentGamepad {
+    actions: Receiver<Action>,
+}
+
+impl GamepadBackend for AgentGamepad {
+    fn poll(&mut self, _frame: u64, _analog: &AnalogSettings) -> InputState {
+        let action = self.actions.recv().unwrap_or_default();
+        InputState { thrust: action.thrust, fire: action.fire, steer: action.steer.clamp(-1.0, 1.0), ..InputState::default() }
+    }
+}
+
+struct AgentObserver {
+    frames: SyncSender<(Observation, u32, bool)>,
+    width: u16,
+    height: u16,
+    connected: bool,
+}
+
+impl Observer for AgentObserver {
+    fn on_frame(&mut self, info: &FrameInfo) {
+        let (width, height) = (self.width.max(1) as f64, self.height.max(1) as f64);
+        let ship = info.ship;
+        let mut observation = vec![
+            ship.position.x / width,
+            ship.position.y / height,
+            ship.velocity.x,
+            ship.velocity.y,
+            ship.angle.cos(),
+            ship.angle.sin(),
+            info.health as f64 / ship.max_health.max(1) as f64,
+        ];
+        // Shortest offset across a wrapping axis
+        let wrapped = |delta: f64, span: f64| (delta + span / 2.0).rem_euclid(span) - span / 2.0;
+        let mut nearby: Vec<(f64, [f64; 5])> = info.asteroids.iter().map(|asteroid| {
+            let dx = wrapped(asteroid.position.x - ship.position.x, width);
+            let dy = wrapped(asteroid.position.y - ship.position.y, height);
+            let distance = (dx / TERMINAL_ASPECT_RATIO_COMPENSATION).hypot(dy);
+            (distance, [dx / width, dy / height, asteroid.velocity.x, asteroid.velocity.y, asteroid.size.radius()])
+        }).collect();
+        nearby.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (_, values) in nearby.iter().take(GYM_NEAREST_ASTEROIDS) {
+            observation.extend_from_slice(values);
+        }
+        observation.resize(OBSERVATION_LEN, 0.0);
+        self.connected = self.frames.send((observation, info.score, info.game_over)).is_ok();
+    }
+
+    fn keep_running(&mut self) -> bool {
+        self.connected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_and_actions_give_the_same_observations() {
+        let actions = [Action { thrust: true, ..Action::default() }, Action { steer: 0.5, fire: true, ..Action::default() }, Action::default()];
+        let run = |gym: &mut Gym| -> Vec<Observation> {
+            let mut observations = vec![gym.reset(11)];
+            for action in actions {
+                let (observation, _, done) = gym.step(action);
+                assert!(!done);
+                observations.push(observation);
+            }
+            observations
+        };
+        let mut gym = Gym::new(80, 24);
+        let first = run(&mut gym);
+        assert!(first.iter().all(|observation| observation.len() == OBSERVATION_LEN));
+        assert_eq!(first, run(&mut gym));
+    }
+}
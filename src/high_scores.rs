@@ -0,0 +1,172 @@
+use std::cmp::Reverse;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+use log::error;
+
+const HIGH_SCORES_HEADER: &str = "vibe-asteroid-scores 2";
+const HIGH_SCORES_HEADER_V1: &str = "vibe-asteroid-scores 1"; // Before assisted runs were marked
+pub const HIGH_SCORE_SLOTS: usize = 10;
+
+// --- HighScores: the best local runs with the seed each was played on, best first ---
+// Runs played with assist options on are kept in the same table but marked, so they're never mistaken for
+// unassisted ones.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HighScores {
+    pub entries: Vec<(u32, u64, bool)>, // (score, seed, assisted)
+}
+
+impl HighScores {
+    // Location of the table: $XDG_DATA_HOME/vibe-asteroid/scores or ~/.local/share/vibe-asteroid/scores.
+    pub fn path() -> Option<PathBuf> {
+        let base = match env::var_os("XDG_DATA_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".local").join("share"),
+        };
+        Some(base.join("vibe-asteroid").join("scores"))
+    }
+
+    // A missing table is an empty one.
+    pub fn load() -> io::Result<Self> {
+        let mut high_scores = HighScores::default();
+        let Some(path) = Self::path() else {
+            return Ok(high_scores);
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(high_scores),
+            Err(e) => return Err(e),
+        };
+        let mut lines = contents.lines();
+        if !matches!(lines.next(), Some(HIGH_SCORES_HEADER | HIGH_SCORES_HEADER_V1)) {
+            return Err(invalid_scores("missing high score header"));
+        }
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["score", score, "seed", seed] => high_scores.entries.push((parse_field(score)?, parse_field(seed)?, false)),
+                ["score", score, "seed", seed, "assist"] => high_scores.entries.push((parse_field(score)?, parse_field(seed)?, true)),
+                _ => return Err(invalid_scores("unrecognised high score line")),
+            }
+        }
+        high_scores.entries.sort_by_key(|&(score, _, _)| Reverse(score));
+        high_scores.entries.truncate(HIGH_SCORE_SLOTS);
+        Ok(high_scores)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path().ok_or_else(|| invalid_scores("no home directory for the high score table"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(&path)?;
+        writeln!(file, "{}", HIGH_SCORES_HEADER)?;
+        for &(score, seed, assisted) in &self.entries {
+            writeln!(file, "score {} seed {}{}", score, seed, if assisted { " assist" } else { "" })?;
+        }
+        Ok(())
+    }
+
+    // Returns where the score placed (0 for the top), or None if it didn't make the table. Ties keep the earlier
+    // run ahead.
+    pub fn record(&mut self, score: u32, seed: u64, assisted: bool) -> Option<usize> {
+        let rank = self.entries.iter().position(|&(existing, _, _)| score > existing).unwrap_or(self.entries.len());
+        if score == 0 || rank >= HIGH_SCORE_SLOTS {
+            return None;
+        }
+        self.entries.insert(rank, (score, seed, assisted));
+        self.entries.truncate(HIGH_SCORE_SLOTS);
+        Some(rank)
+    }
+}
+
+// Where a run's entry into the table has got to, for the game over screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SubmissionStatus {
+    Saving,
+    Ranked(usize), // Saved at this place, 0 for the top
+    Unranked,      // Didn't make the table, so there was nothing to save
+    Failed,        // The write failed; the error is in the log
+}
+
+impl SubmissionStatus {
+    pub fn message(self) -> Option<String> {
+        match self {
+            SubmissionStatus::Saving => Some("Saving high score...".to_string()),
+            SubmissionStatus::Ranked(rank) => Some(format!("New high score: rank #{}", rank + 1)),
+            SubmissionStatus::Unranked => None,
+            SubmissionStatus::Failed => Some("High score not saved, see vibe-asteroid.log".to_string()),
+        }
+    }
+}
+
+// --- ScoreSubmission: a finished run's entry into the table, written out on a background thread ---
+// The in-memory table is updated straight away; the file write reports back over a channel, so the game over
+// screen can draw at once and show the result when it lands.
+pub struct ScoreSubmission {
+    pending: Option<Receiver<io::Result<()>>>,
+    rank: Option<usize>,
+    status: SubmissionStatus,
+}
+
+impl ScoreSubmission {
+    pub fn start(high_scores: &mut HighScores, score: u32, seed: u64, assisted: bool) -> Self {
+        let Some(rank) = high_scores.record(score, seed, assisted) else {
+            return ScoreSubmission { pending: None, rank: None, status: SubmissionStatus::Unranked };
+        };
+        let table = high_scores.clone();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(table.save());
+        });
+        ScoreSubmission { pending: Some(receiver), rank: Some(rank), status: SubmissionStatus::Saving }
+    }
+
+    // Picks up the write's result if it has arrived, without waiting for it.
+    pub fn poll(&mut self) -> SubmissionStatus {
+        let result = match &self.pending {
+            Some(receiver) => match receiver.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Empty) => return self.status,
+                Err(TryRecvError::Disconnected) => Err(io::Error::other("the high score writer stopped")),
+            },
+            None => return self.status,
+        };
+        self.settle(result)
+    }
+
+    // Waits for the write to finish, so the process never exits halfway through it.
+    pub fn finish(&mut self) -> SubmissionStatus {
+        let result = match &self.pending {
+            Some(receiver) => receiver.recv().unwrap_or_else(|_| Err(io::Error::other("the high score writer stopped"))),
+            None => return self.status,
+        };
+        self.settle(result)
+    }
+
+    fn settle(&mut self, result: io::Result<()>) -> SubmissionStatus {
+        self.pending = None;
+        self.status = match (result, self.rank) {
+            (Ok(()), Some(rank)) => SubmissionStatus::Ranked(rank),
+            (Ok(()), None) => SubmissionStatus::Unranked,
+            (Err(e), _) => {
+                error!("Failed to save high scores: {}", e);
+                SubmissionStatus::Failed
+            }
+        };
+        self.status
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str) -> io::Result<T> {
+    field.parse().map_err(|_| invalid_scores("malformed numeric field"))
+}
+
+fn invalid_scores(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -0,0 +1,119 @@
+use crossterm::style::Color;
+
+use crate::rendering::{Corner, GameGrid};
+
+pub const CONTROLS: [&str; 12] = [
+    "Up Arrow   : Thrust",
+    "Left Arrow : Rotate Left",
+    "Right Arrow: Rotate Right",
+    "Spacebar   : Fire Laser",
+    "h / Down   : Hyperspace",
+    "b          : Bomb",
+    "d          : Dampeners",
+    "u / x      : Drop Upgrade",
+    "p / Esc    : Pause",
+    "F6         : Record Macro",
+    "m          : Play Macro",
+    "q          : Quit",
+];
+
+// Fleet: one set of keys steers whichever ships Tab has selected.
+pub const FLEET_CONTROLS: [&str; 11] = [
+    "Arrows     : Fly",
+    "Spacebar   : Fire Laser",
+    "h / Down   : Hyperspace",
+    "b          : Bomb",
+    "Tab        : Switch Ship",
+    "d          : Dampeners",
+    "u / x      : Drop Upgrade",
+    "p / Esc    : Pause",
+    "F6         : Record Macro",
+    "m          : Play Macro",
+    "q          : Quit",
+];
+
+// Co-op: both players' keys, and no dampeners key because D is player two's.
+pub const COOP_CONTROLS: [&str; 14] = [
+    "P1 Arrows      : Fly",
+    "P1 Spacebar    : Fire",
+    "P1 h / Down    : Hyperspace",
+    "P1 b           : Bomb",
+    "P1 u / x       : Drop Upgrade",
+    "P2 W / A / D   : Fly",
+    "P2 L-Shift / f : Fire",
+    "P2 s           : Hyperspace",
+    "P2 g           : Bomb",
+    "P2 e / r       : Drop Upgrade",
+    "p / Esc        : Pause",
+    "F6             : Record Macro",
+    "m              : Play Macro",
+    "q              : Quit",
+];
+
+// --- Panel: a titled box of text lines drawn over the playfield ---
+pub struct Panel {
+    pub title: &'static str,
+    pub lines: Vec<String>,
+    pub color: Color,
+}
+
+impl Panel {
+    pub fn new(title: &'static str, lines: Vec<String>, color: Color) -> Self {
+        Panel { title, lines, color }
+    }
+
+    pub fn width(&self) -> u16 {
+        let widest = self.lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        widest.max(self.title.chars().count() + 2) as u16 + 2
+    }
+
+    pub fn height(&self) -> u16 {
+        self.lines.len() as u16 + 2
+    }
+
+    // The interior is blanked first, so gameplay glyphs underneath never run into the text.
+    pub fn draw(&self, game_grid: &mut GameGrid, x: u16, y: u16) {
+        let inner_width = self.width() as usize - 2;
+        let title = format!(" {} ", self.title);
+        game_grid.write_str(x, y, &format!("┌{:─<width$}┐", title, width = inner_width), self.color);
+        for (i, line) in self.lines.iter().enumerate() {
+            let row = y.saturating_add(i as u16 + 1);
+            game_grid.write_str(x, row, &format!("│{:<width$}│", line, width = inner_width), self.color);
+        }
+        game_grid.write_str(x, y.saturating_add(self.height() - 1), &format!("└{}┘", "─".repeat(inner_width)), self.color);
+    }
+}
+
+// A gauge `width` cells long, filled in proportion to `value / max`.
+pub fn meter(value: f64, max: f64, width: usize) -> String {
+    let filled = ((value / max.max(f64::EPSILON)) * width as f64).round().clamp(0.0, width as f64) as usize;
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+// One pip per charge, e.g. shields left.
+pub fn pips(count: u32) -> String {
+    if count == 0 { "-".to_string() } else { "●".repeat(count as usize) }
+}
+
+// Status top-left, controls bottom-left, active upgrades (if any) bottom-right, all anchored to the grid's
+// current size. `minimap` is the corner the minimap takes and its height; the panel sharing that corner moves
+// along the edge to clear it.
+pub fn draw(game_grid: &mut GameGrid, status: &Panel, controls: &[&str], upgrades: &Panel, minimap: Option<(Corner, u16)>) {
+    let bounds = (game_grid.width, game_grid.height);
+    let clearance = |corner: Corner| match minimap {
+        Some((taken, height)) if taken == corner => height,
+        _ => 0,
+    };
+
+    let (x, y) = Corner::NorthWest.anchor((status.width(), status.height()), bounds);
+    status.draw(game_grid, x, y + clearance(Corner::NorthWest));
+
+    let controls = Panel::new("Controls", controls.iter().map(|line| line.to_string()).collect(), Color::Reset);
+    let (x, y) = Corner::SouthWest.anchor((controls.width(), controls.height()), bounds);
+    controls.draw(game_grid, x, y.saturating_sub(clearance(Corner::SouthWest)));
+
+    if !upgrades.lines.is_empty() {
+        let (x, y) = Corner::SouthEast.anchor((upgrades.width(), upgrades.height()), bounds);
+        upgrades.draw(game_grid, x, y.saturating_sub(clearance(Corner::SouthEast)));
+    }
+}
@@ -0,0 +1,93 @@
+use rand::Rng;
+
+use crate::constants::*;
+use crate::effects::{Effect, EffectsTheme};
+use crate::entities::{Asteroid, Particle, Ship};
+use crate::spatial::clearance;
+use crate::types::Vector2D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JumpOutcome {
+    Arrived,
+    Destroyed, // The drive malfunctioned and the ship broke up
+}
+
+// --- Hyperspace: panic-button teleport, requested from input and carried out on the next tick ---
+#[derive(Clone)]
+pub struct Hyperspace {
+    requested: bool,
+}
+
+impl Hyperspace {
+    pub fn new() -> Self {
+        Hyperspace { requested: false }
+    }
+
+    pub fn request(&mut self) {
+        self.requested = true;
+    }
+
+    pub fn is_ready(&self, ship: &Ship) -> bool {
+        ship.energy.can_afford(HYPERSPACE_ENERGY_COST)
+    }
+
+    // Jumps if a request is pending and the ship has the energy; requests it can't pay for are dropped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        ship: &mut Ship,
+        asteroids: &[Asteroid],
+        particles: &mut Vec<Particle>,
+        effects: &EffectsTheme,
+        rng: &mut impl Rng,
+        cosmetic_rng: &mut impl Rng,
+        terminal_width: u16,
+        terminal_height: u16,
+    ) -> Option<JumpOutcome> {
+        if !std::mem::take(&mut self.requested) || !ship.energy.try_spend(HYPERSPACE_ENERGY_COST) {
+            return None;
+        }
+        warp_effect(ship.position, particles, effects, cosmetic_rng);
+        if rng.gen_bool(HYPERSPACE_FAILURE_CHANCE) {
+            return Some(JumpOutcome::Destroyed);
+        }
+        ship.position = safe_location(asteroids, rng, terminal_width, terminal_height);
+        ship.velocity = Vector2D::new(0.0, 0.0);
+        warp_effect(ship.position, particles, effects, cosmetic_rng);
+        Some(JumpOutcome::Arrived)
+    }
+}
+
+impl Default for Hyperspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Random point with enough clearance; in a crowded field, the roomiest of the points tried.
+fn safe_location(asteroids: &[Asteroid], rng: &mut impl Rng, terminal_width: u16, terminal_height: u16) -> Vector2D {
+    let (w, h) = (terminal_width.max(3) as f64, terminal_height.max(3) as f64);
+    let mut best = Vector2D::new(w / 2.0, h / 2.0);
+    let mut best_clearance = f64::NEG_INFINITY;
+    for _ in 0..HYPERSPACE_ATTEMPTS {
+        let candidate = Vector2D::new(rng.gen_range(1.0..w - 1.0), rng.gen_range(1.0..h - 1.0));
+        let candidate_clearance = clearance(candidate, asteroids);
+        if candidate_clearance >= HYPERSPACE_SAFE_DISTANCE {
+            return candidate;
+        }
+        if candidate_clearance > best_clearance {
+            best = candidate;
+            best_clearance = candidate_clearance;
+        }
+    }
+    best
+}
+
+fn warp_effect(position: Vector2D, particles: &mut Vec<Particle>, effects: &EffectsTheme, rng: &mut impl Rng) {
+    let ring = effects.count(Effect::Warp, HYPERSPACE_WARP_PARTICLES);
+    for i in 0..ring {
+        let angle = i as f64 * 2.0 * std::f64::consts::PI / ring as f64 + rng.gen_range(0.0..0.3);
+        let velocity = Vector2D::new(angle.cos() * TERMINAL_ASPECT_RATIO_COMPENSATION, angle.sin());
+        particles.push(effects.particle(Effect::Warp, position, velocity));
+    }
+}
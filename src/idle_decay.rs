@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use crate::clock::ticks_for;
+
+// --- IdleDecay drains a player's score while they neither move nor fire (measured in simulation ticks) ---
+// Each player has their own idle timer, so in co-op or versus one player idling costs only their own score.
+pub struct IdleDecay {
+    pub grace_ticks: u64,
+    pub interval_ticks: u64,
+    pub points_per_interval: u32,
+    last_activity_ticks: Vec<u64>, // Indexed by player; players not in it yet haven't acted since tick 0
+}
+
+impl IdleDecay {
+    pub fn new(grace: Duration, interval: Duration, points_per_interval: u32) -> Self {
+        IdleDecay { grace_ticks: ticks_for(grace), interval_ticks: ticks_for(interval), points_per_interval, last_activity_ticks: Vec::new() }
+    }
+
+    pub fn note_activity(&mut self, player: usize, tick: u64) {
+        if self.last_activity_ticks.len() <= player {
+            self.last_activity_ticks.resize(player + 1, 0);
+        }
+        self.last_activity_ticks[player] = tick;
+    }
+
+    fn last_activity(&self, player: usize) -> u64 {
+        self.last_activity_ticks.get(player).copied().unwrap_or(0)
+    }
+
+    pub fn is_idle(&self, player: usize, tick: u64) -> bool {
+        tick.saturating_sub(self.last_activity(player)) >= self.grace_ticks
+    }
+
+    pub fn apply(&self, player: usize, score: &mut u32, tick: u64) {
+        if !self.is_idle(player, tick) {
+            return;
+        }
+        let idle_ticks = tick - self.last_activity(player) - self.grace_ticks;
+        if idle_ticks.is_multiple_of(self.interval_ticks) {
+            *score = score.saturating_sub(self.points_per_interval);
+        }
+    }
+}
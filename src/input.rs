@@ -0,0 +1,174 @@
+use std::io;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Instant;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, ModifierKeyCode};
+use log::error;
+
+// Keys whose action repeats every frame while they are held down.
+pub const HELD_KEYS: [KeyCode; 4] = [KeyCode::Up, KeyCode::Left, KeyCode::Right, KeyCode::Char(' ')];
+// Co-op player two's ship controls. Left Shift only arrives on its own where the terminal reports every key;
+// F fires everywhere else.
+pub const PLAYER_TWO_KEYS: [KeyCode; 5] =
+    [KeyCode::Char('w'), KeyCode::Char('a'), KeyCode::Char('d'), KeyCode::Modifier(ModifierKeyCode::LeftShift), KeyCode::Char('f')];
+
+// --- InputThread: reads terminal events on its own thread so a frame never blocks on the terminal ---
+pub struct InputThread {
+    events: Receiver<io::Result<Event>>,
+}
+
+impl InputThread {
+    pub fn spawn() -> Self {
+        let (sender, events) = mpsc::channel();
+        thread::spawn(move || loop {
+            let event = event::read();
+            let failed = event.is_err();
+            if let Err(e) = &event {
+                error!("Failed to read event: {}", e);
+            }
+            if sender.send(event).is_err() || failed {
+                break;
+            }
+        });
+        InputThread { events }
+    }
+
+    // Blocks until the next event.
+    pub fn read(&self) -> io::Result<Event> {
+        self.events.recv().map_err(|_| stopped())?
+    }
+
+    // Everything that arrives before `deadline`, in order; waits out the rest of the time if nothing does.
+    pub fn events_until(&self, deadline: Instant) -> io::Result<Vec<Event>> {
+        let mut events = Vec::new();
+        loop {
+            match self.events.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(event) => events.push(event?),
+                Err(RecvTimeoutError::Timeout) => return Ok(events),
+                Err(RecvTimeoutError::Disconnected) => return Err(stopped()),
+            }
+        }
+    }
+}
+
+fn stopped() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "input thread stopped")
+}
+
+// --- InputState: the ship controls asked for this frame, however many events asked for them ---
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InputState {
+    pub thrust: bool,
+    pub rotate_left: bool,
+    pub rotate_right: bool,
+    pub fire: bool,
+    pub steer: f64, // Analog rotation from a gamepad stick, -1.0 (full left) to 1.0
+}
+
+impl InputState {
+    // Returns false for keys that aren't ship controls, which the caller handles itself.
+    pub fn press(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Up => self.thrust = true,
+            KeyCode::Left => self.rotate_left = true,
+            KeyCode::Right => self.rotate_right = true,
+            KeyCode::Char(' ') => self.fire = true,
+            _ => return false,
+        }
+        true
+    }
+
+    // Player two's WASD-side keys; holding Shift makes them arrive as capitals, which count the same.
+    pub fn press_player_two(&mut self, code: KeyCode) -> bool {
+        match normalize(code) {
+            KeyCode::Char('w') => self.thrust = true,
+            KeyCode::Char('a') => self.rotate_left = true,
+            KeyCode::Char('d') => self.rotate_right = true,
+            KeyCode::Modifier(ModifierKeyCode::LeftShift) | KeyCode::Char('f') => self.fire = true,
+            _ => return false,
+        }
+        true
+    }
+
+    // A gamepad's controls on top of the keyboard's.
+    pub fn merge(&mut self, other: InputState) {
+        self.thrust |= other.thrust;
+        self.rotate_left |= other.rotate_left;
+        self.rotate_right |= other.rotate_right;
+        self.fire |= other.fire;
+        self.steer = (self.steer + other.steer).clamp(-1.0, 1.0);
+    }
+
+    pub fn is_active(&self) -> bool {
+        *self != InputState::default()
+    }
+}
+
+// A ship control goes to the player whose key it is: the arrows and Space are player one's, and with a second
+// player `PLAYER_TWO_KEYS` are theirs. Returns false for keys that aren't ship controls.
+pub fn press(inputs: &mut [InputState], code: KeyCode) -> bool {
+    match inputs {
+        [] => false,
+        [first] => first.press(code),
+        [first, second, ..] => first.press(code) || second.press_player_two(code),
+    }
+}
+
+fn normalize(code: KeyCode) -> KeyCode {
+    match code {
+        KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+        code => code,
+    }
+}
+
+// --- HeldKeys: which ship control keys are down, tracked from press and release reports ---
+// Only meaningful on terminals that report releases; elsewhere auto-repeat presses stand in for holding.
+pub struct HeldKeys {
+    keys: Vec<KeyCode>, // HELD_KEYS, plus PLAYER_TWO_KEYS in co-op
+    held: Vec<KeyCode>,
+}
+
+impl HeldKeys {
+    pub fn new() -> Self {
+        HeldKeys { keys: HELD_KEYS.to_vec(), held: Vec::new() }
+    }
+
+    // Also track `keys` from now on.
+    pub fn track(&mut self, keys: &[KeyCode]) {
+        for &code in keys {
+            if !self.keys.contains(&code) {
+                self.keys.push(code);
+            }
+        }
+    }
+
+    pub fn tracks(&self, code: KeyCode) -> bool {
+        self.keys.contains(&normalize(code))
+    }
+
+    pub fn update(&mut self, key_event: &KeyEvent) {
+        let code = normalize(key_event.code);
+        if !self.keys.contains(&code) {
+            return;
+        }
+        match key_event.kind {
+            KeyEventKind::Press if !self.held.contains(&code) => self.held.push(code),
+            KeyEventKind::Release => self.held.retain(|&held| held != code),
+            _ => {}
+        }
+    }
+
+    pub fn held(&self) -> Vec<KeyCode> {
+        self.held.clone()
+    }
+
+    pub fn clear(&mut self) {
+        self.held.clear();
+    }
+}
+
+impl Default for HeldKeys {
+    fn default() -> Self {
+        Self::new()
+    }
+}
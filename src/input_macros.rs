@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+
+pub const MAX_MACRO_LENGTH: Duration = Duration::from_secs(3); // Recording stops on its own after this long
+
+// --- InputMacros: record a short key sequence and replay it from a single key ---
+pub struct InputMacros {
+    recording: Option<(u64, Vec<(u64, KeyCode)>)>, // Start frame and (frame offset, key) steps
+    max_frames: u64, // MAX_MACRO_LENGTH at the frame rate the current recording was started at
+    bound: Vec<(u64, KeyCode)>,
+    playback: Option<(u64, usize)>, // Start frame and index of the next step
+}
+
+impl InputMacros {
+    pub fn new() -> Self {
+        InputMacros { recording: None, max_frames: 0, bound: Vec::new(), playback: None }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    // Starting a recording replaces the bound macro once it is stopped. Steps are counted in frames, so the
+    // length limit is turned into frames at the rate the game is running at.
+    pub fn toggle_recording(&mut self, frame: u64, fps: u32) {
+        match self.recording.take() {
+            Some((_, steps)) => self.bound = steps,
+            None => {
+                self.playback = None;
+                self.max_frames = (MAX_MACRO_LENGTH.as_secs_f64() * fps as f64).round() as u64;
+                self.recording = Some((frame, Vec::new()));
+            }
+        }
+    }
+
+    pub fn record(&mut self, frame: u64, code: KeyCode) {
+        if let Some((start_frame, steps)) = &mut self.recording {
+            let offset = frame - *start_frame;
+            if offset > self.max_frames {
+                self.bound = std::mem::take(steps);
+                self.recording = None;
+            } else {
+                steps.push((offset, code));
+            }
+        }
+    }
+
+    pub fn start_playback(&mut self, frame: u64) {
+        if !self.bound.is_empty() && self.recording.is_none() {
+            self.playback = Some((frame, 0));
+        }
+    }
+
+    // Key the macro presses this frame, if any; one step per frame like regular input.
+    pub fn next_key(&mut self, frame: u64) -> Option<KeyCode> {
+        let (start_frame, next_index) = self.playback?;
+        let &(offset, code) = self.bound.get(next_index)?;
+        if start_frame + offset > frame {
+            return None;
+        }
+        if next_index + 1 < self.bound.len() {
+            self.playback = Some((start_frame, next_index + 1));
+        } else {
+            self.playback = None;
+        }
+        Some(code)
+    }
+}
+
+impl Default for InputMacros {
+    fn default() -> Self {
+        Self::new()
+    }
+}
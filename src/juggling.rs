@@ -0,0 +1,41 @@
+use crate::clock;
+use crate::constants::{JUGGLE_BONUS_PER_LINK, JUGGLE_WINDOW};
+use crate::entity_id::EntityId;
+
+// --- Juggling: keeping one asteroid's fragments in play and shooting them down in quick succession ---
+#[derive(Clone)]
+pub struct Juggling {
+    family: Vec<EntityId>, // Asteroids destroyed in the current chain, and the parents they split from
+    last_kill_tick: u64,
+    pub chain: u32,
+}
+
+impl Juggling {
+    pub fn new() -> Self {
+        Juggling { family: Vec::new(), last_kill_tick: 0, chain: 0 }
+    }
+
+    // Call for every asteroid destroyed. A kill continues the chain when it comes within JUGGLE_WINDOW of the
+    // last one and the asteroid is a sibling or child of something already in the chain. Returns the bonus
+    // for the kill: nothing for the first link, then JUGGLE_BONUS_PER_LINK more for every link after it.
+    pub fn asteroid_destroyed(&mut self, id: EntityId, parent: Option<EntityId>, tick: u64) -> Option<u32> {
+        let in_window = self.chain > 0 && tick - self.last_kill_tick <= clock::ticks_for(JUGGLE_WINDOW);
+        let related = parent.is_some_and(|parent| self.family.contains(&parent));
+        if in_window && related {
+            self.chain += 1;
+        } else {
+            self.chain = 1;
+            self.family.clear();
+            self.family.extend(parent);
+        }
+        self.family.push(id);
+        self.last_kill_tick = tick;
+        (self.chain > 1).then(|| JUGGLE_BONUS_PER_LINK * (self.chain - 1))
+    }
+}
+
+impl Default for Juggling {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,30 @@
+// --- Letterbox: caps the playfield on very large terminals and centres it, framed by a border ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Letterbox {
+    pub max_width: u16,
+    pub max_height: u16,
+}
+
+impl Letterbox {
+    // "<width>x<height>", e.g. "160x48".
+    pub fn parse(text: &str) -> Option<Self> {
+        let (width, height) = text.split_once('x')?;
+        let letterbox = Letterbox { max_width: width.parse().ok()?, max_height: height.parse().ok()? };
+        (letterbox.max_width > 0 && letterbox.max_height > 0).then_some(letterbox)
+    }
+
+    pub fn name(&self) -> String {
+        format!("{}x{}", self.max_width, self.max_height)
+    }
+
+    // Playfield size and its top-left screen cell for a terminal of the given size.
+    // An axis is only capped when there is room for the border on both sides of it.
+    pub fn fit(&self, screen_width: u16, screen_height: u16) -> ((u16, u16), (u16, u16)) {
+        let axis = |screen: u16, max: u16| {
+            if screen >= max.saturating_add(2) { (max, (screen - max) / 2) } else { (screen, 0) }
+        };
+        let (width, x) = axis(screen_width, self.max_width);
+        let (height, y) = axis(screen_height, self.max_height);
+        ((width, height), (x, y))
+    }
+}
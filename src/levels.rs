@@ -0,0 +1,169 @@
+use std::time::Duration;
+use rand::Rng;
+use log::info;
+
+use crate::clock::{GameClock, Timer};
+use crate::constants::*;
+use crate::difficulty::Difficulty;
+use crate::entities::{Asteroid, AsteroidSize};
+use crate::entity_id::EntityAllocator;
+use crate::formations;
+use crate::spatial::SpatialHash;
+use crate::spawn_bias;
+use crate::types::Vector2D;
+
+// What the director does next, for the HUD's pressure line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NextStep {
+    WaveStarts(u64), // Ticks left of the "WAVE N" interstitial, before anything spawns
+    Spawn(u64),      // Ticks until the next formation arrives
+    FieldClear,      // The whole quota is out: the next, harder wave starts once the field is empty
+}
+
+// --- Pressure: how much of the wave is on the field and what comes next, read off the director's state ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pressure {
+    pub load: usize, // Asteroids on the field, weighted like `progress_percent` (large 4, medium 2, small 1)
+    pub cap: usize,  // The wave's whole quota on the field at once, however it has broken up
+    pub next_step: NextStep,
+}
+
+// --- LevelManager: a fixed quota of asteroids per wave; clearing the field starts the next wave ---
+#[derive(Clone)]
+pub struct LevelManager {
+    pub wave: usize,            // 0 until the first wave is announced
+    pub speed_multiplier: f64,  // Asteroid speed for this wave (fragments inherit it)
+    speed_factor: f64,          // Assist-mode and difficulty scaling applied on top of the per-wave speed
+    difficulty: Difficulty,     // Also scales the wave's quota, spawn interval and spawn bias
+    quota: usize,               // Large asteroids in this wave
+    to_spawn: usize,            // Large asteroids still waiting to enter the field
+    spawn_interval: Duration,
+    next_spawn: Timer,
+    intermission: Timer,        // "WAVE N" interstitial: nothing spawns while it runs
+}
+
+impl LevelManager {
+    // `enemy_speed_factor` is the assist-mode scaling; the difficulty's own comes on top.
+    pub fn new(enemy_speed_factor: f64, difficulty: Difficulty) -> Self {
+        let speed_factor = enemy_speed_factor * difficulty.speed_factor();
+        LevelManager {
+            wave: 0,
+            speed_multiplier: INITIAL_GAME_SPEED_MULTIPLIER * speed_factor,
+            speed_factor,
+            difficulty,
+            quota: 0,
+            to_spawn: 0,
+            spawn_interval: INITIAL_ASTEROID_SPAWN_INTERVAL,
+            next_spawn: Timer::default(),
+            intermission: Timer::default(),
+        }
+    }
+
+    fn start_wave(&mut self, wave: usize, clock: &GameClock) {
+        let steps = (wave - 1) as i32;
+        self.wave = wave;
+        let base_quota = WAVE_BASE_ASTEROIDS + (wave - 1) * WAVE_ASTEROID_INCREMENT;
+        self.quota = ((base_quota as f64 * self.difficulty.wave_size_factor()).round() as usize).max(1);
+        self.to_spawn = self.quota;
+        self.speed_multiplier = (INITIAL_GAME_SPEED_MULTIPLIER + GAME_SPEED_MULTIPLIER_INCREASE * steps as f64) * self.speed_factor;
+        self.spawn_interval = INITIAL_ASTEROID_SPAWN_INTERVAL
+            .mul_f64(ASTEROID_SPAWN_RATE_DECREASE_FACTOR.powi(steps) * self.difficulty.spawn_interval_factor())
+            .max(MIN_ASTEROID_SPAWN_INTERVAL);
+        self.intermission = clock.timer(WAVE_INTERSTITIAL);
+        self.next_spawn = Timer::default();
+        info!("Wave {}: {} asteroids, speed {:.2}.", wave, self.quota, self.speed_multiplier);
+    }
+
+    // Spawns this wave's formations and detects the end of the wave (every asteroid and any boss gone).
+    // Returns the number of a newly announced wave.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        clock: &GameClock,
+        asteroids: &mut Vec<Asteroid>,
+        entity_ids: &mut EntityAllocator,
+        rng: &mut impl Rng,
+        ship_position: Vector2D,
+        density: &SpatialHash,
+        boss_active: bool,
+        terminal_width: u16,
+        terminal_height: u16,
+    ) -> Option<usize> {
+        if self.intermission.is_running(clock) {
+            return None;
+        }
+        if self.to_spawn == 0 {
+            if asteroids.is_empty() && !boss_active {
+                self.start_wave(self.wave + 1, clock);
+                return Some(self.wave);
+            }
+            return None;
+        }
+        if !self.next_spawn.is_running(clock) {
+            let formation = formations::choose(self.wave, self.to_spawn, rng);
+            info!("Spawning '{}' formation in wave {}.", formation.name, self.wave);
+            let mut orders = formations::plan(formation, terminal_width, terminal_height, rng);
+            let strength = spawn_bias::strength(self.wave) * self.difficulty.spawn_bias_factor();
+            spawn_bias::rebalance(&mut orders, ship_position, density, strength, terminal_width, terminal_height, rng);
+            for order in orders {
+                let mut asteroid = Asteroid::new(entity_ids.allocate(), order.position.x, order.position.y, rng, AsteroidSize::Large, self.speed_multiplier);
+                if let Some(heading) = order.heading {
+                    asteroid.set_heading(heading);
+                }
+                asteroids.push(asteroid);
+                self.to_spawn = self.to_spawn.saturating_sub(1);
+            }
+            self.next_spawn = clock.timer(self.spawn_interval);
+        }
+        None
+    }
+
+    pub fn spawn_interval(&self) -> Duration {
+        self.spawn_interval
+    }
+
+    pub fn quota(&self) -> usize {
+        self.quota
+    }
+
+    // Waves whose asteroids (and any boss) are all gone: every wave before the one currently announced.
+    pub fn waves_cleared(&self) -> usize {
+        self.wave.saturating_sub(1)
+    }
+
+    // Share of the wave already shot down, counting a large asteroid as the four small ones it ends up as.
+    pub fn progress_percent(&self, asteroids: &[Asteroid]) -> u8 {
+        let total = self.quota * 4;
+        if total == 0 {
+            return 0;
+        }
+        let remaining = self.to_spawn * 4 + asteroids.iter().map(weight).sum::<usize>();
+        (total.saturating_sub(remaining) * 100 / total) as u8
+    }
+
+    pub fn pressure(&self, asteroids: &[Asteroid], clock: &GameClock) -> Pressure {
+        let next_step = if self.intermission.is_running(clock) {
+            NextStep::WaveStarts(self.intermission.remaining(clock))
+        } else if self.to_spawn > 0 {
+            NextStep::Spawn(self.next_spawn.remaining(clock))
+        } else {
+            NextStep::FieldClear
+        };
+        Pressure { load: asteroids.iter().map(weight).sum(), cap: self.quota * 4, next_step }
+    }
+}
+
+// A large asteroid counts as the four small ones it ends up as.
+fn weight(asteroid: &Asteroid) -> usize {
+    match asteroid.size {
+        AsteroidSize::Large => 4,
+        AsteroidSize::Medium => 2,
+        AsteroidSize::Small => 1,
+    }
+}
+
+impl Default for LevelManager {
+    fn default() -> Self {
+        Self::new(1.0, Difficulty::Normal)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::clock::{GameClock, Timer};
+use crate::constants::*;
+use crate::entities::{Asteroid, Ship};
+use crate::spatial::clearance;
+use crate::types::Vector2D;
+
+// --- Lives: running out of health costs a life and queues a respawn at the centre of the screen ---
+#[derive(Clone)]
+pub struct Lives {
+    pub remaining: u32,
+    respawn_delay: Option<Timer>, // Set while the ship is out of play waiting to respawn
+}
+
+impl Lives {
+    pub fn new(count: u32) -> Self {
+        Lives { remaining: count.max(1), respawn_delay: None }
+    }
+
+    // Returns false when that was the last life.
+    pub fn lose_life(&mut self, clock: &GameClock) -> bool {
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.remaining == 0 {
+            return false;
+        }
+        self.respawn_delay = Some(clock.timer(RESPAWN_DELAY));
+        true
+    }
+
+    pub fn is_respawning(&self) -> bool {
+        self.respawn_delay.is_some()
+    }
+
+    // Brings the ship back once the delay has passed and no asteroid is near the centre.
+    pub fn try_respawn(&mut self, clock: &GameClock, ship: &mut Ship, asteroids: &[Asteroid], terminal_width: u16, terminal_height: u16) -> bool {
+        let Some(delay) = self.respawn_delay else {
+            return false;
+        };
+        let center = Vector2D::new(terminal_width as f64 / 2.0, terminal_height as f64 / 2.0);
+        if delay.is_running(clock) || clearance(center, asteroids) < RESPAWN_CLEAR_RADIUS {
+            return false;
+        }
+        self.respawn_delay = None;
+        ship.position = center;
+        ship.velocity = Vector2D::new(0.0, 0.0);
+        ship.angle = -std::f64::consts::FRAC_PI_2;
+        ship.angular_velocity = 0.0;
+        true
+    }
+}
+
+impl Default for Lives {
+    fn default() -> Self {
+        Self::new(STARTING_LIVES)
+    }
+}
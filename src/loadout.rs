@@ -0,0 +1,104 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::balance::UpgradeBalance;
+use crate::config::Config;
+use crate::constants::MAX_LOADOUT_START;
+use crate::entities::Ship;
+use crate::upgrades::UpgradeType;
+
+const LOADOUTS_HEADER: &str = "vibe-asteroid-loadouts 1";
+
+// --- Loadout: a named build picked after Start Game ---
+// The ship launches with the `start` upgrades already fitted, and upgrade drops lean toward the `favoured` kinds.
+// Presets are written by hand in the `loadouts` file next to the config profile:
+//
+//     vibe-asteroid-loadouts 1
+//     loadout gunner
+//     start fire-rate fire-rate crit
+//     favour fire-rate shot-speed crit
+//
+// `start` takes upgrades the ship keeps (not shields or health); `favour` takes any kind.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loadout {
+    pub name: String,
+    pub start: Vec<UpgradeType>,
+    pub favoured: Vec<UpgradeType>,
+}
+
+impl Loadout {
+    // Location of the presets: beside the config profile, as `loadouts`.
+    pub fn path() -> Option<PathBuf> {
+        Some(Config::path()?.with_file_name("loadouts"))
+    }
+
+    // A missing file means no presets.
+    pub fn load_all() -> io::Result<Vec<Loadout>> {
+        let Some(path) = Self::path() else {
+            return Ok(Vec::new());
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut lines = contents.lines();
+        if lines.next() != Some(LOADOUTS_HEADER) {
+            return Err(invalid_loadout("missing loadouts header"));
+        }
+        let mut loadouts: Vec<Loadout> = Vec::new();
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["loadout", name] => loadouts.push(Loadout { name: name.to_string(), start: Vec::new(), favoured: Vec::new() }),
+                ["start", keys @ ..] => {
+                    let loadout = loadouts.last_mut().ok_or_else(|| invalid_loadout("start before any loadout"))?;
+                    loadout.start = parse_upgrades(keys).ok_or_else(|| invalid_loadout("unknown upgrade"))?;
+                    if loadout.start.iter().any(|upgrade_type| !upgrade_type.is_kept()) {
+                        return Err(invalid_loadout("shields, health and power-ups can't be fitted at the start"));
+                    }
+                    if loadout.start.len() > MAX_LOADOUT_START {
+                        return Err(invalid_loadout("too many starting upgrades"));
+                    }
+                }
+                ["favour", keys @ ..] => {
+                    let loadout = loadouts.last_mut().ok_or_else(|| invalid_loadout("favour before any loadout"))?;
+                    loadout.favoured = parse_upgrades(keys).ok_or_else(|| invalid_loadout("unknown upgrade"))?;
+                }
+                _ => return Err(invalid_loadout("unrecognised loadout line")),
+            }
+        }
+        Ok(loadouts)
+    }
+
+    // Fits the starting upgrades as if they had been picked up, so they show in the HUD and can be jettisoned.
+    pub fn equip(&self, ship: &mut Ship, balance: &UpgradeBalance) {
+        for &upgrade_type in &self.start {
+            ship.upgrades.push(upgrade_type);
+            balance.apply(ship, upgrade_type);
+        }
+    }
+
+    // What the loadout picker shows under the list.
+    pub fn lines(&self) -> Vec<String> {
+        let names = |upgrades: &[UpgradeType]| {
+            if upgrades.is_empty() { "nothing".to_string() } else { upgrades.iter().map(|upgrade_type| upgrade_type.name()).collect::<Vec<_>>().join(", ") }
+        };
+        vec![format!("Starts with: {}", names(&self.start)), format!("Favours: {}", names(&self.favoured))]
+    }
+}
+
+// Upgrade keys as written in presets and replays, e.g. `fire-rate crit`. None if any is unknown.
+pub fn parse_upgrades(keys: &[&str]) -> Option<Vec<UpgradeType>> {
+    keys.iter().map(|key| UpgradeType::parse(key)).collect()
+}
+
+pub fn upgrade_keys(upgrades: &[UpgradeType]) -> String {
+    upgrades.iter().map(|upgrade_type| upgrade_type.key()).collect::<Vec<_>>().join(" ")
+}
+
+fn invalid_loadout(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
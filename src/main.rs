@@ -1,25 +1,114 @@
 use std::io::{self, Write};
-use std::collections::HashMap;
-use crossterm::{
-    terminal::{enable_raw_mode, disable_raw_mode, size},
-    cursor::{Hide, Show},
-    event::{Event, KeyCode},
-};
+use crossterm::terminal::size;
 use log::{info, error};
-use simple_logging;
 use std::env;
 
 pub mod constants;
 pub mod types;
 pub mod rendering;
+pub mod camera;
+pub mod letterbox;
+pub mod entity_id;
 pub mod entities;
+pub mod status_effects;
+pub mod active_effects;
 pub mod upgrades;
+pub mod balance;
+pub mod weapons;
+pub mod loadout;
+pub mod scoring;
+pub mod juggling;
+pub mod bounty;
+pub mod shockwave;
 pub mod terminal_io;
+pub mod input;
+pub mod analog;
+pub mod pilot;
+pub mod autopilot;
+pub mod gamepad;
+pub mod terminal_guard;
+pub mod cli;
+pub mod spatial;
+pub mod collisions;
+#[cfg(feature = "overlays")]
+pub mod overlays;
+pub mod hud;
+#[cfg(feature = "overlays")]
+pub mod director_stats;
+pub mod backdrop;
+pub mod effects;
+pub mod events;
+pub mod observer;
+pub mod profiler;
+#[cfg(feature = "gym")]
+pub mod gym;
+pub mod event_log;
+pub mod time_scale;
+pub mod clock;
+pub mod frame_pacer;
+pub mod feedback;
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod idle_decay;
+pub mod terminal_status;
+#[cfg(feature = "score-stream")]
+pub mod score_stream;
+pub mod input_macros;
+pub mod assist;
+pub mod tournament;
+pub mod difficulty;
+pub mod config;
+pub mod onboarding;
+pub mod replay;
+pub mod quick_save;
+pub mod preview;
+pub mod formations;
+pub mod field;
+pub mod levels;
+pub mod spawn_bias;
+pub mod energy;
+pub mod hyperspace;
+pub mod lives;
+pub mod player;
+pub mod versus;
+pub mod fleet;
+pub mod practice;
+pub mod death_map;
+pub mod encounters;
+pub mod boss;
+pub mod world;
+pub mod run_stats;
+pub mod game_state;
+pub mod mode_rules;
+pub mod pause_menu;
+pub mod high_scores;
+pub mod title_menu;
+pub mod settings_menu;
+pub mod cutscene;
+pub mod credits;
 pub mod game;
 
 use crate::rendering::{OutputTarget, ScreenBuffer};
 use crate::terminal_io::SimulatedInput;
+use crate::terminal_guard::TerminalGuard;
 use crate::game::Game;
+use crate::cli::Options;
+use crate::autopilot::Autopilot;
+use crate::effects::EffectsTheme;
+use crate::replay::ReplayRecorder;
+use crate::quick_save::QuickSave;
+use crate::loadout::Loadout;
+use crate::idle_decay::IdleDecay;
+use crate::backdrop::Backdrop;
+#[cfg(feature = "score-stream")]
+use crate::score_stream::ScoreStream;
+use crate::terminal_status::TerminalStatus;
+use crate::config::Config;
+use crate::practice::Practice;
+use crate::mode_rules::Goal;
+use crate::death_map::DeathMap;
+use crate::high_scores::HighScores;
+use crate::constants::{BENCH_SEED, BOT_MINUTES, FIXED_WORLD_HEIGHT, FIXED_WORLD_WIDTH, IDLE_DECAY_GRACE, IDLE_DECAY_INTERVAL, IDLE_DECAY_POINTS, PREVIEW_MINUTES};
 
 fn main() -> io::Result<()> {
     simple_logging::log_to_file("vibe-asteroid.log", log::LevelFilter::Info).unwrap();
@@ -27,36 +116,76 @@ fn main() -> io::Result<()> {
 
     let mut stdout_target;
     let simulated_input: Option<SimulatedInput>;
+    let mut terminal_guard: Option<TerminalGuard> = None; // Dropped last, or on any early return
 
     let args: Vec<String> = env::args().collect();
+
+    if args.len() > 2 && args[1] == "verify" {
+        info!("Verifying replay {}.", args[2]);
+        let passed = replay::verify(&args[2])?;
+        terminal_guard::exit(if passed { 0 } else { 1 });
+    }
+
+    let options = Options::parse(&args)?;
+
+    if args.iter().any(|arg| arg == "--bot") {
+        let minutes = cli::minutes(&args, BOT_MINUTES);
+        let seed = options.seed_or(rand::random);
+        info!("Bot run on seed {} for up to {} minute(s).", seed, minutes);
+        let summary = pilot::run_bot(Box::new(Autopilot::new()), seed, minutes)?;
+        println!("Seed {}: score {} after {} frames (hash {:016x})", seed, summary.score, summary.frames, summary.state_hash);
+        return Ok(());
+    }
+
+    if let Some(frames) = cli::bench_frames(&args) {
+        let seed = options.seed_or(|| BENCH_SEED);
+        info!("Benchmarking {} frames on seed {}.", frames, seed);
+        let report = profiler::run_bench(seed, frames, options.profiler.unwrap_or_default())?;
+        for line in report.lines() {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    if args.len() > 1 && args[1] == "preview" {
+        let minutes = cli::minutes(&args, PREVIEW_MINUTES);
+        let seed = options.seed_or(rand::random);
+        info!("Previewing seed {} for {} minute(s).", seed, minutes);
+        return preview::run(seed, minutes);
+    }
+
     let debug_mode_active = args.len() > 1 && args[1] == "--debug";
 
+    // The saved profile, or None when onboarding has to make one first (on first launch or with --setup).
+    // Debug runs keep the defaults.
+    let saved_config = if debug_mode_active {
+        Some(Config::default())
+    } else {
+        match Config::load() {
+            Ok(Some(config)) if !args.iter().any(|arg| arg == "--setup") => Some(config),
+            Ok(_) => None,
+            Err(e) => {
+                error!("Ignoring unreadable config profile: {}", e);
+                Some(Config::default())
+            }
+        }
+    };
+    if options.hires && saved_config.as_ref().is_some_and(|config| !config.unicode) {
+        eprintln!("--hires draws with Braille characters, but your config profile has Unicode glyphs turned off.");
+        terminal_guard::exit(1);
+    }
+
     let terminal_width: u16;
     let terminal_height: u16;
 
     if debug_mode_active {
         info!("Debug mode enabled.");
-        let mut debug_width = 80;
-        let mut debug_height = 24;
-        if args.len() >= 4 {
-            debug_width = args[2].parse::<u16>().unwrap_or(80);
-            debug_height = args[3].parse::<u16>().unwrap_or(24);
-        }
-        terminal_width = debug_width;
-        terminal_height = debug_height;
+        (terminal_width, terminal_height) = cli::debug_screen(&args);
         info!("Debug resolution set to {}x{}", terminal_width, terminal_height);
         stdout_target = OutputTarget::ScreenBuffer(ScreenBuffer::new(terminal_width, terminal_height));
-        let mut sim_events = HashMap::new();
-        sim_events.insert(1, Event::Key(KeyCode::Up.into()));
-        sim_events.insert(2, Event::Key(KeyCode::Right.into()));
-        sim_events.insert(3, Event::Key(KeyCode::Char(' ').into()));
-        sim_events.insert(4, Event::Key(KeyCode::Left.into()));
-        sim_events.insert(10, Event::Key(KeyCode::Char('q').into())); // Quit after 10 frames
-        simulated_input = Some(SimulatedInput::new(sim_events));
+        simulated_input = Some(SimulatedInput::new(cli::debug_events(&args)));
     } else {
-        info!("Attempting to enable raw mode.");
-        enable_raw_mode().map_err(|e| { error!("Failed to enable raw mode: {}", e); e })?;
-        info!("Raw mode enabled.");
+        terminal_guard = Some(TerminalGuard::acquire()?);
         let (width, height) = size().map_err(|e| { error!("Failed to get terminal size: {}", e); e })?;
         terminal_width = width;
         terminal_height = height;
@@ -65,26 +194,29 @@ fn main() -> io::Result<()> {
         simulated_input = None; // No simulated input in non-debug mode
     }
 
-    let max_frames: Option<u64> = if !debug_mode_active && args.len() > 1 {
-        match args[1].parse::<u64>() {
-            Ok(num) => Some(num),
-            Err(_) => None,
-        }
-    } else if debug_mode_active && args.len() > 4 {
-        match args[4].parse::<u64>() {
-            Ok(num) => Some(num),
-            Err(_) => None,
-        }
-    } else {
-        None
+    let config = match saved_config {
+        Some(config) => config,
+        None => onboarding::run(&mut stdout_target)?,
     };
-
-    info!("Attempting to clear screen and hide cursor.");
-    if !debug_mode_active {
-        stdout_target.execute_other_command(Hide)?;
+    info!("Config: {:?}", config);
+    if options.hires && !config.unicode {
+        // Only the wizard can have just turned Unicode off; put the terminal back before saying so
+        drop(terminal_guard.take());
+        eprintln!("--hires draws with Braille characters, but Unicode glyphs were turned off during setup.");
+        terminal_guard::exit(1);
+    }
+    if config.key_release
+        && let Some(terminal_guard) = &terminal_guard
+    {
+        terminal_guard.enable_key_releases(options.coop || options.versus.is_some())?;
     }
+
+    let max_frames = cli::max_frames(&args, debug_mode_active);
+
     stdout_target.flush()?;
-    info!("Screen cleared and cursor hidden.");
+
+    let seed: u64 = options.seed_or(rand::random);
+    info!("Game seed: {}", seed);
 
     let mut game = Game::new(
         terminal_width,
@@ -93,16 +225,161 @@ fn main() -> io::Result<()> {
         simulated_input,
         debug_mode_active,
         max_frames,
+        seed,
     );
 
-    game.run()?;
+    game.config = config;
+    #[cfg(all(feature = "gamepad", target_os = "linux"))]
+    if game.config.gamepad && !debug_mode_active {
+        match gamepad::Joystick::open(constants::GAMEPAD_DEVICE) {
+            Ok(joystick) => game.gamepad = Some(Box::new(joystick)),
+            Err(e) => error!("Gamepad not available: {}", e),
+        }
+    }
+    if let Some(letterbox) = options.letterbox {
+        game.config.letterbox = letterbox;
+    }
+    game.resize(terminal_width, terminal_height);
+    if let Some(letterbox) = game.config.letterbox {
+        info!("Letterbox {}: playfield is {}x{}.", letterbox.name(), game.terminal_width, game.terminal_height);
+    }
+    game.seed_locked = options.seed.is_some() || options.ruleset.is_some();
+    game.assist = options.assist;
+    if let Some(difficulty) = options.difficulty {
+        game.config.difficulty = difficulty;
+        info!("Difficulty: {}", difficulty.name());
+    }
+    if let Some(scoring_mode) = options.scoring {
+        game.scoring_mode = scoring_mode;
+        info!("Scoring rules: {}", scoring_mode.name());
+    }
+    if let Some(scale) = options.world_scale {
+        game.world_scale = scale;
+        info!("World is {}x{} screens.", scale, scale);
+    }
+    if args.iter().any(|arg| arg == "--fixed-world") {
+        game.fixed_world = true;
+        info!("Fixed {}x{} world.", FIXED_WORLD_WIDTH, FIXED_WORLD_HEIGHT);
+    }
+    if let Some(fps) = options.fps {
+        game.fps = fps;
+        info!("Pacing frames at {} fps.", fps);
+    }
+    if let Some(lives) = options.lives {
+        game.starting_lives = lives;
+    }
+    if game.assist.is_active() {
+        info!("Assist mode: {}", game.assist.summary());
+    }
 
-    info!("Game loop ended. Displaying game over screen.");
+    if options.practice {
+        game.practice = Some(Practice::new());
+    }
+    if options.coop {
+        game.coop = true;
+        info!("Two-player co-op.");
+    }
+    if let Some(kills) = options.versus {
+        game.versus = Some(kills);
+        info!("Two-player versus, first to {} kills.", kills);
+    }
+    if let Some(ships) = options.fleet {
+        game.fleet = Some(ships);
+        info!("Fleet of {} ships.", ships);
+    }
+    if options.goal != Goal::Endless {
+        game.goal = options.goal;
+        info!("Goal: {:?}.", options.goal);
+    }
+    game.practice_field = options.practice_field;
 
+    // Debug runs are scripted, so their deaths would only skew the map.
     if !debug_mode_active {
-        game.stdout_target.execute_other_command(Show)?;
-        disable_raw_mode()?;
+        game.death_map = match DeathMap::load() {
+            Ok(death_map) => Some(death_map),
+            Err(e) => {
+                error!("Not recording deaths, the death log is unreadable: {}", e);
+                None
+            }
+        };
+        game.loadouts = match Loadout::load_all() {
+            Ok(loadouts) => loadouts,
+            Err(e) => {
+                error!("Ignoring loadout presets, the loadouts file is unreadable: {}", e);
+                Vec::new()
+            }
+        };
+        game.effects = match EffectsTheme::load() {
+            Ok(effects) => effects,
+            Err(e) => {
+                error!("Using the classic effects, the effects file is unreadable: {}", e);
+                EffectsTheme::classic()
+            }
+        };
+        game.high_scores = match HighScores::load() {
+            Ok(high_scores) => Some(high_scores),
+            Err(e) => {
+                error!("Not recording high scores, the table is unreadable: {}", e);
+                None
+            }
+        };
+    }
+
+    if args.iter().any(|arg| arg == "--flash") {
+        game.config.hit_flash = true;
+    }
+
+    if args.iter().any(|arg| arg == "--idle-decay") {
+        game.idle_decay = Some(IdleDecay::new(IDLE_DECAY_GRACE, IDLE_DECAY_INTERVAL, IDLE_DECAY_POINTS));
+    }
+
+    game.hires = options.hires;
+
+    if args.iter().any(|arg| arg == "--static-backdrop") {
+        game.backdrop = Backdrop::new(true);
+    }
+
+    if args.iter().any(|arg| arg == "--title-status") {
+        game.terminal_status = Some(TerminalStatus::new());
     }
 
+    if let Some(ruleset) = options.ruleset {
+        game.ruleset = Some(ruleset);
+        if ruleset.idle_decay {
+            game.idle_decay = Some(IdleDecay::new(IDLE_DECAY_GRACE, IDLE_DECAY_INTERVAL, IDLE_DECAY_POINTS));
+        }
+    }
+
+    #[cfg(feature = "score-stream")]
+    if let Some(path) = &options.stream {
+        info!("Streaming game state to {}.", path);
+        game.score_stream = Some(ScoreStream::open(path)?);
+    }
+
+    if let Some(path) = options.record {
+        info!("Recording replay to {}.", path);
+        game.replay_recorder = Some(ReplayRecorder::new(Some(path), seed, game.terminal_width, game.terminal_height, game.assist, game.ruleset, game.run_settings()));
+    }
+
+    // Quick saves are the run's replay so far, so live runs record one whether or not --record was given.
+    // Practice mode has F5 and F9 for its checkpoint, and a tournament run can't be saved and retried.
+    if !debug_mode_active && !options.practice && game.ruleset.is_none() {
+        game.quick_save = Some(QuickSave::new());
+        if game.replay_recorder.is_none() {
+            game.replay_recorder = Some(ReplayRecorder::new(None, seed, game.terminal_width, game.terminal_height, game.assist, game.ruleset, game.run_settings()));
+        }
+    }
+
+    if options.profiler.is_some() {
+        info!("Logging frame timings.");
+        game.profiler = options.profiler;
+    }
+
+    game.run()?;
+
+    info!("Game loop ended. Displaying game over screen.");
+
+    drop(terminal_guard);
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+use crate::player::{self, Player};
+
+// What ends a run in victory. Running out of lives or quitting ends any run; this is the other way out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Goal {
+    Endless,           // Never won: plays on until the players are out of lives or quit
+    TargetScore(u32),  // Classic with --target-score: reaching the score wins
+    ClearWaves(usize), // Objective: clearing this many waves wins
+    Kills(u32),        // Versus: the first player to this many kills wins
+}
+
+// --- ModeRules: the win condition the main loop checks after every tick ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ModeRules {
+    pub goal: Goal,
+}
+
+impl ModeRules {
+    pub fn new(goal: Goal) -> Self {
+        ModeRules { goal }
+    }
+
+    // Whether the goal has been reached. `waves_cleared` counts waves whose asteroids are all gone.
+    pub fn is_won(&self, players: &[Player], waves_cleared: usize) -> bool {
+        match self.goal {
+            Goal::Endless => false,
+            Goal::TargetScore(target) => player::team_score(players) >= target,
+            Goal::ClearWaves(waves) => waves_cleared >= waves,
+            Goal::Kills(kills) => players.iter().any(|player| player.kills >= kills),
+        }
+    }
+
+    // A status line tracking the goal; versus shows kills per player instead, and endless has nothing to track.
+    pub fn progress_line(&self, players: &[Player], waves_cleared: usize) -> Option<String> {
+        match self.goal {
+            Goal::TargetScore(target) => Some(format!("Target {}/{}", player::team_score(players).min(target), target)),
+            Goal::ClearWaves(waves) => Some(format!("Objective: clear {} waves ({}/{})", waves, waves_cleared.min(waves), waves)),
+            Goal::Endless | Goal::Kills(_) => None,
+        }
+    }
+}
+
+impl Default for ModeRules {
+    fn default() -> Self {
+        Self::new(Goal::Endless)
+    }
+}
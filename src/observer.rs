@@ -0,0 +1,35 @@
+use crate::entities::{Asteroid, Ship};
+use crate::events::GameEvent;
+use crate::rendering::GameGrid;
+
+// What a frame ended with: the headline numbers, plus the ship and asteroids for observers that look closer.
+// In co-op the score is the team's and the health and ship are player one's.
+#[derive(Clone, Copy)]
+pub struct FrameInfo<'a> {
+    pub frame: u64,
+    pub tick: u64,
+    pub score: u32,
+    pub health: u32,
+    pub wave: usize,
+    pub game_over: bool, // This was the run's last frame
+    pub ship: &'a Ship,
+    pub asteroids: &'a [Asteroid],
+}
+
+// --- Observer: hooks into the game loop for embedding hosts (bots, trainers, research tools) ---
+// Register with `Game::add_observer`. Every method defaults to doing nothing, so implement only what you need.
+pub trait Observer {
+    // After each frame's simulation and rendering.
+    fn on_frame(&mut self, _info: &FrameInfo) {}
+
+    // Every game event, in the order the simulation raised it, with the tick it was handled on.
+    fn on_event(&mut self, _tick: u64, _event: &GameEvent) {}
+
+    // The composed playfield just before it is written out; not called for headless runs, which draw no HUD.
+    fn on_render(&mut self, _grid: &GameGrid) {}
+
+    // Checked after `on_frame`; returning false ends the run there, as if the player had quit.
+    fn keep_running(&mut self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,83 @@
+use std::io::{self, Write};
+use std::path::Path;
+use crossterm::{
+    cursor::MoveTo,
+    event::{self, Event, KeyCode, KeyEventKind},
+    style::{Color, ResetColor, SetForegroundColor},
+    terminal::{supports_keyboard_enhancement, Clear, ClearType},
+};
+
+use crate::config::Config;
+use crate::constants::GAMEPAD_DEVICE;
+use crate::rendering::OutputTarget;
+
+// --- Onboarding: a first-launch wizard that checks what the terminal can display ---
+// Colour and glyph support can only be judged by the player; key releases and gamepads are probed.
+pub fn run(stdout: &mut OutputTarget) -> io::Result<Config> {
+    let mut config = Config::default();
+
+    stdout.execute_other_command(Clear(ClearType::All))?;
+    write_line(stdout, 1, "Welcome to Vibe-asteroid! Let's check your terminal (y = yes, n = no).")?;
+
+    write_line(stdout, 3, "1. Colour test:")?;
+    stdout.execute_move_to(MoveTo(4, 4))?;
+    for (color, name) in [(Color::Cyan, "ship"), (Color::Grey, "asteroid"), (Color::Yellow, "bullet"), (Color::Green, "upgrade")] {
+        stdout.execute_other_command(SetForegroundColor(color))?;
+        write!(stdout, "{}  ", name)?;
+    }
+    stdout.execute_other_command(ResetColor)?;
+    config.color = ask(stdout, 5, "   Are these four words in different colours?")?;
+
+    write_line(stdout, 7, "2. Glyph test:  ● ░▒▓ ┌─┐ · 𝟏𝟐𝟑")?;
+    config.unicode = ask(stdout, 8, "   Do you see a dot, three shades, a box corner, a small dot and bold digits?")?;
+
+    config.key_release = supports_keyboard_enhancement().unwrap_or(false);
+    write_line(stdout, 10, &format!("3. Key release reporting: {}", detected(config.key_release)))?;
+
+    config.gamepad = Path::new(GAMEPAD_DEVICE).exists();
+    write_line(stdout, 11, &format!("4. Gamepad: {}", detected(config.gamepad)))?;
+
+    match config.save() {
+        Ok(path) => write_line(stdout, 13, &format!("Saved to {}. Run with --setup to repeat this.", path.display()))?,
+        Err(e) => write_line(stdout, 13, &format!("Could not save the profile ({}); using these settings for now.", e))?,
+    }
+    write_line(stdout, 14, "Press any key to continue...")?;
+    wait_for_key()?;
+    stdout.execute_other_command(Clear(ClearType::All))?;
+    Ok(config)
+}
+
+fn write_line(stdout: &mut OutputTarget, y: u16, text: &str) -> io::Result<()> {
+    stdout.execute_move_to(MoveTo(2, y))?;
+    write!(stdout, "{}", text)?;
+    stdout.flush()
+}
+
+fn ask(stdout: &mut OutputTarget, y: u16, question: &str) -> io::Result<bool> {
+    write_line(stdout, y, &format!("{} [y/n]", question))?;
+    loop {
+        if let Event::Key(key_event) = event::read()?
+            && key_event.kind == KeyEventKind::Press
+        {
+            match key_event.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => return Ok(true),
+                KeyCode::Char('n') | KeyCode::Char('N') => return Ok(false),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn wait_for_key() -> io::Result<()> {
+    loop {
+        if let Event::Key(key_event) = event::read()?
+            && key_event.kind == KeyEventKind::Press
+        {
+            return Ok(());
+        }
+    }
+}
+
+fn detected(present: bool) -> &'static str {
+    if present { "detected" } else { "not detected" }
+}
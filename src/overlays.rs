@@ -0,0 +1,88 @@
+use crate::constants::{DEATH_MAP_COLOR, SPATIAL_HASH_CELL_HEIGHT, SPATIAL_HASH_CELL_WIDTH};
+use crate::death_map::DeathMap;
+use crate::director_stats::DirectorStats;
+use crate::rendering::GameGrid;
+use crossterm::style::Color;
+use crate::spatial::SpatialHash;
+
+// --- Debug overlays drawn on top of the GameGrid ---
+
+// Shades the empty cells of every spatial hash bucket by how many asteroids it holds.
+pub fn draw_density_heatmap(game_grid: &mut GameGrid, spatial_hash: &SpatialHash) {
+    for row in 0..spatial_hash.rows() {
+        for column in 0..spatial_hash.columns() {
+            let shade = match spatial_hash.count(column, row) {
+                0 => continue,
+                1 => '░',
+                2 => '▒',
+                _ => '▓',
+            };
+            let start_x = column as u16 * spatial_hash.cell_width();
+            let start_y = row as u16 * spatial_hash.cell_height();
+            for y in start_y..start_y.saturating_add(spatial_hash.cell_height()) {
+                for x in start_x..start_x.saturating_add(spatial_hash.cell_width()) {
+                    if game_grid.get_char(x, y) == Some(' ') {
+                        game_grid.set_char(x, y, shade);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Shades empty cells by how often the player has died there, relative to the deadliest bucket.
+pub fn draw_death_map(game_grid: &mut GameGrid, death_map: &DeathMap) {
+    let columns = (game_grid.width as usize).div_ceil(SPATIAL_HASH_CELL_WIDTH as usize).max(1);
+    let rows = (game_grid.height as usize).div_ceil(SPATIAL_HASH_CELL_HEIGHT as usize).max(1);
+    let counts = death_map.counts(columns, rows);
+    let max_count = counts.iter().copied().max().unwrap_or(0);
+    if max_count == 0 {
+        return;
+    }
+    for row in 0..rows {
+        for column in 0..columns {
+            let count = counts[row * columns + column];
+            let shade = match count * 3 / max_count {
+                _ if count == 0 => continue,
+                0 => '░',
+                1 => '▒',
+                _ => '▓',
+            };
+            let start_x = column as u16 * SPATIAL_HASH_CELL_WIDTH;
+            let start_y = row as u16 * SPATIAL_HASH_CELL_HEIGHT;
+            for y in start_y..start_y.saturating_add(SPATIAL_HASH_CELL_HEIGHT) {
+                for x in start_x..start_x.saturating_add(SPATIAL_HASH_CELL_WIDTH) {
+                    if game_grid.get_char(x, y) == Some(' ') {
+                        game_grid.set_cell(x, y, shade, DEATH_MAP_COLOR);
+                    }
+                }
+            }
+        }
+    }
+}
+
+const BAR_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// Mini bar charts of the wave director's recent behaviour, one row per metric, below the status line.
+pub fn draw_difficulty_chart(game_grid: &mut GameGrid, stats: &DirectorStats) {
+    let Some(latest) = stats.latest() else {
+        return;
+    };
+    let rows = [
+        ("spawn", format!("{:.1}s", latest.spawn_interval.as_secs_f64()), stats.series(|sample| sample.spawn_interval.as_secs_f64())),
+        ("cap", latest.asteroid_cap.to_string(), stats.series(|sample| sample.asteroid_cap as f64)),
+        ("speed", format!("x{:.2}", latest.speed_multiplier), stats.series(|sample| sample.speed_multiplier)),
+        ("rocks", latest.asteroid_count.to_string(), stats.series(|sample| sample.asteroid_count as f64)),
+    ];
+    for (i, (label, value, series)) in rows.iter().enumerate() {
+        let y = 1 + i as u16;
+        let text = format!("{:<6}{:>6} ", label, value);
+        game_grid.write_str(0, y, &text, Color::DarkCyan);
+        // Each row is scaled to its own peak so slow trends stay visible
+        let peak = series.iter().copied().fold(0.0, f64::max);
+        for (x, value) in series.iter().enumerate() {
+            let level = if peak > 0.0 { (value / peak * (BAR_GLYPHS.len() - 1) as f64).round() as usize } else { 0 };
+            game_grid.set_cell(text.len() as u16 + x as u16, y, BAR_GLYPHS[level], Color::DarkCyan);
+        }
+    }
+}
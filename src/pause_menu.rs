@@ -0,0 +1,47 @@
+use crossterm::{event::KeyCode, style::Color};
+
+use crate::game_state::GameState;
+use crate::rendering::GameGrid;
+
+pub const PAUSE_MENU_ITEMS: [&str; 4] = ["Resume", "Settings", "Restart", "Quit"];
+
+// Applies a key press to the pause menu and returns the resulting state.
+pub fn handle_key(code: KeyCode, selected: usize) -> GameState {
+    let item_count = PAUSE_MENU_ITEMS.len();
+    match code {
+        KeyCode::Up => GameState::Paused { selected: (selected + item_count - 1) % item_count },
+        KeyCode::Down => GameState::Paused { selected: (selected + 1) % item_count },
+        KeyCode::Char('p') | KeyCode::Esc => GameState::Playing,
+        KeyCode::Char('q') => GameState::Quit,
+        KeyCode::Enter => match selected {
+            0 => GameState::Playing,
+            1 => GameState::Settings { selected: 0 },
+            2 => GameState::Restarting,
+            _ => GameState::Quit,
+        },
+        _ => GameState::Paused { selected },
+    }
+}
+
+pub fn draw(game_grid: &mut GameGrid, selected: usize) {
+    let box_width: u16 = 20;
+    let box_height = PAUSE_MENU_ITEMS.len() as u16 + 4;
+    let start_x = (game_grid.width / 2).saturating_sub(box_width / 2);
+    let start_y = (game_grid.height / 2).saturating_sub(box_height / 2);
+    let inner_width = box_width as usize - 2;
+
+    let mut lines = vec![
+        format!("┌{}┐", "─".repeat(inner_width)),
+        format!("│{:^width$}│", "PAUSED", width = inner_width),
+        format!("├{}┤", "─".repeat(inner_width)),
+    ];
+    for (i, item) in PAUSE_MENU_ITEMS.iter().enumerate() {
+        let label = if i == selected { format!("> {} <", item) } else { item.to_string() };
+        lines.push(format!("│{:^width$}│", label, width = inner_width));
+    }
+    lines.push(format!("└{}┘", "─".repeat(inner_width)));
+
+    for (i, line) in lines.iter().enumerate() {
+        game_grid.write_str(start_x, start_y + i as u16, line, Color::Reset);
+    }
+}
@@ -0,0 +1,53 @@
+use std::io;
+use std::time::Duration;
+
+use crate::clock::ticks_for;
+use crate::constants::{BOT_HEIGHT, BOT_WIDTH};
+use crate::entities::{Asteroid, Ship};
+use crate::game::{Game, RunSummary};
+use crate::input::InputState;
+use crate::rendering::{OutputTarget, ScreenBuffer};
+use crate::types::Vector2D;
+
+// A frame of ship controls: exactly what the keyboard or a gamepad produces, so pilots can't do anything a
+// player couldn't.
+pub type InputAction = InputState;
+
+// What a pilot gets to look at before each frame. In co-op the ship and health are player one's.
+#[derive(Clone, Copy)]
+pub struct WorldView<'a> {
+    pub tick: u64,
+    pub score: u32,
+    pub health: u32,
+    pub wave: usize,
+    pub ship: &'a Ship,
+    pub asteroids: &'a [Asteroid],
+    pub saucer: Option<Vector2D>, // Where the saucer is, while one is on the field
+    pub world_width: u16,
+    pub world_height: u16,
+}
+
+// --- Pilot: flies player one's ship in place of the keyboard ---
+// Set `Game::pilot` to hand a run over to one; `--bot` runs the built-in autopilot headless and reports its score.
+// A key press during a piloted run ends it, which is how the title screen's demo hands back control.
+pub trait Pilot {
+    fn decide(&mut self, view: &WorldView) -> InputAction;
+}
+
+// Plays one headless run from `seed` with `pilot` at the controls, as fast as the simulation goes, stopping
+// after `minutes` of game time if the pilot is still alive.
+pub fn run_bot(pilot: Box<dyn Pilot>, seed: u64, minutes: u64) -> io::Result<RunSummary> {
+    let frames = ticks_for(Duration::from_secs(minutes * 60));
+    let mut game = Game::new(
+        BOT_WIDTH,
+        BOT_HEIGHT,
+        OutputTarget::ScreenBuffer(ScreenBuffer::new(BOT_WIDTH, BOT_HEIGHT)),
+        None,
+        true,
+        Some(frames),
+        seed,
+    );
+    game.headless = true;
+    game.pilot = Some(pilot);
+    game.run()
+}
@@ -0,0 +1,60 @@
+use crossterm::style::Color;
+
+use crate::clock::Timer;
+use crate::constants::*;
+use crate::entities::Ship;
+use crate::hyperspace::Hyperspace;
+use crate::lives::Lives;
+
+// --- Player: a ship and everything that belongs to whoever is flying it ---
+// Solo runs have one. Co-op and versus add a second in the same field, with its own health, score, lives and colours.
+#[derive(Clone)]
+pub struct Player {
+    pub ship: Ship,
+    pub health: u32,
+    pub invincibility: Timer, // Spawn protection and the grace period after a hit
+    pub shot_cooldown: Timer,
+    pub volleys: u32, // Times the guns have fired, for weapons that go off every few volleys
+    pub score: u32,
+    pub kills: u32, // Versus: times this player shot the other one down
+    pub deaths: u32,
+    pub lives: Lives,
+    pub hyperspace: Hyperspace,
+    pub bullet_color: Color,
+    pub upgrade_cursor: Option<usize>, // Row picked in the upgrade panel while choosing one to jettison
+}
+
+impl Player {
+    pub fn new(ship: Ship, lives: u32, invincibility: Timer) -> Self {
+        Player {
+            health: ship.max_health,
+            ship,
+            invincibility,
+            shot_cooldown: Timer::default(),
+            volleys: 0,
+            score: 0,
+            kills: 0,
+            deaths: 0,
+            lives: Lives::new(lives),
+            hyperspace: Hyperspace::new(),
+            bullet_color: BULLET_COLOR,
+            upgrade_cursor: None,
+        }
+    }
+
+    // The second player's ship: blue, with its own shot colour.
+    pub fn player_two(mut ship: Ship, lives: u32, invincibility: Timer) -> Self {
+        ship.color = PLAYER_TWO_SHIP_COLOR;
+        Player { bullet_color: PLAYER_TWO_BULLET_COLOR, ..Player::new(ship, lives, invincibility) }
+    }
+
+    // Flying: neither waiting to respawn nor out of lives.
+    pub fn in_play(&self) -> bool {
+        !self.lives.is_respawning() && self.lives.remaining > 0
+    }
+}
+
+// Co-op runs are ranked on what the players scored between them.
+pub fn team_score(players: &[Player]) -> u32 {
+    players.iter().map(|player| player.score).sum()
+}
@@ -0,0 +1,41 @@
+use crate::world::World;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PracticeAction {
+    Save,
+    Restore,
+}
+
+// --- Snapshot: everything a session needs to resume from an exact moment, including the RNG ---
+#[derive(Clone)]
+pub struct Snapshot {
+    pub tick: u64,
+    pub world: World,
+}
+
+// --- Practice mode: one checkpoint slot, saved and restored on request ---
+pub struct Practice {
+    pub checkpoint: Option<Snapshot>,
+    requested: Option<PracticeAction>,
+}
+
+impl Practice {
+    pub fn new() -> Self {
+        Practice { checkpoint: None, requested: None }
+    }
+
+    // Input only records the request; the session loop owns the state being saved or replaced.
+    pub fn request(&mut self, action: PracticeAction) {
+        self.requested = Some(action);
+    }
+
+    pub fn take_request(&mut self) -> Option<PracticeAction> {
+        self.requested.take()
+    }
+}
+
+impl Default for Practice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
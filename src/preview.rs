@@ -0,0 +1,128 @@
+use std::io;
+use std::time::Duration;
+
+use crate::clock::ticks_for;
+use crate::constants::SIMULATION_TICK;
+use crate::entities::Asteroid;
+use crate::events::GameEvent;
+use crate::game::Game;
+use crate::rendering::{OutputTarget, ScreenBuffer};
+use crate::spatial::clearance;
+use crate::types::Vector2D;
+
+const PREVIEW_WIDTH: u16 = 80;
+const PREVIEW_HEIGHT: u16 = 24;
+const PRESSURE_WINDOW: Duration = Duration::from_secs(30); // Width of each row in the pressure table
+const PRESSURE_NEAR_DISTANCE: f64 = 6.0; // Rows from the ship that count as "close"
+
+// --- SeedPreview: timeline collected while a seed plays out with nobody at the controls ---
+pub struct SeedPreview {
+    timeline: Vec<(u64, String)>,
+    windows: Vec<PressureWindow>,
+    saucer_present: bool,
+}
+
+#[derive(Default)]
+struct PressureWindow {
+    samples: u64,
+    asteroid_total: u64,
+    asteroid_max: usize,
+    close_calls: u64, // Ticks with an asteroid within PRESSURE_NEAR_DISTANCE of the ship
+}
+
+impl SeedPreview {
+    pub fn new() -> Self {
+        SeedPreview { timeline: Vec::new(), windows: Vec::new(), saucer_present: false }
+    }
+
+    pub fn record_event(&mut self, tick: u64, event: &GameEvent) {
+        match event {
+            GameEvent::WaveStarted { wave } => self.timeline.push((tick, format!("wave {} announced", wave))),
+            GameEvent::BossArrived { wave } => self.timeline.push((tick, format!("boss arrives in wave {}", wave))),
+            GameEvent::UpgradeBoxSpawned { position } => {
+                self.timeline.push((tick, format!("upgrade box at ({:.0}, {:.0})", position.x, position.y)));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn sample(&mut self, tick: u64, asteroids: &[Asteroid], ship_position: Vector2D, saucer_present: bool) {
+        if saucer_present && !self.saucer_present {
+            self.timeline.push((tick, "saucer arrives".to_string()));
+        }
+        self.saucer_present = saucer_present;
+
+        let window_ticks = ticks_for(PRESSURE_WINDOW);
+        let index = (tick / window_ticks) as usize;
+        if self.windows.len() <= index {
+            self.windows.resize_with(index + 1, PressureWindow::default);
+        }
+        let window = &mut self.windows[index];
+        window.samples += 1;
+        window.asteroid_total += asteroids.len() as u64;
+        window.asteroid_max = window.asteroid_max.max(asteroids.len());
+        if clearance(ship_position, asteroids) < PRESSURE_NEAR_DISTANCE {
+            window.close_calls += 1;
+        }
+    }
+
+    pub fn print(&self, seed: u64, minutes: u64) {
+        println!("Seed {} preview: {} minute(s) on an {}x{} field, no player input", seed, minutes, PREVIEW_WIDTH, PREVIEW_HEIGHT);
+        println!();
+        println!("Timeline:");
+        for (tick, entry) in &self.timeline {
+            println!("  {}  {}", timestamp(*tick), entry);
+        }
+        println!();
+        println!("Asteroid pressure:");
+        println!("  window        avg   max   near ship");
+        let window_ticks = ticks_for(PRESSURE_WINDOW);
+        let end = ticks_for(Duration::from_secs(minutes * 60));
+        for (index, window) in self.windows.iter().enumerate() {
+            let start = index as u64 * window_ticks;
+            if window.samples == 0 || start >= end {
+                continue;
+            }
+            println!(
+                "  {}-{}  {:>5.1} {:>5} {:>10.0}%",
+                timestamp(start),
+                timestamp((start + window_ticks).min(end)),
+                window.asteroid_total as f64 / window.samples as f64,
+                window.asteroid_max,
+                window.close_calls as f64 * 100.0 / window.samples as f64,
+            );
+        }
+    }
+}
+
+impl Default for SeedPreview {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn timestamp(tick: u64) -> String {
+    let seconds = (SIMULATION_TICK * tick as u32).as_secs();
+    format!("{:>2}:{:02}", seconds / 60, seconds % 60)
+}
+
+// Plays the seed headlessly with an untouchable, idle ship and prints what happened when.
+pub fn run(seed: u64, minutes: u64) -> io::Result<()> {
+    let frames = ticks_for(Duration::from_secs(minutes * 60));
+    let mut game = Game::new(
+        PREVIEW_WIDTH,
+        PREVIEW_HEIGHT,
+        OutputTarget::ScreenBuffer(ScreenBuffer::new(PREVIEW_WIDTH, PREVIEW_HEIGHT)),
+        None,
+        true,
+        Some(frames),
+        seed,
+    );
+    game.headless = true;
+    game.preview = Some(SeedPreview::new());
+    game.run()?;
+    if let Some(preview) = &game.preview {
+        preview.print(seed, minutes);
+    }
+    Ok(())
+}
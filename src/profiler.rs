@@ -0,0 +1,275 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::time::{Duration, Instant};
+
+use log::info;
+
+use crate::autopilot::Autopilot;
+use crate::constants::{BENCH_HEIGHT, BENCH_WIDTH, PROFILE_FLAME_INTERVAL};
+use crate::game::Game;
+use crate::rendering::{OutputTarget, ScreenBuffer};
+use crate::world::World;
+
+// The parts of a frame that are timed separately. Ships, collisions, bullets and particles happen inside the
+// simulation, so their time is counted in both.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Phase {
+    Input,
+    Simulation,
+    Ships,      // Movement, respawns, hyperspace and status effects
+    Collisions, // Asteroid movement and ship, boss and saucer hit tests
+    Bullets,    // Shots moving and hitting asteroids, bosses, saucers and boxes
+    Particles,  // Particles, ion clouds and floating texts
+    Draw,       // Composing the playfield and HUD
+    Output,     // Writing the composed frame out
+}
+
+const PHASE_COUNT: usize = Phase::ALL.len();
+
+impl Phase {
+    pub const ALL: [Phase; 8] = [
+        Phase::Input,
+        Phase::Simulation,
+        Phase::Ships,
+        Phase::Collisions,
+        Phase::Bullets,
+        Phase::Particles,
+        Phase::Draw,
+        Phase::Output,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Input => "input",
+            Phase::Simulation => "simulation",
+            Phase::Ships => "ships",
+            Phase::Collisions => "collisions",
+            Phase::Bullets => "bullets",
+            Phase::Particles => "particles",
+            Phase::Draw => "draw",
+            Phase::Output => "output",
+        }
+    }
+
+    // The phase whose time this one's is part of.
+    pub fn parent(self) -> Option<Phase> {
+        match self {
+            Phase::Ships | Phase::Collisions | Phase::Bullets | Phase::Particles => Some(Phase::Simulation),
+            _ => None,
+        }
+    }
+
+    // Where this phase sits in a flame graph, e.g. "frame;simulation;bullets".
+    pub fn stack(self) -> String {
+        match self.parent() {
+            Some(parent) => format!("{};{}", parent.stack(), self.name()),
+            None => format!("frame;{}", self.name()),
+        }
+    }
+
+    fn index(self) -> usize {
+        Phase::ALL.iter().position(|&candidate| candidate == self).unwrap_or(0)
+    }
+}
+
+// How many entities a frame ended with.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EntityCounts {
+    pub asteroids: usize,
+    pub bullets: usize,
+    pub particles: usize,
+    pub pickups: usize, // Upgrade boxes and loose upgrades
+}
+
+impl EntityCounts {
+    pub fn of(world: &World) -> Self {
+        EntityCounts {
+            asteroids: world.asteroids.len(),
+            bullets: world.bullets.len(),
+            particles: world.particles.len(),
+            pickups: world.upgrade_boxes.len() + world.upgrades.len(),
+        }
+    }
+
+    fn max(self, other: EntityCounts) -> Self {
+        EntityCounts {
+            asteroids: self.asteroids.max(other.asteroids),
+            bullets: self.bullets.max(other.bullets),
+            particles: self.particles.max(other.particles),
+            pickups: self.pickups.max(other.pickups),
+        }
+    }
+}
+
+// --- Profiler: per-phase frame timings and peak entity counts, for --bench, --profile-log and --profile-flame ---
+// Attach one as `Game::profiler`; the game loop reports each phase's time and closes every frame. With a log file
+// it writes one CSV row per frame, so hot paths can be compared before and after a change. With a flame file it
+// rewrites the totals so far as folded stacks every PROFILE_FLAME_INTERVAL frames, ready for flamegraph.pl or
+// speedscope, so a player can send in a profile from their own terminal even if the game is killed.
+pub struct Profiler {
+    frame: [Duration; PHASE_COUNT], // This frame's time so far, indexed like `Phase::ALL`
+    totals: [Duration; PHASE_COUNT],
+    frames: u64,
+    peaks: EntityCounts,
+    log: Option<BufWriter<File>>,
+    flame: Option<String>, // Path of the folded-stacks file
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Profiler {
+            frame: [Duration::ZERO; PHASE_COUNT],
+            totals: [Duration::ZERO; PHASE_COUNT],
+            frames: 0,
+            peaks: EntityCounts::default(),
+            log: None,
+            flame: None,
+        }
+    }
+
+    // Also writes every frame's timings (in microseconds) and entity counts to `path`.
+    pub fn with_log(path: &str) -> io::Result<Self> {
+        let mut log = BufWriter::new(File::create(path)?);
+        let phases: Vec<String> = Phase::ALL.iter().map(|phase| format!("{}_us", phase.name())).collect();
+        writeln!(log, "frame,{},asteroids,bullets,particles,pickups", phases.join(","))?;
+        Ok(Profiler { log: Some(log), ..Profiler::new() })
+    }
+
+    // Also keeps `path` up to date with the aggregated profile as folded stacks.
+    pub fn with_flame(self, path: &str) -> Self {
+        Profiler { flame: Some(path.to_string()), ..self }
+    }
+
+    pub fn record(&mut self, phase: Phase, elapsed: Duration) {
+        self.frame[phase.index()] += elapsed;
+    }
+
+    pub fn end_frame(&mut self, frame: u64, counts: EntityCounts) -> io::Result<()> {
+        if let Some(log) = &mut self.log {
+            let timings: Vec<String> = self.frame.iter().map(|elapsed| elapsed.as_micros().to_string()).collect();
+            writeln!(log, "{},{},{},{},{},{}", frame, timings.join(","), counts.asteroids, counts.bullets, counts.particles, counts.pickups)?;
+        }
+        for (total, elapsed) in self.totals.iter_mut().zip(self.frame) {
+            *total += elapsed;
+        }
+        self.frame = [Duration::ZERO; PHASE_COUNT];
+        self.frames += 1;
+        self.peaks = self.peaks.max(counts);
+        if self.frames.is_multiple_of(PROFILE_FLAME_INTERVAL) {
+            self.write_flame()?;
+        }
+        Ok(())
+    }
+
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    pub fn peaks(&self) -> EntityCounts {
+        self.peaks
+    }
+
+    // Mean time per frame spent in `phase`.
+    pub fn average(&self, phase: Phase) -> Duration {
+        self.totals[phase.index()] / self.frames.max(1) as u32
+    }
+
+    // The totals so far as folded stacks: one "frame;simulation;bullets 1234" line per phase, in microseconds.
+    // A flame graph adds children into their parent, so a parent's line only holds the time none of them cover.
+    pub fn folded_stacks(&self) -> Vec<String> {
+        Phase::ALL
+            .iter()
+            .map(|&phase| {
+                let children: Duration = Phase::ALL.iter().filter(|child| child.parent() == Some(phase)).map(|child| self.totals[child.index()]).sum();
+                let own = self.totals[phase.index()].saturating_sub(children);
+                format!("{} {}", phase.stack(), own.as_micros())
+            })
+            .collect()
+    }
+
+    fn write_flame(&self) -> io::Result<()> {
+        let Some(path) = &self.flame else {
+            return Ok(());
+        };
+        let lines = self.folded_stacks();
+        let mut file = BufWriter::new(File::create(path)?);
+        for line in &lines {
+            writeln!(file, "{}", line)?;
+        }
+        file.flush()?;
+        info!("Profile after {} frames: {}", self.frames, lines.join(", "));
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.write_flame()?;
+        match &mut self.log {
+            Some(log) => log.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// What `--bench` prints.
+pub struct BenchReport {
+    pub seed: u64,
+    pub elapsed: Duration,
+    pub profiler: Profiler,
+}
+
+impl BenchReport {
+    pub fn lines(&self) -> Vec<String> {
+        let profiler = &self.profiler;
+        let frames_per_second = profiler.frames() as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON);
+        let millis = |phase| format!("{:.3}ms", profiler.average(phase).as_secs_f64() * 1000.0);
+        let peaks = profiler.peaks();
+        vec![
+            format!("Seed {}: {} frames in {:.2}s ({:.0} frames/sec)", self.seed, profiler.frames(), self.elapsed.as_secs_f64(), frames_per_second),
+            format!(
+                "Average per frame: input {}, update {} (ships {}, collisions {}, bullets {}, particles {}), draw {}, output {}",
+                millis(Phase::Input),
+                millis(Phase::Simulation),
+                millis(Phase::Ships),
+                millis(Phase::Collisions),
+                millis(Phase::Bullets),
+                millis(Phase::Particles),
+                millis(Phase::Draw),
+                millis(Phase::Output),
+            ),
+            format!("Peak entities: {} asteroids, {} bullets, {} particles, {} pickups", peaks.asteroids, peaks.bullets, peaks.particles, peaks.pickups),
+        ]
+    }
+}
+
+// Plays `frames` frames from `seed` as fast as they go, drawing into an off-screen buffer. The autopilot flies
+// with unlimited lives so every run lasts the full length and keeps the field busy.
+pub fn run_bench(seed: u64, frames: u64, profiler: Profiler) -> io::Result<BenchReport> {
+    let mut game = Game::new(
+        BENCH_WIDTH,
+        BENCH_HEIGHT,
+        OutputTarget::ScreenBuffer(ScreenBuffer::new(BENCH_WIDTH, BENCH_HEIGHT)),
+        None,
+        true,
+        Some(frames),
+        seed,
+    );
+    game.pilot = Some(Box::new(Autopilot::new()));
+    game.starting_lives = u32::MAX;
+    game.profiler = Some(profiler);
+    // Per-frame logging (the debug screen dump especially) would swamp the numbers
+    log::set_max_level(log::LevelFilter::Warn);
+    let started = Instant::now();
+    let result = game.run();
+    let elapsed = started.elapsed();
+    log::set_max_level(log::LevelFilter::Info);
+    result?;
+    let profiler = game.profiler.take().unwrap_or_default();
+    info!("Bench on seed {} ran {} frames in {:?}.", seed, profiler.frames(), elapsed);
+    Ok(BenchReport { seed, elapsed, profiler })
+}
@@ -0,0 +1,81 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::practice::Snapshot;
+use crate::replay::{self, Replay};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuickSaveAction {
+    Save,
+    Load,
+}
+
+// --- QuickSave: F5 writes the game so far to disk and F9 brings it back, in live runs outside practice mode ---
+// A save is the session's replay up to the frame F5 was pressed: seed, settings and every frame's input. Loading
+// plays that back headlessly to the same frame, which rebuilds the whole world (RNG included) exactly as it was,
+// and the score and state hash stored with the save confirm it before play resumes.
+pub struct QuickSave {
+    requested: Option<QuickSaveAction>,
+}
+
+impl QuickSave {
+    pub fn new() -> Self {
+        QuickSave { requested: None }
+    }
+
+    // Location of the save: $XDG_DATA_HOME/vibe-asteroid/quicksave or ~/.local/share/vibe-asteroid/quicksave.
+    pub fn path() -> Option<PathBuf> {
+        let base = match env::var_os("XDG_DATA_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".local").join("share"),
+        };
+        Some(base.join("vibe-asteroid").join("quicksave"))
+    }
+
+    // Input only records the request; the session loop owns the state being saved or replaced.
+    pub fn request(&mut self, action: QuickSaveAction) {
+        self.requested = Some(action);
+    }
+
+    pub fn take_request(&mut self) -> Option<QuickSaveAction> {
+        self.requested.take()
+    }
+}
+
+impl Default for QuickSave {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// A loaded save: the run it came from, and the moment it was made.
+pub struct SavedGame {
+    pub replay: Replay,
+    pub snapshot: Snapshot,
+}
+
+// Plays the save back and checks that it reached the moment it was made.
+pub fn load() -> io::Result<SavedGame> {
+    let path = QuickSave::path().ok_or_else(|| invalid_save("no home directory for the quick save"))?;
+    let replay = Replay::load(&path.to_string_lossy())?;
+    let (summary, snapshot) = replay::resimulate(&replay)?;
+    if summary.score != replay.score || summary.state_hash != replay.state_hash {
+        return Err(invalid_save("the quick save no longer plays back to the moment it was made"));
+    }
+    Ok(SavedGame { replay, snapshot })
+}
+
+// Where F5 writes to, with its directory created.
+pub fn prepare_path() -> io::Result<PathBuf> {
+    let path = QuickSave::path().ok_or_else(|| invalid_save("no home directory for the quick save"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    Ok(path)
+}
+
+fn invalid_save(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
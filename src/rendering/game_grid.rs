@@ -0,0 +1,272 @@
+use std::io::{self, Write};
+use crossterm::{
+    cursor::MoveTo,
+    style::{Color, SetForegroundColor},
+    terminal::{Clear, ClearType},
+    Command,
+};
+
+use super::{Cell, EMPTY_CELL, OutputTarget, line_cells};
+
+// The empty Braille pattern; the other 255 follow it, one bit per dot.
+const BRAILLE_BLANK: char = '\u{2800}';
+// The bit for each dot of a Braille cell, by row and column.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+// --- GameGrid for geometric rendering ---
+// Holds the whole frame (playfield and HUD). `render` only sends the cells that differ from
+// what the terminal already shows, so a mostly static screen costs almost nothing to redraw.
+pub struct GameGrid {
+    pub grid: Vec<Vec<Cell>>,
+    pub width: u16,
+    pub height: u16,
+    pub color: bool,   // Emit colour escapes (off for monochrome terminals)
+    pub unicode: bool, // Off: replace non-ASCII glyphs with ASCII look-alikes
+    pub origin: (u16, u16), // Screen cell of the grid's top-left corner; non-zero when letterboxed
+    pub camera: (i32, i32), // Offset from world to grid cells (camera and shake); reset to zero for HUD and menus
+    pub world: Option<(u16, u16)>, // Size of a wrapping world larger than the grid, while drawing it
+    pub fit: Option<(u16, u16)>,   // Size of a fixed world stretched or squeezed to fill the grid, while drawing it
+    pub hires: bool, // --hires: ships, asteroids and shots are plotted in Braille dots instead of whole cells
+    previous: Option<Vec<Vec<Cell>>>, // Last frame sent to the terminal; None forces a full redraw
+    dots: Vec<Vec<u8>>, // Braille dots lit in each cell by `plot`; cleared when a glyph replaces the cell
+}
+
+impl GameGrid {
+    pub fn new(width: u16, height: u16) -> Self {
+        GameGrid {
+            grid: vec![vec![EMPTY_CELL; width as usize]; height as usize],
+            width,
+            height,
+            color: true,
+            unicode: true,
+            origin: (0, 0),
+            camera: (0, 0),
+            world: None,
+            fit: None,
+            hires: false,
+            previous: None,
+            dots: vec![vec![0; width as usize]; height as usize],
+        }
+    }
+
+    pub fn set_char(&mut self, x: u16, y: u16, c: char) {
+        self.set_cell(x, y, c, Color::Reset);
+    }
+
+    pub fn set_cell(&mut self, x: u16, y: u16, c: char, color: Color) {
+        let (mut x, mut y) = (x as i32 + self.camera.0, y as i32 + self.camera.1);
+        if let Some((world_width, world_height)) = self.world {
+            // The viewport may straddle the world's seam
+            x = x.rem_euclid(world_width.max(1) as i32);
+            y = y.rem_euclid(world_height.max(1) as i32);
+        }
+        let Some((fit_width, fit_height)) = self.fit else {
+            self.put(x, y, c, color);
+            return;
+        };
+        // A world cell covers a block of grid cells, at least one, so stretched shapes don't come apart
+        let span = |cell: i32, grid: u16, world: u16| {
+            let scale = grid as f64 / world.max(1) as f64;
+            let (start, end) = ((cell as f64 * scale).floor() as i32, ((cell + 1) as f64 * scale).floor() as i32);
+            start..end.max(start + 1)
+        };
+        for grid_y in span(y, self.height, fit_height) {
+            for grid_x in span(x, self.width, fit_width) {
+                self.put(grid_x, grid_y, c, color);
+            }
+        }
+    }
+
+    fn put(&mut self, x: i32, y: i32, c: char, color: Color) {
+        if (0..self.height as i32).contains(&y) && (0..self.width as i32).contains(&x) {
+            self.grid[y as usize][x as usize] = (c, color);
+            self.dots[y as usize][x as usize] = 0;
+        }
+    }
+
+    // Lights the Braille dot under world point (`x`, `y`). Each cell holds 2x4 dots, and dots lit in the same cell
+    // merge into one character, so shapes keep four times the detail of whole-cell glyphs.
+    pub fn plot(&mut self, x: f64, y: f64, color: Color) {
+        // Shifted half a cell so a point lands in the same cell `set_cell` would round it to
+        let (mut x, mut y) = (x + 0.5 + self.camera.0 as f64, y + 0.5 + self.camera.1 as f64);
+        if let Some((world_width, world_height)) = self.world {
+            x = x.rem_euclid(world_width.max(1) as f64);
+            y = y.rem_euclid(world_height.max(1) as f64);
+        }
+        if let Some((fit_width, fit_height)) = self.fit {
+            x *= self.width as f64 / fit_width.max(1) as f64;
+            y *= self.height as f64 / fit_height.max(1) as f64;
+        }
+        let (dot_x, dot_y) = ((x * 2.0).floor() as i32, (y * 4.0).floor() as i32);
+        let (cell_x, cell_y) = (dot_x.div_euclid(2), dot_y.div_euclid(4));
+        if !(0..self.height as i32).contains(&cell_y) || !(0..self.width as i32).contains(&cell_x) {
+            return;
+        }
+        let dots = &mut self.dots[cell_y as usize][cell_x as usize];
+        *dots |= BRAILLE_DOTS[dot_y.rem_euclid(4) as usize][dot_x.rem_euclid(2) as usize];
+        let c = char::from_u32(BRAILLE_BLANK as u32 + *dots as u32).unwrap_or(BRAILLE_BLANK);
+        self.grid[cell_y as usize][cell_x as usize] = (c, color);
+    }
+
+    // A line of dots between two world points, one dot per step so there are no gaps.
+    pub fn plot_line(&mut self, from: (f64, f64), to: (f64, f64), color: Color) {
+        let steps = ((to.0 - from.0).abs() * 2.0).max((to.1 - from.1).abs() * 4.0).ceil().max(1.0);
+        for step in 0..=steps as u32 {
+            let t = step as f64 / steps;
+            self.plot(from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t, color);
+        }
+    }
+
+    pub fn write_str(&mut self, x: u16, y: u16, text: &str, color: Color) {
+        for (i, c) in text.chars().enumerate() {
+            self.set_cell(x.saturating_add(i as u16), y, c, color);
+        }
+    }
+
+    // A one-cell ring of `c` around `cells`. Drawn before the shape itself, so only the ring shows.
+    pub fn outline(&mut self, cells: &[(u16, u16)], c: char, color: Color) {
+        for &(x, y) in cells {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+                        self.set_cell(x, y, c, color);
+                    }
+                }
+            }
+        }
+    }
+
+    // A straight run of `c` from one cell to another, both ends included.
+    pub fn line(&mut self, from: (u16, u16), to: (u16, u16), c: char, color: Color) {
+        for (x, y) in line_cells(from, to) {
+            self.set_cell(x, y, c, color);
+        }
+    }
+
+    // Recolours everything drawn so far, e.g. to push the playfield into the background.
+    pub fn dim(&mut self, color: Color) {
+        for row in &mut self.grid {
+            for cell in row.iter_mut().filter(|cell| cell.0 != ' ') {
+                cell.1 = color;
+            }
+        }
+    }
+
+    // Call when something outside the grid touched the screen (attributes, other screens).
+    pub fn invalidate(&mut self) {
+        self.previous = None;
+    }
+
+    pub fn get_char(&self, x: u16, y: u16) -> Option<char> {
+        if y < self.height && x < self.width {
+            Some(self.grid[y as usize][x as usize].0)
+        } else {
+            None
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.grid = vec![vec![EMPTY_CELL; self.width as usize]; self.height as usize];
+        self.dots = vec![vec![0; self.width as usize]; self.height as usize];
+    }
+
+    // Writes changed cells as one batch; cursor moves and colour escapes only where needed.
+    pub fn render(&mut self, stdout: &mut OutputTarget) -> io::Result<()> {
+        let mut frame = String::new();
+        if self.previous.is_none() && self.origin != (0, 0) {
+            self.write_letterbox_border(&mut frame);
+        }
+        let (origin_x, origin_y) = self.origin;
+        let mut current_color = Color::Reset;
+        for y in 0..self.height as usize {
+            let mut cursor_x = None;
+            for x in 0..self.width as usize {
+                let cell = self.grid[y][x];
+                if self.previous.as_ref().is_some_and(|previous| previous[y][x] == cell) {
+                    continue;
+                }
+                let (mut c, mut color) = cell;
+                if !self.color {
+                    color = Color::Reset;
+                }
+                if !self.unicode {
+                    c = ascii_fallback(c);
+                }
+                if cursor_x != Some(x) {
+                    let _ = MoveTo(origin_x + x as u16, origin_y + y as u16).write_ansi(&mut frame);
+                }
+                if color != current_color {
+                    let _ = SetForegroundColor(color).write_ansi(&mut frame);
+                    current_color = color;
+                }
+                frame.push(c);
+                cursor_x = Some(x + 1);
+            }
+        }
+        if current_color != Color::Reset {
+            let _ = SetForegroundColor(Color::Reset).write_ansi(&mut frame);
+        }
+        stdout.write_all(frame.as_bytes())?;
+        self.previous = Some(self.grid.clone());
+        Ok(())
+    }
+
+    // Blanks the whole screen and frames the grid; sides are only drawn on the axes that are letterboxed.
+    fn write_letterbox_border(&self, frame: &mut String) {
+        let _ = Clear(ClearType::All).write_ansi(frame);
+        let (x, y) = self.origin;
+        let (left, right, top, bottom) = (x.saturating_sub(1), x + self.width, y.saturating_sub(1), y + self.height);
+        let glyph = |c: char| if self.unicode { c } else { ascii_fallback(c) };
+        if y > 0 {
+            for row in [top, bottom] {
+                let _ = MoveTo(x, row).write_ansi(frame);
+                frame.extend(std::iter::repeat_n(glyph('─'), self.width as usize));
+            }
+        }
+        if x > 0 {
+            for row in y..bottom {
+                for column in [left, right] {
+                    let _ = MoveTo(column, row).write_ansi(frame);
+                    frame.push(glyph('│'));
+                }
+            }
+        }
+        if x > 0 && y > 0 {
+            for (column, row, corner) in [(left, top, '┌'), (right, top, '┐'), (left, bottom, '└'), (right, bottom, '┘')] {
+                let _ = MoveTo(column, row).write_ansi(frame);
+                frame.push(glyph(corner));
+            }
+        }
+    }
+
+    pub fn clear_screen_manual(&self, stdout: &mut OutputTarget, terminal_width: u16, terminal_height: u16) -> io::Result<()> {
+        for y in 0..terminal_height {
+            stdout.execute_move_to(MoveTo(0, y))?;
+            write!(stdout, "{}", " ".repeat(terminal_width as usize))?;
+        }
+        stdout.execute_move_to(MoveTo(0, 0))?;
+        Ok(())
+    }
+}
+
+
+// Closest ASCII stand-in for the glyphs the game draws, for terminals without Unicode support.
+fn ascii_fallback(c: char) -> char {
+    match c {
+        _ if c.is_ascii() => c,
+        '●' => 'o',
+        '·' | '∙' | '░' => '.',
+        '▒' => ':',
+        '▓' => '#',
+        '▁' | '▂' => '_',
+        '▃' | '▄' => '-',
+        '▅' | '▆' => '=',
+        '▇' | '█' => '#',
+        '\u{2801}'..='\u{28FF}' => '.',
+        '─' => '-',
+        '│' => '|',
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' => '+',
+        '𝟎'..='𝟗' => char::from_u32('0' as u32 + (c as u32 - '𝟎' as u32)).unwrap_or('?'),
+        _ => '?',
+    }
+}
@@ -0,0 +1,56 @@
+// --- Line rasterizing for entity outlines ---
+// Bresenham's algorithm: the cells of the line from `from` to `to`, in order and both ends included, with no
+// gaps even diagonally.
+pub fn line_cells(from: (u16, u16), to: (u16, u16)) -> Vec<(u16, u16)> {
+    let (mut x, mut y) = (from.0 as i32, from.1 as i32);
+    let (end_x, end_y) = (to.0 as i32, to.1 as i32);
+    let (dx, dy) = ((end_x - x).abs(), -(end_y - y).abs());
+    let (step_x, step_y) = (if x < end_x { 1 } else { -1 }, if y < end_y { 1 } else { -1 });
+    let mut error = dx + dy;
+    let mut cells = Vec::with_capacity((dx - dy) as usize + 1);
+    loop {
+        cells.push((x as u16, y as u16));
+        if x == end_x && y == end_y {
+            return cells;
+        }
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += step_y;
+        }
+    }
+}
+
+// The edges of the closed polygon through `vertices`, taken in order.
+pub fn polygon_cells(vertices: &[(u16, u16)]) -> Vec<(u16, u16)> {
+    let mut cells: Vec<(u16, u16)> = Vec::new();
+    for (i, &from) in vertices.iter().enumerate() {
+        let to = vertices[(i + 1) % vertices.len()];
+        for cell in line_cells(from, to) {
+            if !cells.contains(&cell) {
+                cells.push(cell);
+            }
+        }
+    }
+    cells
+}
+
+// The character that best follows a line's slope. Rows are about twice as tall as columns are wide, so a line
+// counts as flat until it climbs more than one row per two columns.
+pub fn line_glyph(from: (u16, u16), to: (u16, u16)) -> char {
+    let dx = to.0 as i32 - from.0 as i32;
+    let dy = to.1 as i32 - from.1 as i32;
+    if dy.abs() * 2 < dx.abs() {
+        '-'
+    } else if dx.abs() * 2 < dy.abs() {
+        '|'
+    } else if (dx > 0) == (dy > 0) {
+        '\\'
+    } else {
+        '/'
+    }
+}
@@ -0,0 +1,109 @@
+use crate::types::Vector2D;
+
+use super::GameGrid;
+
+// --- Corner overlays and the minimap ---
+// A corner of the playfield to pin an overlay to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Corner {
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Corner {
+    pub const ALL: [Corner; 4] = [Corner::NorthEast, Corner::NorthWest, Corner::SouthEast, Corner::SouthWest];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Corner::NorthEast => "ne",
+            Corner::NorthWest => "nw",
+            Corner::SouthEast => "se",
+            Corner::SouthWest => "sw",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|corner| corner.name() == name)
+    }
+
+    pub fn is_south(self) -> bool {
+        matches!(self, Corner::SouthEast | Corner::SouthWest)
+    }
+
+    pub fn is_east(self) -> bool {
+        matches!(self, Corner::NorthEast | Corner::SouthEast)
+    }
+
+    // Top-left cell of a `size` box tucked into this corner of an area `bounds` cells across.
+    pub fn anchor(self, size: (u16, u16), bounds: (u16, u16)) -> (u16, u16) {
+        let x = if self.is_east() { bounds.0.saturating_sub(size.0) } else { 0 };
+        let y = if self.is_south() { bounds.1.saturating_sub(size.1) } else { 0 };
+        (x, y)
+    }
+}
+
+// The minimap keeps no position of its own: `draw` anchors it to the grid it is given, so it follows resizes.
+pub struct Minimap {
+    buffer: Vec<Vec<char>>,
+    width: u16,
+    height: u16,
+}
+
+impl Minimap {
+    pub fn new(width: u16, height: u16) -> Self {
+        Minimap { buffer: vec![vec![' '; width as usize]; height as usize], width, height }
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn set_char(&mut self, x: u16, y: u16, c: char) {
+        if y < self.height && x < self.width {
+            self.buffer[y as usize][x as usize] = c;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer = vec![vec![' '; self.width as usize]; self.height as usize];
+    }
+
+    // Scales world positions into the map inside its one-cell border. Later entries are drawn over earlier ones.
+    pub fn plot_world(&mut self, entities: &[(Vector2D, char)], world_width: u16, world_height: u16) {
+        let (inner_width, inner_height) = (self.width.saturating_sub(2), self.height.saturating_sub(2));
+        if inner_width == 0 || inner_height == 0 {
+            return;
+        }
+        let scale = |value: f64, world: u16, inner: u16| {
+            ((value / world.max(1) as f64) * inner as f64).floor().clamp(0.0, (inner - 1) as f64) as u16
+        };
+        for &(position, c) in entities {
+            let x = scale(position.x, world_width, inner_width);
+            let y = scale(position.y, world_height, inner_height);
+            self.set_char(x + 1, y + 1, c);
+        }
+    }
+
+    pub fn draw(&self, game_grid: &mut GameGrid, corner: Corner) {
+        let (x_offset, y_offset) = corner.anchor((self.width, self.height), (game_grid.width, game_grid.height));
+        let (right, bottom) = (self.width.saturating_sub(1), self.height.saturating_sub(1));
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let c = match (x, y) {
+                    (0, 0) => '┌',
+                    (x, 0) if x == right => '┐',
+                    (0, y) if y == bottom => '└',
+                    (x, y) if x == right && y == bottom => '┘',
+                    (_, 0) => '─',
+                    (_, y) if y == bottom => '─',
+                    (0, _) => '│',
+                    (x, _) if x == right => '│',
+                    _ => self.buffer[y as usize][x as usize],
+                };
+                game_grid.set_char(x_offset + x, y_offset + y, c);
+            }
+        }
+    }
+}
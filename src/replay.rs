@@ -0,0 +1,59 @@
+use std::io;
+
+use crate::difficulty::Difficulty;
+use crate::scoring::ScoringMode;
+use crate::mode_rules::Goal;
+use crate::loadout::Loadout;
+use crate::constants::STARTING_LIVES;
+
+mod file;
+mod hash;
+mod playback;
+mod recorder;
+
+pub use file::Replay;
+pub use hash::{fnv1a, state_hash};
+pub use playback::{resimulate, verify};
+pub use recorder::ReplayRecorder;
+
+// --- RunSettings: the choices a run starts with that playing it back has to repeat ---
+// Built by `Game::run_settings` with tournament overrides already applied, and put back with
+// `Game::apply_run_settings` for playback and quick loads.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunSettings {
+    pub dampeners: bool, // Flight model the run started with
+    pub difficulty: Difficulty,
+    pub scoring: ScoringMode,
+    pub world_scale: u16,
+    pub fixed_world: bool,
+    pub lives: u32,
+    pub coop: bool,
+    pub versus: Option<u32>,  // Kills to win, for a versus round
+    pub fleet: Option<usize>, // Ships flown, for a fleet run
+    pub goal: Goal,
+    pub loadout: Option<Loadout>,
+    pub idle_decay: bool,
+}
+
+impl Default for RunSettings {
+    fn default() -> Self {
+        RunSettings {
+            dampeners: true,
+            difficulty: Difficulty::Normal,
+            scoring: ScoringMode::Classic,
+            world_scale: 1,
+            fixed_world: false,
+            lives: STARTING_LIVES,
+            coop: false,
+            versus: None,
+            fleet: None,
+            goal: Goal::Endless,
+            loadout: None,
+            idle_decay: false,
+        }
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
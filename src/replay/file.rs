@@ -0,0 +1,275 @@
+use std::fs;
+use std::io::{self, Write};
+use crossterm::event::{Event, KeyCode, ModifierKeyCode};
+
+use crate::assist::AssistSettings;
+use crate::difficulty::Difficulty;
+use crate::scoring::ScoringMode;
+use crate::terminal_io::SimulatedInput;
+use crate::input::InputState;
+use crate::tournament::Ruleset;
+use crate::mode_rules::Goal;
+use crate::loadout::{self, Loadout};
+use crate::constants::STARTING_LIVES;
+
+use super::{RunSettings, invalid_data};
+
+const REPLAY_HEADER: &str = "vibe-asteroid-replay 2";
+
+// --- Replay file: seed, playfield size, per-frame input and ticks, and final-state metadata ---
+pub struct Replay {
+    pub seed: u64,
+    pub width: u16,
+    pub height: u16,
+    pub frames: u64,
+    pub score: u32,
+    pub state_hash: u64,
+    pub assist: AssistSettings,
+    pub ruleset: Option<Ruleset>, // Tournament conditions the run was played under
+    pub settings: RunSettings,
+    pub events: Vec<(u64, Event)>,
+    pub steps: Vec<(u64, u32)>, // Frames that ran other than exactly one simulation tick
+    pub pad: Vec<(u64, InputState)>, // Frames a gamepad was pushed or pressed
+}
+
+impl Replay {
+    pub fn load(path: &str) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines();
+        if lines.next() != Some(REPLAY_HEADER) {
+            return Err(invalid_data("missing replay header"));
+        }
+
+        let mut replay = Replay {
+            seed: 0,
+            width: 0,
+            height: 0,
+            frames: 0,
+            score: 0,
+            state_hash: 0,
+            assist: AssistSettings::default(),
+            ruleset: None,
+            settings: RunSettings::default(),
+            events: Vec::new(),
+            steps: Vec::new(),
+            pad: Vec::new(),
+        };
+        let mut ruleset_line = None;
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["seed", seed] => replay.seed = parse_field(seed)?,
+                ["size", width, height] => {
+                    replay.width = parse_field(width)?;
+                    replay.height = parse_field(height)?;
+                }
+                ["frames", frames] => replay.frames = parse_field(frames)?,
+                ["score", score] => replay.score = parse_field(score)?,
+                ["hash", hash] => {
+                    replay.state_hash = u64::from_str_radix(hash, 16).map_err(|_| invalid_data("bad state hash"))?;
+                }
+                ["assist", speed, health, invincibility, auto_fire, hitbox] => {
+                    replay.assist = AssistSettings {
+                        enemy_speed_percent: parse_field(speed)?,
+                        extra_starting_health: parse_field(health)?,
+                        extended_invincibility: parse_field(invincibility)?,
+                        auto_fire: parse_field(auto_fire)?,
+                        forgiving_hitbox: parse_field(hitbox)?,
+                    };
+                }
+                ["ruleset", idle_decay, hash] => {
+                    let hash = u64::from_str_radix(hash, 16).map_err(|_| invalid_data("bad ruleset hash"))?;
+                    ruleset_line = Some((parse_field::<bool>(idle_decay)?, hash));
+                }
+                ["dampeners", value] => replay.settings.dampeners = parse_field(value)?,
+                ["lives", lives] => replay.settings.lives = parse_field(lives)?,
+                ["difficulty", name] => replay.settings.difficulty = Difficulty::parse(name).ok_or_else(|| invalid_data("unknown difficulty"))?,
+                ["scoring", name] => replay.settings.scoring = ScoringMode::parse(name).ok_or_else(|| invalid_data("unknown scoring rules"))?,
+                ["world-scale", scale] => replay.settings.world_scale = parse_field(scale)?,
+                ["fixed-world", value] => replay.settings.fixed_world = parse_field(value)?,
+                ["idle-decay", value] => replay.settings.idle_decay = parse_field(value)?,
+                ["coop", value] => replay.settings.coop = parse_field(value)?,
+                ["versus", kills] => replay.settings.versus = Some(parse_field(kills)?),
+                ["fleet", ships] => replay.settings.fleet = Some(parse_field(ships)?),
+                ["goal", "score", target] => replay.settings.goal = Goal::TargetScore(parse_field(target)?),
+                ["goal", "waves", waves] => replay.settings.goal = Goal::ClearWaves(parse_field(waves)?),
+                ["loadout", name] => replay.settings.loadout = Some(Loadout { name: name.to_string(), start: Vec::new(), favoured: Vec::new() }),
+                ["loadout-start", keys @ ..] => {
+                    let loadout = replay.settings.loadout.as_mut().ok_or_else(|| invalid_data("loadout upgrades without a loadout"))?;
+                    loadout.start = loadout::parse_upgrades(keys).ok_or_else(|| invalid_data("unknown upgrade"))?;
+                }
+                ["loadout-favour", keys @ ..] => {
+                    let loadout = replay.settings.loadout.as_mut().ok_or_else(|| invalid_data("loadout upgrades without a loadout"))?;
+                    loadout.favoured = loadout::parse_upgrades(keys).ok_or_else(|| invalid_data("unknown upgrade"))?;
+                }
+                ["key", frame, name] => {
+                    let code = decode_key(name).ok_or_else(|| invalid_data("unknown key name"))?;
+                    replay.events.push((parse_field(frame)?, Event::Key(code.into())));
+                }
+                ["steps", frame, steps] => replay.steps.push((parse_field(frame)?, parse_field(steps)?)),
+                ["pad", frame, thrust, fire, steer] => {
+                    let pad = InputState { thrust: parse_field(thrust)?, fire: parse_field(fire)?, steer: parse_field(steer)?, ..InputState::default() };
+                    replay.pad.push((parse_field(frame)?, pad));
+                }
+                ["resize", frame, width, height] => {
+                    replay.events.push((parse_field(frame)?, Event::Resize(parse_field(width)?, parse_field(height)?)));
+                }
+                _ => return Err(invalid_data("unrecognised replay line")),
+            }
+        }
+        if let Some((idle_decay, hash)) = ruleset_line {
+            let ruleset = Ruleset { seed: replay.seed, idle_decay };
+            if ruleset.hash() != hash {
+                return Err(invalid_data("ruleset hash does not match the recorded rules"));
+            }
+            replay.ruleset = Some(ruleset);
+        }
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut file = fs::File::create(path)?;
+        writeln!(file, "{}", REPLAY_HEADER)?;
+        writeln!(file, "seed {}", self.seed)?;
+        writeln!(file, "size {} {}", self.width, self.height)?;
+        writeln!(file, "frames {}", self.frames)?;
+        writeln!(file, "score {}", self.score)?;
+        writeln!(file, "hash {:016x}", self.state_hash)?;
+        if self.assist.is_active() {
+            writeln!(
+                file,
+                "assist {} {} {} {} {}",
+                self.assist.enemy_speed_percent,
+                self.assist.extra_starting_health,
+                self.assist.extended_invincibility,
+                self.assist.auto_fire,
+                self.assist.forgiving_hitbox,
+            )?;
+        }
+        if let Some(ruleset) = &self.ruleset {
+            writeln!(file, "ruleset {} {:016x}", ruleset.idle_decay, ruleset.hash())?;
+        }
+        if !self.settings.dampeners {
+            writeln!(file, "dampeners false")?;
+        }
+        if self.settings.difficulty != Difficulty::Normal {
+            writeln!(file, "difficulty {}", self.settings.difficulty.name())?;
+        }
+        if self.settings.scoring != ScoringMode::Classic {
+            writeln!(file, "scoring {}", self.settings.scoring.name())?;
+        }
+        if self.settings.world_scale != 1 {
+            writeln!(file, "world-scale {}", self.settings.world_scale)?;
+        }
+        if self.settings.fixed_world {
+            writeln!(file, "fixed-world true")?;
+        }
+        if self.settings.idle_decay {
+            writeln!(file, "idle-decay true")?;
+        }
+        if self.settings.lives != STARTING_LIVES {
+            writeln!(file, "lives {}", self.settings.lives)?;
+        }
+        if self.settings.coop {
+            writeln!(file, "coop true")?;
+        }
+        if let Some(kills) = self.settings.versus {
+            writeln!(file, "versus {}", kills)?;
+        }
+        if let Some(ships) = self.settings.fleet {
+            writeln!(file, "fleet {}", ships)?;
+        }
+        // Versus kills are on their own line above; endless is the default
+        match self.settings.goal {
+            Goal::TargetScore(target) => writeln!(file, "goal score {}", target)?,
+            Goal::ClearWaves(waves) => writeln!(file, "goal waves {}", waves)?,
+            Goal::Endless | Goal::Kills(_) => {}
+        }
+        if let Some(loadout) = &self.settings.loadout {
+            writeln!(file, "loadout {}", loadout.name)?;
+            writeln!(file, "loadout-start {}", loadout::upgrade_keys(&loadout.start))?;
+            writeln!(file, "loadout-favour {}", loadout::upgrade_keys(&loadout.favoured))?;
+        }
+        for (frame, event) in &self.events {
+            match event {
+                Event::Key(key_event) => {
+                    if let Some(name) = encode_key(key_event.code) {
+                        writeln!(file, "key {} {}", frame, name)?;
+                    }
+                }
+                Event::Resize(width, height) => writeln!(file, "resize {} {} {}", frame, width, height)?,
+                _ => {}
+            }
+        }
+        for (frame, steps) in &self.steps {
+            writeln!(file, "steps {} {}", frame, steps)?;
+        }
+        for (frame, pad) in &self.pad {
+            writeln!(file, "pad {} {} {} {}", frame, pad.thrust, pad.fire, pad.steer)?;
+        }
+        Ok(())
+    }
+
+    // The playfield size the replay ends at, after any recorded resizes.
+    pub fn final_size(&self) -> (u16, u16) {
+        self.events
+            .iter()
+            .rev()
+            .find_map(|(_, event)| match event {
+                Event::Resize(width, height) => Some((*width, *height)),
+                _ => None,
+            })
+            .unwrap_or((self.width, self.height))
+    }
+
+    pub fn simulated_input(&self) -> SimulatedInput {
+        SimulatedInput::from_events(self.events.iter().cloned())
+    }
+}
+
+fn encode_key(code: KeyCode) -> Option<String> {
+    let name = match code {
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Modifier(ModifierKeyCode::LeftShift) => "LeftShift".to_string(),
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn decode_key(name: &str) -> Option<KeyCode> {
+    let code = match name {
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Esc" => KeyCode::Esc,
+        "Enter" => KeyCode::Enter,
+        "Tab" => KeyCode::Tab,
+        "Space" => KeyCode::Char(' '),
+        "LeftShift" => KeyCode::Modifier(ModifierKeyCode::LeftShift),
+        _ if name.starts_with('F') && name.len() > 1 => KeyCode::F(name[1..].parse().ok()?),
+        _ => {
+            let mut chars = name.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            KeyCode::Char(c)
+        }
+    };
+    Some(code)
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str) -> io::Result<T> {
+    field.parse().map_err(|_| invalid_data("malformed numeric field"))
+}
@@ -0,0 +1,35 @@
+use crate::entities::{Asteroid, Ship};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// --- Hashing: FNV-1a, for ruleset fingerprints and the end-of-run state ---
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+// FNV-1a over the simulation state that matters for a run's outcome.
+pub fn state_hash(ship: &Ship, asteroids: &[Asteroid], score: u32, frame_count: u64) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut feed = |value: u64| {
+        for byte in value.to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+    feed(score as u64);
+    feed(frame_count);
+    feed(ship.position.x.to_bits());
+    feed(ship.position.y.to_bits());
+    feed(ship.angle.to_bits());
+    for asteroid in asteroids {
+        feed(asteroid.position.x.to_bits());
+        feed(asteroid.position.y.to_bits());
+    }
+    hash
+}
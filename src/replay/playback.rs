@@ -0,0 +1,67 @@
+use std::io;
+
+use crate::game::{Game, RunSummary};
+use crate::rendering::{OutputTarget, ScreenBuffer};
+use crate::gamepad::ScriptedGamepad;
+use crate::idle_decay::IdleDecay;
+use crate::practice::Snapshot;
+use crate::constants::{IDLE_DECAY_GRACE, IDLE_DECAY_INTERVAL, IDLE_DECAY_POINTS};
+
+use super::{Replay, invalid_data};
+
+// --- Playback: re-running a recorded game headlessly ---
+// A headless game set up to play `replay` back from its first frame to its last.
+fn playback_game(replay: &Replay) -> Game {
+    let mut game = Game::new(
+        replay.width,
+        replay.height,
+        OutputTarget::ScreenBuffer(ScreenBuffer::new(replay.width, replay.height)),
+        Some(replay.simulated_input()),
+        true,
+        Some(replay.frames),
+        replay.seed,
+    );
+    game.headless = true;
+    game.assist = replay.assist;
+    game.apply_run_settings(&replay.settings);
+    game.step_script = Some(replay.steps.iter().copied().collect());
+    if !replay.pad.is_empty() {
+        game.gamepad = Some(Box::new(ScriptedGamepad::new(replay.pad.iter().copied())));
+    }
+    if let Some(ruleset) = replay.ruleset {
+        game.ruleset = Some(ruleset);
+        if ruleset.idle_decay {
+            game.idle_decay = Some(IdleDecay::new(IDLE_DECAY_GRACE, IDLE_DECAY_INTERVAL, IDLE_DECAY_POINTS));
+        }
+    }
+    game
+}
+
+// Plays a replay back headlessly, returning how it ended and the world at that moment.
+pub fn resimulate(replay: &Replay) -> io::Result<(RunSummary, Snapshot)> {
+    let mut game = playback_game(replay);
+    let summary = game.run()?;
+    let snapshot = game.final_snapshot.take().ok_or_else(|| invalid_data("the replay ended before it started"))?;
+    Ok((summary, snapshot))
+}
+
+// Re-simulates a replay headlessly and reports whether it reproduces the recorded outcome.
+pub fn verify(path: &str) -> io::Result<bool> {
+    let replay = Replay::load(path)?;
+    let mut game = playback_game(&replay);
+    if let Some(ruleset) = replay.ruleset {
+        println!("Ruleset: {:016x}", ruleset.hash());
+    }
+    let summary = game.run()?;
+
+    let passed = summary.score == replay.score && summary.state_hash == replay.state_hash;
+    println!(
+        "{}: score {} (expected {}), hash {:016x} (expected {:016x})",
+        if passed { "PASS" } else { "FAIL" },
+        summary.score,
+        replay.score,
+        summary.state_hash,
+        replay.state_hash,
+    );
+    Ok(passed)
+}
@@ -0,0 +1,103 @@
+use std::io;
+use crossterm::event::Event;
+
+use crate::assist::AssistSettings;
+use crate::game::RunSummary;
+use crate::input::InputState;
+use crate::tournament::Ruleset;
+
+use super::{RunSettings, Replay};
+
+// --- ReplayRecorder collects input while a live game is played ---
+// Live runs with quick saves record even without --record; those have no `path` and only ever save progress.
+pub struct ReplayRecorder {
+    path: Option<String>,
+    seed: u64,
+    width: u16,
+    height: u16,
+    assist: AssistSettings,
+    ruleset: Option<Ruleset>,
+    settings: RunSettings,
+    events: Vec<(u64, Event)>,
+    steps: Vec<(u64, u32)>,
+    pad: Vec<(u64, InputState)>,
+}
+
+impl ReplayRecorder {
+    pub fn new(path: Option<String>, seed: u64, width: u16, height: u16, assist: AssistSettings, ruleset: Option<Ruleset>, settings: RunSettings) -> Self {
+        ReplayRecorder { path, seed, width, height, assist, ruleset, settings, events: Vec::new(), steps: Vec::new(), pad: Vec::new() }
+    }
+
+    // A restarted game is a new run: drop what was recorded so far.
+    pub fn restart(&mut self, seed: u64, settings: RunSettings) {
+        self.seed = seed;
+        self.settings = settings;
+        self.events.clear();
+        self.steps.clear();
+        self.pad.clear();
+    }
+
+    // Wall-clock timing decides how many ticks a live frame runs, so it has to be replayed verbatim.
+    pub fn record_steps(&mut self, frame: u64, steps: u32) {
+        if steps != 1 {
+            self.steps.push((frame, steps));
+        }
+    }
+
+    // Analog steering has no key to stand for it, so gamepad input is kept as-is.
+    pub fn record_pad(&mut self, frame: u64, pad: InputState) {
+        self.pad.push((frame, pad));
+    }
+
+    pub fn record(&mut self, frame: u64, event: &Event) {
+        if matches!(event, Event::Key(_) | Event::Resize(_, _)) {
+            self.events.push((frame, event.clone()));
+        }
+    }
+
+    // Carries on from a quick save: its run's settings and input so far become this recording's.
+    pub fn resume(&mut self, replay: &Replay) {
+        self.seed = replay.seed;
+        self.width = replay.width;
+        self.height = replay.height;
+        self.assist = replay.assist;
+        self.ruleset = replay.ruleset;
+        self.settings = replay.settings.clone();
+        self.events = replay.events.clone();
+        self.steps = replay.steps.clone();
+        self.pad = replay.pad.clone();
+    }
+
+    pub fn records_to_file(&self) -> bool {
+        self.path.is_some()
+    }
+
+    // Writes the run so far as a replay ending after `frames` frames, as a quick save.
+    pub fn save_progress(&self, path: &str, frames: u64, score: u32, state_hash: u64) -> io::Result<()> {
+        self.replay(frames, score, state_hash).save(path)
+    }
+
+    pub fn finish(self, summary: &RunSummary) -> io::Result<()> {
+        match &self.path {
+            Some(path) => self.replay(summary.frames, summary.score, summary.state_hash).save(path),
+            None => Ok(()),
+        }
+    }
+
+    fn replay(&self, frames: u64, score: u32, state_hash: u64) -> Replay {
+        Replay {
+            seed: self.seed,
+            width: self.width,
+            height: self.height,
+            frames,
+            score,
+            state_hash,
+            assist: self.assist,
+            ruleset: self.ruleset,
+            settings: self.settings.clone(),
+            events: self.events.clone(),
+            steps: self.steps.clone(),
+            pad: self.pad.clone(),
+        }
+    }
+}
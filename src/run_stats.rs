@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use crate::entities::AsteroidSize;
+use crate::events::GameEvent;
+
+// --- RunStats: what a run got done, for the game over summary ---
+// Kept on the `World` and counted as the game plays: shots where they're fired and land, everything else from
+// the game events. In co-op and fleet runs the counts cover every ship.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RunStats {
+    pub asteroids_destroyed: [u32; 3], // Large, medium and small
+    pub shots_fired: u32,
+    pub shots_landed: u32, // Shots that hit an asteroid, saucer, boss or upgrade box
+    pub upgrades_collected: u32,
+}
+
+impl RunStats {
+    pub fn new() -> Self {
+        RunStats { asteroids_destroyed: [0; 3], shots_fired: 0, shots_landed: 0, upgrades_collected: 0 }
+    }
+
+    pub fn record_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::AsteroidDestroyed { size, .. } => {
+                let index = match size {
+                    AsteroidSize::Large => 0,
+                    AsteroidSize::Medium => 1,
+                    AsteroidSize::Small => 2,
+                };
+                self.asteroids_destroyed[index] += 1;
+            }
+            GameEvent::UpgradeCollected { .. } => self.upgrades_collected += 1,
+            _ => {}
+        }
+    }
+
+    pub fn shot_fired(&mut self) {
+        self.shots_fired += 1;
+    }
+
+    pub fn shot_landed(&mut self) {
+        self.shots_landed += 1;
+    }
+
+    // None until a shot has been fired.
+    pub fn accuracy_percent(&self) -> Option<u32> {
+        (self.shots_fired > 0).then(|| (self.shots_landed.min(self.shots_fired) as f64 * 100.0 / self.shots_fired as f64).round() as u32)
+    }
+
+    // The summary's lines, with the wave the run ended on and how long it lasted in game time.
+    pub fn lines(&self, wave: usize, survived: Duration) -> Vec<String> {
+        let [large, medium, small] = self.asteroids_destroyed;
+        let accuracy = match self.accuracy_percent() {
+            Some(percent) => format!("{}% ({} of {} shots)", percent, self.shots_landed, self.shots_fired),
+            None => "no shots fired".to_string(),
+        };
+        let seconds = survived.as_secs();
+        vec![
+            format!("Asteroids destroyed: {} large, {} medium, {} small", large, medium, small),
+            format!("Accuracy: {}", accuracy),
+            format!("Upgrades collected: {}", self.upgrades_collected),
+            format!("Wave reached: {}", wave),
+            format!("Time survived: {}:{:02}", seconds / 60, seconds % 60),
+        ]
+    }
+}
+
+impl Default for RunStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::{self, Write};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+
+// --- ScoreStream: live game state as JSON lines for overlays and status bars ---
+// Writing happens on a background thread so a slow or absent reader never stalls the game.
+// If the path is an existing named pipe the lines are written to it; otherwise a Unix socket
+// is bound there and every connected client receives every line.
+
+const STREAM_QUEUE_LENGTH: usize = 64; // Lines buffered before new ones are dropped
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct StreamSnapshot {
+    pub score: u32,
+    pub health: u32,
+    pub max_health: u32,
+    pub wave: usize,
+    pub ruleset_hash: Option<u64>, // Tournament runs identify the rules they were played under
+}
+
+impl StreamSnapshot {
+    pub fn to_json_line(self, frame: u64) -> String {
+        let ruleset = match self.ruleset_hash {
+            Some(hash) => format!(",\"ruleset\":\"{:016x}\"", hash),
+            None => String::new(),
+        };
+        format!(
+            "{{\"score\":{},\"health\":{},\"max_health\":{},\"wave\":{},\"frame\":{}{}}}\n",
+            self.score, self.health, self.max_health, self.wave, frame, ruleset
+        )
+    }
+}
+
+pub struct ScoreStream {
+    sender: SyncSender<String>,
+    last_sent: Option<StreamSnapshot>,
+}
+
+impl ScoreStream {
+    pub fn open(path: &str) -> io::Result<Self> {
+        let (sender, receiver) = mpsc::sync_channel(STREAM_QUEUE_LENGTH);
+        spawn_writer(path, receiver)?;
+        Ok(ScoreStream { sender, last_sent: None })
+    }
+
+    // Queues a line only when something a viewer would notice has changed.
+    pub fn publish(&mut self, snapshot: StreamSnapshot, frame: u64) {
+        if self.last_sent == Some(snapshot) {
+            return;
+        }
+        match self.sender.try_send(snapshot.to_json_line(frame)) {
+            Ok(()) => self.last_sent = Some(snapshot),
+            Err(TrySendError::Full(_)) => {} // Reader is behind; retry on the next frame
+            Err(TrySendError::Disconnected(_)) => self.last_sent = Some(snapshot),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn spawn_writer(path: &str, receiver: Receiver<String>) -> io::Result<()> {
+    use std::fs::{self, OpenOptions};
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::sync::mpsc::RecvTimeoutError;
+    use std::time::Duration;
+
+    let path = path.to_string();
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.file_type().is_fifo()) {
+        std::thread::spawn(move || {
+            // Opening a pipe for writing blocks until a reader shows up, and again after it leaves.
+            while let Ok(mut pipe) = OpenOptions::new().write(true).open(&path) {
+                let mut reader_left = false;
+                for line in receiver.iter() {
+                    if pipe.write_all(line.as_bytes()).is_err() {
+                        reader_left = true;
+                        break;
+                    }
+                }
+                if !reader_left {
+                    return; // The game has finished
+                }
+            }
+        });
+        return Ok(());
+    }
+
+    remove_socket(&path)?; // Stale socket from an earlier run
+    let listener = UnixListener::bind(&path)?;
+    listener.set_nonblocking(true)?;
+    std::thread::spawn(move || {
+        let mut clients: Vec<UnixStream> = Vec::new();
+        let mut latest_line = String::new(); // Sent to new clients so they start with the current state
+        loop {
+            while let Ok((mut client, _)) = listener.accept() {
+                if client.write_all(latest_line.as_bytes()).is_ok() {
+                    clients.push(client);
+                }
+            }
+            match receiver.recv_timeout(Duration::from_millis(100)) {
+                Ok(line) => {
+                    clients.retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+                    latest_line = line;
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        let _ = remove_socket(&path);
+    });
+    Ok(())
+}
+
+// Removes the socket at `path`, if there is one. Anything else there is the player's, so it's an error instead.
+#[cfg(unix)]
+fn remove_socket(path: &str) -> io::Result<()> {
+    use std::fs;
+    use std::os::unix::fs::FileTypeExt;
+
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path),
+        Ok(_) => Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} exists and is not a socket or named pipe", path))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(not(unix))]
+fn spawn_writer(_path: &str, _receiver: Receiver<String>) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "score streaming needs Unix sockets or named pipes"))
+}
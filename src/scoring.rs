@@ -0,0 +1,156 @@
+use crate::clock;
+use crate::constants::*;
+use crate::entities::AsteroidSize;
+
+// What a kill was made on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScoreTarget {
+    Asteroid(AsteroidSize),
+    Saucer,
+    Boss,
+}
+
+impl ScoreTarget {
+    // The classic per-target values every rule set starts from.
+    pub fn base_points(self) -> u32 {
+        match self {
+            ScoreTarget::Asteroid(AsteroidSize::Large) => SCORE_LARGE_ASTEROID,
+            ScoreTarget::Asteroid(AsteroidSize::Medium) => SCORE_MEDIUM_ASTEROID,
+            ScoreTarget::Asteroid(AsteroidSize::Small) => SCORE_SMALL_ASTEROID,
+            ScoreTarget::Saucer => SCORE_SAUCER,
+            ScoreTarget::Boss => SCORE_BOSS,
+        }
+    }
+}
+
+// --- ScoringRules: how much each kill is worth; rules keep whatever running state they need ---
+pub trait ScoringRules {
+    // Points for destroying `target` at simulation tick `tick`.
+    fn award(&mut self, target: ScoreTarget, tick: u64) -> u32;
+
+    fn shot_fired(&mut self) {}
+
+    // A bullet hit something (once per bullet, however many asteroids it took out).
+    fn shot_landed(&mut self) {}
+
+    // Practice checkpoints copy the rules along with the rest of the session.
+    fn boxed_clone(&self) -> Box<dyn ScoringRules>;
+}
+
+impl Clone for Box<dyn ScoringRules> {
+    fn clone(&self) -> Self {
+        self.boxed_clone()
+    }
+}
+
+// Fixed value per target.
+#[derive(Clone)]
+pub struct Classic;
+
+impl ScoringRules for Classic {
+    fn award(&mut self, target: ScoreTarget, _tick: u64) -> u32 {
+        target.base_points()
+    }
+
+    fn boxed_clone(&self) -> Box<dyn ScoringRules> {
+        Box::new(self.clone())
+    }
+}
+
+// Kills in quick succession raise a multiplier; a gap longer than COMBO_WINDOW resets it.
+#[derive(Clone)]
+pub struct Combo {
+    last_kill_tick: Option<u64>,
+    multiplier: u32,
+}
+
+impl ScoringRules for Combo {
+    fn award(&mut self, target: ScoreTarget, tick: u64) -> u32 {
+        let chained = self.last_kill_tick.is_some_and(|last| tick - last <= clock::ticks_for(COMBO_WINDOW));
+        self.multiplier = if chained { (self.multiplier + 1).min(COMBO_MAX_MULTIPLIER) } else { 1 };
+        self.last_kill_tick = Some(tick);
+        target.base_points() * self.multiplier
+    }
+
+    fn boxed_clone(&self) -> Box<dyn ScoringRules> {
+        Box::new(self.clone())
+    }
+}
+
+// Kills are worth more the longer the run has lasted.
+#[derive(Clone)]
+pub struct TimeWeighted;
+
+impl ScoringRules for TimeWeighted {
+    fn award(&mut self, target: ScoreTarget, tick: u64) -> u32 {
+        let minutes = tick as f64 / clock::ticks_for(std::time::Duration::from_secs(60)) as f64;
+        (target.base_points() as f64 * (1.0 + minutes * TIME_WEIGHT_BONUS_PER_MINUTE)).round() as u32
+    }
+
+    fn boxed_clone(&self) -> Box<dyn ScoringRules> {
+        Box::new(self.clone())
+    }
+}
+
+// Kills are scaled by the share of shots that have hit something: half value at 0%, 1.5x at 100%.
+#[derive(Clone)]
+pub struct AccuracyWeighted {
+    shots: u32,
+    hits: u32,
+}
+
+impl ScoringRules for AccuracyWeighted {
+    fn award(&mut self, target: ScoreTarget, _tick: u64) -> u32 {
+        let accuracy = if self.shots == 0 { 1.0 } else { (self.hits as f64 / self.shots as f64).min(1.0) };
+        (target.base_points() as f64 * (0.5 + accuracy)).round() as u32
+    }
+
+    fn shot_fired(&mut self) {
+        self.shots += 1;
+    }
+
+    fn shot_landed(&mut self) {
+        self.hits += 1;
+    }
+
+    fn boxed_clone(&self) -> Box<dyn ScoringRules> {
+        Box::new(self.clone())
+    }
+}
+
+// --- ScoringMode: the rule set a run is played under, chosen with --scoring ---
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ScoringMode {
+    #[default]
+    Classic,
+    Combo,
+    TimeWeighted,
+    Accuracy,
+}
+
+pub const SCORING_MODES: [ScoringMode; 4] = [ScoringMode::Classic, ScoringMode::Combo, ScoringMode::TimeWeighted, ScoringMode::Accuracy];
+
+impl ScoringMode {
+    pub fn name(self) -> &'static str {
+        match self {
+            ScoringMode::Classic => "classic",
+            ScoringMode::Combo => "combo",
+            ScoringMode::TimeWeighted => "time",
+            ScoringMode::Accuracy => "accuracy",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        SCORING_MODES.into_iter().find(|mode| mode.name() == name)
+    }
+
+    // Fresh rules for a new run.
+    pub fn rules(self) -> Box<dyn ScoringRules> {
+        match self {
+            ScoringMode::Classic => Box::new(Classic),
+            ScoringMode::Combo => Box::new(Combo { last_kill_tick: None, multiplier: 1 }),
+            ScoringMode::TimeWeighted => Box::new(TimeWeighted),
+            ScoringMode::Accuracy => Box::new(AccuracyWeighted { shots: 0, hits: 0 }),
+        }
+    }
+}
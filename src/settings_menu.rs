@@ -0,0 +1,92 @@
+use crossterm::{event::KeyCode, style::Color};
+
+use crate::config::Config;
+use crate::constants::MAX_SCREEN_SHAKE;
+use crate::rendering::{Corner, GameGrid};
+
+pub const SETTINGS_ITEMS: [&str; 10] =
+    ["Difficulty", "Colour", "Minimap", "Screen shake", "Hit flash", "Outlines", "Stick dead zone", "Stick curve", "Sensitivity", "Back"];
+const SHAKE_LABELS: [&str; 4] = ["off", "low", "medium", "high"];
+
+// Applies a key press to the settings screen, editing `config` in place.
+// Returns the selected row, or None when the player leaves the screen.
+pub fn handle_key(code: KeyCode, selected: usize, config: &mut Config) -> Option<usize> {
+    let item_count = SETTINGS_ITEMS.len();
+    let forward = match code {
+        KeyCode::Up => return Some((selected + item_count - 1) % item_count),
+        KeyCode::Down => return Some((selected + 1) % item_count),
+        KeyCode::Esc | KeyCode::Char('q') => return None,
+        KeyCode::Enter if selected == item_count - 1 => return None,
+        KeyCode::Left => false,
+        KeyCode::Right | KeyCode::Enter | KeyCode::Char(' ') => true,
+        _ => return Some(selected),
+    };
+    match selected {
+        0 => config.difficulty = config.difficulty.step(forward),
+        1 => config.color = !config.color,
+        2 => step_minimap(config, forward),
+        3 if forward => config.screen_shake = (config.screen_shake + 1) % (MAX_SCREEN_SHAKE + 1),
+        3 => config.screen_shake = (config.screen_shake + MAX_SCREEN_SHAKE) % (MAX_SCREEN_SHAKE + 1),
+        4 => config.hit_flash = !config.hit_flash,
+        5 => config.outlines = !config.outlines,
+        6 => config.analog.step_dead_zone(forward),
+        7 => config.analog.curve = config.analog.curve.step(forward),
+        8 => config.analog.step_sensitivity(forward),
+        _ => {}
+    }
+    Some(selected)
+}
+
+// Cycles off, then each corner in turn.
+fn step_minimap(config: &mut Config, forward: bool) {
+    let mut choices = vec![None];
+    choices.extend(Corner::ALL.into_iter().map(Some));
+    let current = if config.minimap { Some(config.minimap_corner) } else { None };
+    let index = choices.iter().position(|&choice| choice == current).unwrap_or(0);
+    let count = choices.len();
+    match choices[if forward { (index + 1) % count } else { (index + count - 1) % count }] {
+        Some(corner) => (config.minimap, config.minimap_corner) = (true, corner),
+        None => config.minimap = false,
+    }
+}
+
+pub fn draw(game_grid: &mut GameGrid, selected: usize, config: &Config) {
+    let box_width: u16 = 35;
+    let box_height = SETTINGS_ITEMS.len() as u16 + 6;
+    let start_x = (game_grid.width / 2).saturating_sub(box_width / 2);
+    let start_y = (game_grid.height / 2).saturating_sub(box_height / 2);
+    let inner_width = box_width as usize - 2;
+    let on_off = |flag: bool| if flag { "on" } else { "off" };
+
+    let mut lines = vec![
+        format!("┌{}┐", "─".repeat(inner_width)),
+        format!("│{:^width$}│", "SETTINGS", width = inner_width),
+        format!("├{}┤", "─".repeat(inner_width)),
+    ];
+    for (i, item) in SETTINGS_ITEMS.iter().enumerate() {
+        let value = match i {
+            0 => config.difficulty.name().to_string(),
+            1 => on_off(config.color).to_string(),
+            2 if config.minimap => config.minimap_corner.name().to_uppercase(),
+            2 => "off".to_string(),
+            3 => SHAKE_LABELS[(config.screen_shake as usize).min(SHAKE_LABELS.len() - 1)].to_string(),
+            4 => on_off(config.hit_flash).to_string(),
+            5 => on_off(config.outlines).to_string(),
+            6 => format!("{}%", config.analog.dead_zone),
+            7 => config.analog.curve.name().to_string(),
+            8 => format!("{}%", config.analog.sensitivity),
+            _ => String::new(),
+        };
+        let marker = if i == selected { '>' } else { ' ' };
+        let row = if value.is_empty() { item.to_string() } else { format!("{:<15}< {:^8} >", item, value) };
+        lines.push(format!("│{} {:<width$}│", marker, row, width = inner_width - 2));
+    }
+    lines.push(format!("├{}┤", "─".repeat(inner_width)));
+    lines.push(format!("│{:^width$}│", "Left/Right change, Esc back", width = inner_width));
+    lines.push(format!("│{:^width$}│", "Difficulty applies next game", width = inner_width));
+    lines.push(format!("└{}┘", "─".repeat(inner_width)));
+
+    for (i, line) in lines.iter().enumerate() {
+        game_grid.write_str(start_x, start_y + i as u16, line, Color::Reset);
+    }
+}
@@ -0,0 +1,126 @@
+use std::f64::consts::TAU;
+
+use crate::clock::ticks_for;
+use crate::constants::*;
+use crate::entities::{Entity, UpdateContext};
+use crate::entity_id::EntityId;
+use crate::rendering::GameGrid;
+use crate::status_effects;
+use crate::types::Vector2D;
+
+// --- Shockwave: the ring a bomb sends out, growing to SHOCKWAVE_RADIUS over SHOCKWAVE_DURATION ---
+// Every asteroid the front reaches is hit once for SHOCKWAVE_DAMAGE; whatever survives is shoved outward.
+// Fragments of an asteroid it broke are left alone, so they scatter instead of being swept up a tick later.
+#[derive(Clone)]
+pub struct Shockwave {
+    pub center: Vector2D,
+    pub owner: usize, // Index of the player who dropped the bomb
+    age: u64,         // Ticks since it went off
+    struck: Vec<EntityId>,
+    pub kills: usize, // Asteroids it has broken, for the multi-kill check
+}
+
+impl Shockwave {
+    pub fn new(center: Vector2D, owner: usize) -> Self {
+        Shockwave { center, owner, age: 0, struck: Vec::new(), kills: 0 }
+    }
+
+    // In rows, with columns squashed to match.
+    pub fn radius(&self) -> f64 {
+        SHOCKWAVE_RADIUS * (self.age as f64 / ticks_for(SHOCKWAVE_DURATION) as f64).min(1.0)
+    }
+
+    // Whether the front has reached `position` and the asteroid there hasn't been hit yet. Marks it hit if so.
+    pub fn strike(&mut self, id: EntityId, parent: Option<EntityId>, position: Vector2D) -> bool {
+        if self.struck.contains(&id) || parent.is_some_and(|parent| self.struck.contains(&parent)) {
+            return false;
+        }
+        if !status_effects::within(position, self.center, self.radius()) {
+            return false;
+        }
+        self.struck.push(id);
+        true
+    }
+
+    // Unit push away from the centre for something at `position`, in screen cells.
+    pub fn push_direction(&self, position: Vector2D) -> Vector2D {
+        let dx = (position.x - self.center.x) / TERMINAL_ASPECT_RATIO_COMPENSATION;
+        let dy = position.y - self.center.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance == 0.0 {
+            return Vector2D::new(0.0, -1.0);
+        }
+        Vector2D::new(dx / distance * TERMINAL_ASPECT_RATIO_COMPENSATION, dy / distance)
+    }
+}
+
+impl Entity for Shockwave {
+    fn update(&mut self, _ctx: &UpdateContext) {
+        self.age += 1;
+    }
+
+    fn draw(&self, game_grid: &mut GameGrid) {
+        for (x, y) in self.coords() {
+            game_grid.set_cell(x, y, SHOCKWAVE_CHAR, SHOCKWAVE_COLOR);
+        }
+    }
+
+    // The ring itself, one cell per step of its circumference.
+    fn coords(&self) -> Vec<(u16, u16)> {
+        let radius = self.radius();
+        let steps = ((TAU * radius * TERMINAL_ASPECT_RATIO_COMPENSATION).ceil() as usize).max(1);
+        let mut cells = Vec::new();
+        for step in 0..steps {
+            let angle = TAU * step as f64 / steps as f64;
+            let x = (self.center.x + angle.cos() * radius * TERMINAL_ASPECT_RATIO_COMPENSATION).round();
+            let y = (self.center.y + angle.sin() * radius).round();
+            if x >= 0.0 && y >= 0.0 && !cells.contains(&(x as u16, y as u16)) {
+                cells.push((x as u16, y as u16));
+            }
+        }
+        cells
+    }
+
+    fn is_alive(&self) -> bool {
+        self.age < ticks_for(SHOCKWAVE_DURATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::tick_seconds;
+    use crate::entity_id::EntityAllocator;
+
+    fn step(shockwave: &mut Shockwave, ticks: u64) {
+        for _ in 0..ticks {
+            shockwave.update(&UpdateContext { dt: tick_seconds(), world_width: 120, world_height: 40 });
+        }
+    }
+
+    #[test]
+    fn radius_grows_to_full_size_then_the_wave_dies_out() {
+        let mut shockwave = Shockwave::new(Vector2D::new(60.0, 20.0), 0);
+        assert_eq!(shockwave.radius(), 0.0);
+        step(&mut shockwave, ticks_for(SHOCKWAVE_DURATION) / 2);
+        assert_eq!(shockwave.radius(), SHOCKWAVE_RADIUS / 2.0);
+        assert!(shockwave.is_alive());
+        step(&mut shockwave, ticks_for(SHOCKWAVE_DURATION) / 2);
+        assert_eq!(shockwave.radius(), SHOCKWAVE_RADIUS);
+        assert!(!shockwave.is_alive());
+    }
+
+    #[test]
+    fn strikes_each_asteroid_once_and_spares_its_fragments() {
+        let mut ids = EntityAllocator::new();
+        let (asteroid, fragment) = (ids.allocate(), ids.allocate());
+        let mut shockwave = Shockwave::new(Vector2D::new(60.0, 20.0), 0);
+        let position = Vector2D::new(60.0, 25.0); // Five rows below
+        step(&mut shockwave, 1);
+        assert!(!shockwave.strike(asteroid, None, position), "the front hasn't reached it yet");
+        step(&mut shockwave, ticks_for(SHOCKWAVE_DURATION) / 2);
+        assert!(shockwave.strike(asteroid, None, position));
+        assert!(!shockwave.strike(asteroid, None, position));
+        assert!(!shockwave.strike(fragment, Some(asteroid), position));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::constants::TERMINAL_ASPECT_RATIO_COMPENSATION;
+use crate::entities::Asteroid;
+use crate::types::Vector2D;
+
+// --- SpatialHash for broad-phase queries over the playfield ---
+pub struct SpatialHash {
+    cell_width: u16,
+    cell_height: u16,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl SpatialHash {
+    pub fn new(width: u16, height: u16, cell_width: u16, cell_height: u16) -> Self {
+        let columns = (width as usize).div_ceil(cell_width as usize).max(1);
+        let rows = (height as usize).div_ceil(cell_height as usize).max(1);
+        SpatialHash {
+            cell_width,
+            cell_height,
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+    }
+
+    pub fn cell_of(&self, position: Vector2D) -> (usize, usize) {
+        let column = (position.x.max(0.0) as usize / self.cell_width as usize).min(self.columns - 1);
+        let row = (position.y.max(0.0) as usize / self.cell_height as usize).min(self.rows - 1);
+        (column, row)
+    }
+
+    pub fn insert(&mut self, index: usize, position: Vector2D) {
+        let (column, row) = self.cell_of(position);
+        self.cells[row * self.columns + column].push(index);
+    }
+
+    pub fn indices_at(&self, column: usize, row: usize) -> &[usize] {
+        &self.cells[row * self.columns + column]
+    }
+
+    pub fn count(&self, column: usize, row: usize) -> usize {
+        self.indices_at(column, row).len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cell_width(&self) -> u16 {
+        self.cell_width
+    }
+
+    pub fn cell_height(&self) -> u16 {
+        self.cell_height
+    }
+}
+
+// Distance in rows to the closest asteroid, compensating for tall terminal cells.
+pub fn clearance(position: Vector2D, asteroids: &[Asteroid]) -> f64 {
+    asteroids.iter().map(|asteroid| {
+        let dx = (asteroid.position.x - position.x) / TERMINAL_ASPECT_RATIO_COMPENSATION;
+        let dy = asteroid.position.y - position.y;
+        (dx * dx + dy * dy).sqrt()
+    }).fold(f64::INFINITY, f64::min)
+}
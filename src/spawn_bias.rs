@@ -0,0 +1,74 @@
+use rand::Rng;
+
+use crate::constants::SPAWN_BIAS_BY_WAVE;
+use crate::formations::SpawnOrder;
+use crate::spatial::SpatialHash;
+use crate::types::Vector2D;
+
+// Quadrants are numbered 0 top-left, 1 top-right, 2 bottom-left, 3 bottom-right.
+fn quadrant_of(position: Vector2D, width: u16, height: u16) -> usize {
+    let right = position.x >= width as f64 / 2.0;
+    let bottom = position.y >= height as f64 / 2.0;
+    right as usize + 2 * bottom as usize
+}
+
+// Asteroids per quadrant, read from the broad-phase buckets.
+fn quadrant_densities(density: &SpatialHash, width: u16, height: u16) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for row in 0..density.rows() {
+        for column in 0..density.columns() {
+            let center = Vector2D::new(
+                (column as f64 + 0.5) * density.cell_width() as f64,
+                (row as f64 + 0.5) * density.cell_height() as f64,
+            );
+            counts[quadrant_of(center, width, height)] += density.count(column, row);
+        }
+    }
+    counts
+}
+
+// Chance of moving a formation planned in the ship's quadrant; easy waves get the most protection.
+pub fn strength(wave: usize) -> f64 {
+    SPAWN_BIAS_BY_WAVE[wave.saturating_sub(1).min(SPAWN_BIAS_BY_WAVE.len() - 1)]
+}
+
+// Mirrors a formation planned on top of the ship into the least crowded other quadrant.
+pub fn rebalance(
+    orders: &mut [SpawnOrder],
+    ship_position: Vector2D,
+    density: &SpatialHash,
+    strength: f64,
+    width: u16,
+    height: u16,
+    rng: &mut impl Rng,
+) {
+    if orders.is_empty() {
+        return;
+    }
+    let count = orders.len() as f64;
+    let centroid = Vector2D::new(
+        orders.iter().map(|order| order.position.x).sum::<f64>() / count,
+        orders.iter().map(|order| order.position.y).sum::<f64>() / count,
+    );
+    let ship_quadrant = quadrant_of(ship_position, width, height);
+    if quadrant_of(centroid, width, height) != ship_quadrant || !rng.gen_bool(strength.clamp(0.0, 1.0)) {
+        return;
+    }
+
+    let densities = quadrant_densities(density, width, height);
+    let Some(target) = (0..4).filter(|&quadrant| quadrant != ship_quadrant).min_by_key(|&quadrant| densities[quadrant]) else {
+        return;
+    };
+    let flip_x = (target & 1) != (ship_quadrant & 1);
+    let flip_y = (target & 2) != (ship_quadrant & 2);
+    for order in orders.iter_mut() {
+        if flip_x {
+            order.position.x = (width as f64 - 1.0 - order.position.x).max(0.0);
+            order.heading = order.heading.map(|heading| std::f64::consts::PI - heading);
+        }
+        if flip_y {
+            order.position.y = (height as f64 - 1.0 - order.position.y).max(0.0);
+            order.heading = order.heading.map(|heading| -heading);
+        }
+    }
+}
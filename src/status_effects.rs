@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::clock::ticks_for;
+use crate::constants::*;
+use crate::rendering::GameGrid;
+use crate::types::Vector2D;
+
+// Lingering conditions an entity can be put under. New content adds a variant here and says what it does in
+// `StatusEffects`; the effect lists, ticking and indicators come for free.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusKind {
+    Burning, // Takes a point of damage every BURN_DAMAGE_INTERVAL
+    Slowed,  // Moves and thrusts at SLOWED_SPEED_FACTOR
+    Stunned, // Can't move or fire
+}
+
+impl StatusKind {
+    // The glyph drawn over an entity while it is affected.
+    pub fn indicator(self) -> (char, Color) {
+        match self {
+            StatusKind::Burning => ('!', BURNING_COLOR),
+            StatusKind::Slowed => ('~', SLOWED_COLOR),
+            StatusKind::Stunned => ('?', STUNNED_COLOR),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub ticks_left: u64,
+    pub age: u64, // Ticks since it was applied, which times damage over time
+}
+
+// --- StatusEffects: the effects one entity is under, counted down in simulation ticks ---
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        StatusEffects { effects: Vec::new() }
+    }
+
+    // Reapplying an effect the entity already has extends it rather than stacking a second copy.
+    pub fn apply(&mut self, kind: StatusKind, duration: Duration) {
+        let ticks = ticks_for(duration);
+        match self.effects.iter_mut().find(|effect| effect.kind == kind) {
+            Some(effect) => effect.ticks_left = effect.ticks_left.max(ticks),
+            None => self.effects.push(StatusEffect { kind, ticks_left: ticks, age: 0 }),
+        }
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    // Advances every effect by one tick and drops the ones that ran out. Returns the damage dealt this tick.
+    pub fn tick(&mut self) -> u32 {
+        let mut damage = 0;
+        for effect in &mut self.effects {
+            effect.age += 1;
+            effect.ticks_left = effect.ticks_left.saturating_sub(1);
+            if effect.kind == StatusKind::Burning && effect.age.is_multiple_of(ticks_for(BURN_DAMAGE_INTERVAL)) {
+                damage += 1;
+            }
+        }
+        self.effects.retain(|effect| effect.ticks_left > 0);
+        damage
+    }
+
+    // Multiplier on how far the entity moves and how hard it thrusts.
+    pub fn speed_factor(&self) -> f64 {
+        if self.has(StatusKind::Stunned) {
+            0.0
+        } else if self.has(StatusKind::Slowed) {
+            SLOWED_SPEED_FACTOR
+        } else {
+            1.0
+        }
+    }
+
+    // Shows the most recently applied effect just above `position`.
+    pub fn draw_indicator(&self, game_grid: &mut GameGrid, position: Vector2D) {
+        if let Some(effect) = self.effects.last() {
+            let (glyph, color) = effect.kind.indicator();
+            game_grid.set_cell(position.x.round() as u16, (position.y - 2.0).round() as u16, glyph, color);
+        }
+    }
+}
+
+impl Default for StatusEffects {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Whether `point` is within `radius` of `center`, measured in rows with columns squashed to match.
+pub fn within(point: Vector2D, center: Vector2D, radius: f64) -> bool {
+    let dx = (point.x - center.x) / TERMINAL_ASPECT_RATIO_COMPENSATION;
+    let dy = point.y - center.y;
+    dx * dx + dy * dy <= radius * radius
+}
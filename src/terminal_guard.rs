@@ -0,0 +1,117 @@
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    style::ResetColor,
+    terminal::{disable_raw_mode, enable_raw_mode},
+    QueueableCommand,
+};
+use log::{error, info};
+
+// Set while the terminal is in raw mode with the cursor hidden; whoever clears it does the restoring.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+// Set when the terminal agreed to report key releases (kitty keyboard protocol), which has to be undone too.
+static KEY_RELEASES: AtomicBool = AtomicBool::new(false);
+
+// --- TerminalGuard: raw mode, a hidden cursor and key release reports for as long as it lives ---
+// Created once in main for interactive runs. Dropping it (including when main returns an error), a panic,
+// `restore()` and `exit()` all undo the terminal changes, and only the first of them does any work.
+pub struct TerminalGuard {
+    _private: (),
+}
+
+impl TerminalGuard {
+    pub fn acquire() -> io::Result<Self> {
+        enable_raw_mode().map_err(|e| { error!("Failed to enable raw mode: {}", e); e })?;
+        ACTIVE.store(true, Ordering::SeqCst);
+        let guard = TerminalGuard { _private: () };
+        let mut stdout = io::stdout();
+        stdout.queue(Hide)?;
+        stdout.flush()?;
+        install_panic_hook();
+        #[cfg(unix)]
+        watch_signals();
+        info!("Raw mode enabled and cursor hidden.");
+        Ok(guard)
+    }
+
+    // Asks for press/release reports; only for terminals onboarding found support them (`Config::key_release`).
+    // `every_key` also has modifier keys reported on their own, for co-op's Left Shift fire button.
+    pub fn enable_key_releases(&self, every_key: bool) -> io::Result<()> {
+        let mut flags = KeyboardEnhancementFlags::REPORT_EVENT_TYPES;
+        if every_key {
+            flags |= KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES;
+        }
+        let mut stdout = io::stdout();
+        stdout.queue(PushKeyboardEnhancementFlags(flags))?;
+        stdout.flush()?;
+        KEY_RELEASES.store(true, Ordering::SeqCst);
+        info!("Key release reporting enabled.");
+        Ok(())
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+// Puts the terminal back the way the shell expects it. Safe to call any number of times, from any thread.
+pub fn restore() {
+    if !ACTIVE.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    let mut stdout = io::stdout();
+    if KEY_RELEASES.swap(false, Ordering::SeqCst) {
+        let _ = stdout.queue(PopKeyboardEnhancementFlags);
+    }
+    let _ = stdout.queue(ResetColor).and_then(|stdout| stdout.queue(Show)).and_then(|stdout| stdout.flush());
+    let _ = disable_raw_mode();
+    info!("Terminal restored.");
+}
+
+// Whether key events come with a press/release kind, so held keys can be tracked.
+pub fn reports_key_releases() -> bool {
+    KEY_RELEASES.load(Ordering::SeqCst)
+}
+
+// `std::process::exit` skips destructors, so every early exit goes through here instead.
+pub fn exit(code: i32) -> ! {
+    restore();
+    std::process::exit(code);
+}
+
+// A panic restores the terminal before the default hook prints the message, so it isn't mangled by raw mode.
+// This also covers the `minimal` profile, where panics abort and the guard is never dropped.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        restore();
+        error!("Panic: {}", panic_info);
+        default_hook(panic_info);
+    }));
+}
+
+// Raw mode turns Ctrl+C into a key press, but SIGTERM and SIGHUP (closed window, dropped SSH session) still
+// arrive as signals. Restoring from the signal handler itself isn't async-signal-safe, so a thread waits for them.
+#[cfg(unix)]
+fn watch_signals() {
+    use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    let mut signals = match Signals::new([SIGINT, SIGTERM, SIGHUP]) {
+        Ok(signals) => signals,
+        Err(e) => {
+            error!("Failed to install signal handlers: {}", e);
+            return;
+        }
+    };
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            info!("Received signal {}; exiting.", signal);
+            exit(128 + signal);
+        }
+    });
+}
@@ -1,28 +1,48 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::io;
 use crossterm::event::{Event, KeyCode};
 
+// Named debug resolutions for exercising layouts without resizing a real terminal.
+pub const DEBUG_PRESETS: [(&str, u16, u16); 3] = [
+    ("tiny", 20, 8),   // Smaller than the HUD: everything must clip rather than panic
+    ("phone", 48, 20), // Narrow portrait-ish terminal app
+    ("wide", 200, 50), // Ultrawide monitor
+];
+
+pub fn debug_preset(name: &str) -> Option<(u16, u16)> {
+    DEBUG_PRESETS.iter().find(|(preset, _, _)| *preset == name).map(|&(_, width, height)| (width, height))
+}
+
 // --- SimulatedInput for debugging ---
 pub struct SimulatedInput {
-    events: HashMap<u64, Event>,
+    events: HashMap<u64, VecDeque<Event>>, // Frame -> events delivered during it, in order
     current_frame: u64,
 }
 
 impl SimulatedInput {
     pub fn new(events: HashMap<u64, Event>) -> Self {
-        SimulatedInput { events, current_frame: 0 }
+        Self::from_events(events)
+    }
+
+    // Unlike `new`, several events may share a frame (replays of live runs have them).
+    pub fn from_events(events: impl IntoIterator<Item = (u64, Event)>) -> Self {
+        let mut by_frame: HashMap<u64, VecDeque<Event>> = HashMap::new();
+        for (frame, event) in events {
+            by_frame.entry(frame).or_default().push_back(event);
+        }
+        SimulatedInput { events: by_frame, current_frame: 0 }
     }
 
     pub fn poll(&mut self, frame_count: u64) -> io::Result<bool> {
         self.current_frame = frame_count;
-        Ok(self.events.contains_key(&frame_count))
+        Ok(self.events.get(&frame_count).is_some_and(|events| !events.is_empty()))
     }
 
     pub fn read(&mut self) -> io::Result<Event> {
-        if let Some(event) = self.events.remove(&self.current_frame) {
+        if let Some(event) = self.events.get_mut(&self.current_frame).and_then(VecDeque::pop_front) {
             Ok(event)
         } else {
             Ok(Event::Key(KeyCode::Null.into()))
         }
     }
-}
\ No newline at end of file
+}
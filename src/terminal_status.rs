@@ -0,0 +1,56 @@
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+use crossterm::{terminal::SetTitle, Command};
+
+use crate::rendering::OutputTarget;
+
+const STATUS_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+// --- OSC 9;4 taskbar/tab progress (ConEmu, Windows Terminal, WezTerm; ignored elsewhere) ---
+pub struct SetProgress(pub Option<u8>);
+
+impl Command for SetProgress {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        match self.0 {
+            Some(percent) => write!(f, "\x1b]9;4;1;{}\x07", percent.min(100)),
+            None => write!(f, "\x1b]9;4;0;0\x07"),
+        }
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// --- TerminalStatus mirrors score and progress into the window title once a second ---
+pub struct TerminalStatus {
+    last_update: Option<Instant>,
+}
+
+impl TerminalStatus {
+    pub fn new() -> Self {
+        TerminalStatus { last_update: None }
+    }
+
+    pub fn update(&mut self, stdout: &mut OutputTarget, score: u32, level: usize, progress_percent: u8) -> io::Result<()> {
+        if self.last_update.is_some_and(|last| last.elapsed() < STATUS_UPDATE_INTERVAL) {
+            return Ok(());
+        }
+        self.last_update = Some(Instant::now());
+        stdout.execute_other_command(SetTitle(format!("Vibe-asteroid | Score {} | Level {}", score, level)))?;
+        stdout.execute_other_command(SetProgress(Some(progress_percent)))
+    }
+
+    pub fn clear(&self, stdout: &mut OutputTarget) -> io::Result<()> {
+        stdout.execute_other_command(SetTitle("Vibe-asteroid"))?;
+        stdout.execute_other_command(SetProgress(None))
+    }
+}
+
+impl Default for TerminalStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,35 @@
+// --- TimeScale stretches simulation time for slow-motion effects ---
+pub struct TimeScale {
+    scale: f64,
+    until_tick: u64,
+    accumulator: f64,
+}
+
+impl TimeScale {
+    pub fn new() -> Self {
+        TimeScale { scale: 1.0, until_tick: 0, accumulator: 0.0 }
+    }
+
+    pub fn slow_down(&mut self, scale: f64, tick: u64, duration_ticks: u64) {
+        self.scale = scale;
+        self.until_tick = tick + duration_ticks;
+    }
+
+    pub fn scale(&self, tick: u64) -> f64 {
+        if tick < self.until_tick { self.scale } else { 1.0 }
+    }
+
+    // Number of simulation steps to run this frame; fractional scales accumulate across frames.
+    pub fn steps_for_frame(&mut self, tick: u64) -> u32 {
+        self.accumulator += self.scale(tick);
+        let steps = self.accumulator.floor();
+        self.accumulator -= steps;
+        steps as u32
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
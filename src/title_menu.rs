@@ -0,0 +1,129 @@
+use crossterm::{event::KeyCode, style::Color};
+
+use crate::config::Config;
+use crate::high_scores::HighScores;
+use crate::loadout::Loadout;
+use crate::rendering::GameGrid;
+use crate::settings_menu;
+
+pub const TITLE_MENU_ITEMS: [&str; 5] = ["Start Game", "High Scores", "Settings", "About", "Quit"];
+
+const TITLE_ART: [&str; 6] = [
+    r"VIBE-ASTEROID",
+    r" _   _ _____ _____ ____  _____ ____  _   _ ____  _",
+    r"| | | | ____|_   _|  _ \| ____|  _ \| | | |  _ \| |",
+    r"| |_| |  _|   | | | |_) |  _| | |_) | |_| | |_) | |",
+    r"|  _  | |___  | | |  _ <| |___|  _ <|  _  |  _ <| |",
+    r"|_| |_|_____| |_| |_| \|_____|_| \|_| |_|_| \|_|",
+];
+
+// --- TitleScreen: where the player is on the title screen ---
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TitleScreen {
+    Menu { selected: usize }, // Index into the title menu
+    Loadout { selected: usize }, // After Start Game, when the profile has presets: 0 is the standard ship
+    HighScores,
+    Settings { selected: usize }, // Index into the settings list
+    About, // The scrolling credits, which run their own loop (`Game::show_credits`)
+    Start,
+    Quit,
+}
+
+// Applies a key press to the title screen and returns where it leads. Settings edit `config` in place.
+// `loadout_count` is how many presets the profile has; with none, Start Game launches straight away.
+pub fn handle_key(code: KeyCode, screen: TitleScreen, config: &mut Config, loadout_count: usize) -> TitleScreen {
+    let item_count = TITLE_MENU_ITEMS.len();
+    match screen {
+        TitleScreen::Menu { selected } => match code {
+            KeyCode::Up => TitleScreen::Menu { selected: (selected + item_count - 1) % item_count },
+            KeyCode::Down => TitleScreen::Menu { selected: (selected + 1) % item_count },
+            KeyCode::Char('q') | KeyCode::Esc => TitleScreen::Quit,
+            KeyCode::Enter | KeyCode::Char(' ') => match selected {
+                0 if loadout_count > 0 => TitleScreen::Loadout { selected: 0 },
+                0 => TitleScreen::Start,
+                1 => TitleScreen::HighScores,
+                2 => TitleScreen::Settings { selected: 0 },
+                3 => TitleScreen::About,
+                _ => TitleScreen::Quit,
+            },
+            _ => screen,
+        },
+        TitleScreen::Loadout { selected } => {
+            let choices = loadout_count + 1;
+            match code {
+                KeyCode::Up => TitleScreen::Loadout { selected: (selected + choices - 1) % choices },
+                KeyCode::Down => TitleScreen::Loadout { selected: (selected + 1) % choices },
+                KeyCode::Enter | KeyCode::Char(' ') => TitleScreen::Start,
+                KeyCode::Char('q') | KeyCode::Esc => TitleScreen::Menu { selected: 0 },
+                _ => screen,
+            }
+        }
+        // The high score table is read-only: any key goes back to the menu
+        TitleScreen::HighScores => TitleScreen::Menu { selected: 1 },
+        TitleScreen::Settings { selected } => match settings_menu::handle_key(code, selected, config) {
+            Some(selected) => TitleScreen::Settings { selected },
+            None => TitleScreen::Menu { selected: 2 },
+        },
+        TitleScreen::About | TitleScreen::Start | TitleScreen::Quit => screen,
+    }
+}
+
+pub fn draw(game_grid: &mut GameGrid, screen: TitleScreen, high_scores: &HighScores, config: &Config, loadouts: &[Loadout]) {
+    if let TitleScreen::Settings { selected } = screen {
+        settings_menu::draw(game_grid, selected, config);
+        return;
+    }
+    let art_y = (game_grid.height / 2).saturating_sub(TITLE_ART.len() as u16 + 2);
+    for (i, line) in TITLE_ART.iter().enumerate() {
+        write_centered(game_grid, art_y + i as u16, line);
+    }
+
+    let mut lines = Vec::new();
+    match screen {
+        TitleScreen::Menu { selected } => {
+            for (i, item) in TITLE_MENU_ITEMS.iter().enumerate() {
+                lines.push(if i == selected { format!("> {} <", item) } else { item.to_string() });
+            }
+            lines.push(String::new());
+            lines.push("Up/Down to choose, Enter to select".to_string());
+        }
+        TitleScreen::Loadout { selected } => {
+            lines.push("CHOOSE A LOADOUT".to_string());
+            lines.push(String::new());
+            let names = std::iter::once("Standard").chain(loadouts.iter().map(|loadout| loadout.name.as_str()));
+            for (i, name) in names.enumerate() {
+                lines.push(if i == selected { format!("> {} <", name) } else { name.to_string() });
+            }
+            lines.push(String::new());
+            match selected.checked_sub(1).and_then(|index| loadouts.get(index)) {
+                Some(loadout) => lines.extend(loadout.lines()),
+                None => lines.extend(["No upgrades fitted".to_string(), "Every upgrade drops as often".to_string()]),
+            }
+            lines.push(String::new());
+            lines.push("Up/Down to choose, Enter to launch, Esc to go back".to_string());
+        }
+        TitleScreen::HighScores => {
+            lines.push("HIGH SCORES".to_string());
+            lines.push(String::new());
+            if high_scores.entries.is_empty() {
+                lines.push("No runs recorded yet".to_string());
+            }
+            for (i, &(score, seed, assisted)) in high_scores.entries.iter().enumerate() {
+                lines.push(format!("{:>2}. {:>8}  seed {:<20}  {:<6}", i + 1, score, seed, if assisted { "ASSIST" } else { "" }));
+            }
+            lines.push(String::new());
+            lines.push("Press any key to go back".to_string());
+        }
+        TitleScreen::Settings { .. } | TitleScreen::About | TitleScreen::Start | TitleScreen::Quit => {}
+    }
+
+    let start_y = art_y + TITLE_ART.len() as u16 + 2;
+    for (i, line) in lines.iter().enumerate() {
+        write_centered(game_grid, start_y + i as u16, line);
+    }
+}
+
+fn write_centered(game_grid: &mut GameGrid, y: u16, text: &str) {
+    let x = (game_grid.width / 2).saturating_sub(text.chars().count() as u16 / 2);
+    game_grid.write_str(x, y, text, Color::Reset);
+}
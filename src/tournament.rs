@@ -0,0 +1,60 @@
+use std::fs;
+use std::io;
+
+use crate::replay::fnv1a;
+
+const RULESET_HEADER: &str = "vibe-asteroid-ruleset 1";
+
+// --- Ruleset: the conditions every entrant of a tournament plays under ---
+// Loaded from a small text file published by the organisers:
+//
+//     vibe-asteroid-ruleset 1
+//     seed 12345
+//     idle-decay true
+//
+// Assist settings are always off. The hash also covers the game version, so entrants on a
+// different build get a different hash.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ruleset {
+    pub seed: u64,
+    pub idle_decay: bool,
+}
+
+impl Ruleset {
+    pub fn load(path: &str) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines();
+        if lines.next() != Some(RULESET_HEADER) {
+            return Err(invalid_ruleset("missing ruleset header"));
+        }
+
+        let mut seed = None;
+        let mut idle_decay = false;
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["seed", value] => seed = Some(value.parse().map_err(|_| invalid_ruleset("malformed seed"))?),
+                ["idle-decay", value] => idle_decay = value.parse().map_err(|_| invalid_ruleset("malformed idle-decay"))?,
+                _ => return Err(invalid_ruleset("unrecognised ruleset line")),
+            }
+        }
+        let seed = seed.ok_or_else(|| invalid_ruleset("ruleset has no seed"))?;
+        Ok(Ruleset { seed, idle_decay })
+    }
+
+    pub fn hash(&self) -> u64 {
+        let canonical = format!(
+            "{}\nversion {}\nseed {}\nidle-decay {}\n",
+            RULESET_HEADER,
+            env!("CARGO_PKG_VERSION"),
+            self.seed,
+            self.idle_decay,
+        );
+        fnv1a(canonical.as_bytes())
+    }
+}
+
+fn invalid_ruleset(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
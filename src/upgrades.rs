@@ -1,48 +1,251 @@
+use rand::Rng;
+
+use crate::active_effects::PowerUp;
+use crate::constants::{LOADOUT_FAVOUR_WEIGHT, POWER_UP_COLOR, UPGRADE_COLOR};
 use crate::types::Vector2D;
 use crate::rendering::GameGrid;
+use crate::entity_id::{EntityId, Identified};
+use crate::entities::Entity;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum UpgradeType {
     // Beam Upgrades
     FireRate,
     BulletSpeed,
     BulletSize,
+    CritChance,
+    Range,
+    Piercing,
+    // Weapon Upgrades
+    TripleShot,
+    RearGun,
+    RadialBurst,
     // Ship Upgrades
     Booster,
     Shield,
     ShipSize,
+    EnergyCapacity,
     // Health Upgrades
     Health,
     HealthMax,
+    // Timed Power-ups
+    RapidFire,
+    Invincibility,
+    TimeSlow,
+}
+
+impl UpgradeType {
+    pub const ALL: [UpgradeType; 18] = [
+        UpgradeType::FireRate,
+        UpgradeType::BulletSpeed,
+        UpgradeType::BulletSize,
+        UpgradeType::CritChance,
+        UpgradeType::Range,
+        UpgradeType::Piercing,
+        UpgradeType::TripleShot,
+        UpgradeType::RearGun,
+        UpgradeType::RadialBurst,
+        UpgradeType::Booster,
+        UpgradeType::Shield,
+        UpgradeType::ShipSize,
+        UpgradeType::EnergyCapacity,
+        UpgradeType::Health,
+        UpgradeType::HealthMax,
+        UpgradeType::RapidFire,
+        UpgradeType::Invincibility,
+        UpgradeType::TimeSlow,
+    ];
+
+    // Every kind is equally likely.
+    pub fn random(rng: &mut impl Rng) -> Self {
+        match rng.gen_range(0..18) {
+            0 => UpgradeType::FireRate,
+            1 => UpgradeType::BulletSpeed,
+            2 => UpgradeType::BulletSize,
+            3 => UpgradeType::CritChance,
+            4 => UpgradeType::Range,
+            5 => UpgradeType::Booster,
+            6 => UpgradeType::Shield,
+            7 => UpgradeType::ShipSize,
+            8 => UpgradeType::Health,
+            9 => UpgradeType::EnergyCapacity,
+            10 => UpgradeType::Piercing,
+            11 => UpgradeType::TripleShot,
+            12 => UpgradeType::RearGun,
+            13 => UpgradeType::RadialBurst,
+            14 => UpgradeType::RapidFire,
+            15 => UpgradeType::Invincibility,
+            16 => UpgradeType::TimeSlow,
+            _ => UpgradeType::HealthMax,
+        }
+    }
+
+    // A drop leaning toward `favoured` (a loadout's picks), each LOADOUT_FAVOUR_WEIGHT times as likely as the
+    // rest, and never one of `excluded` (weapons every ship already has, say). With nothing favoured or excluded
+    // this is `random`, so runs without a loadout roll exactly as before.
+    pub fn weighted(rng: &mut impl Rng, favoured: &[UpgradeType], excluded: &[UpgradeType]) -> Self {
+        if favoured.is_empty() && excluded.is_empty() {
+            return Self::random(rng);
+        }
+        let weight = |upgrade_type: &UpgradeType| match (excluded.contains(upgrade_type), favoured.contains(upgrade_type)) {
+            (true, _) => 0,
+            (false, true) => LOADOUT_FAVOUR_WEIGHT,
+            (false, false) => 1,
+        };
+        let mut roll = rng.gen_range(0..Self::ALL.iter().map(weight).sum::<u32>());
+        for upgrade_type in Self::ALL {
+            if roll < weight(&upgrade_type) {
+                return upgrade_type;
+            }
+            roll -= weight(&upgrade_type);
+        }
+        UpgradeType::HealthMax
+    }
+
+    // Name used in loadout presets and replay files.
+    pub fn key(&self) -> &'static str {
+        match self {
+            UpgradeType::FireRate => "fire-rate",
+            UpgradeType::BulletSpeed => "shot-speed",
+            UpgradeType::BulletSize => "shot-size",
+            UpgradeType::CritChance => "crit",
+            UpgradeType::Range => "range",
+            UpgradeType::Piercing => "pierce",
+            UpgradeType::TripleShot => "triple-shot",
+            UpgradeType::RearGun => "rear-gun",
+            UpgradeType::RadialBurst => "radial-burst",
+            UpgradeType::Booster => "booster",
+            UpgradeType::Shield => "shield",
+            UpgradeType::ShipSize => "ship-size",
+            UpgradeType::EnergyCapacity => "energy-cap",
+            UpgradeType::Health => "health",
+            UpgradeType::HealthMax => "max-health",
+            UpgradeType::RapidFire => "rapid-fire",
+            UpgradeType::Invincibility => "invincibility",
+            UpgradeType::TimeSlow => "time-slow",
+        }
+    }
+
+    pub fn parse(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|upgrade_type| upgrade_type.key() == key)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpgradeType::FireRate => "Fire rate",
+            UpgradeType::BulletSpeed => "Shot speed",
+            UpgradeType::BulletSize => "Shot size",
+            UpgradeType::CritChance => "Crit",
+            UpgradeType::Range => "Range",
+            UpgradeType::Piercing => "Piercing",
+            UpgradeType::TripleShot => "Triple shot",
+            UpgradeType::RearGun => "Rear gun",
+            UpgradeType::RadialBurst => "Radial burst",
+            UpgradeType::Booster => "Booster",
+            UpgradeType::Shield => "Shield",
+            UpgradeType::ShipSize => "Ship size",
+            UpgradeType::EnergyCapacity => "Energy cap",
+            UpgradeType::Health => "Health",
+            UpgradeType::HealthMax => "Max health",
+            UpgradeType::RapidFire => "Rapid fire",
+            UpgradeType::Invincibility => "Invincibility",
+            UpgradeType::TimeSlow => "Time slow",
+        }
+    }
+
+    // Whether the ship keeps it: stat upgrades stay on the ship (and can be jettisoned again), while shields and
+    // health are used up and power-ups wear off.
+    pub fn is_kept(&self) -> bool {
+        !matches!(self, UpgradeType::Shield | UpgradeType::Health | UpgradeType::HealthMax) && self.power_up().is_none()
+    }
+
+    // The timed power-up this pickup starts, if it is one.
+    pub fn power_up(&self) -> Option<PowerUp> {
+        match self {
+            UpgradeType::RapidFire => Some(PowerUp::RapidFire),
+            UpgradeType::Invincibility => Some(PowerUp::Invincibility),
+            UpgradeType::TimeSlow => Some(PowerUp::TimeSlow),
+            _ => None,
+        }
+    }
+
+    pub fn pickup_banner(&self) -> &'static str {
+        match self {
+            UpgradeType::FireRate => "Fire Rate Increased!",
+            UpgradeType::BulletSpeed => "Bullet Speed Increased!",
+            UpgradeType::BulletSize => "Bullet Size Increased!",
+            UpgradeType::CritChance => "Crit Chance Increased!",
+            UpgradeType::Range => "Bullet Range Increased!",
+            UpgradeType::Piercing => "Piercing Shots!",
+            UpgradeType::TripleShot => "Triple Shot!",
+            UpgradeType::RearGun => "Rear Gun Mounted!",
+            UpgradeType::RadialBurst => "Radial Burst!",
+            UpgradeType::Booster => "Booster Power Increased!",
+            UpgradeType::Shield => "Shield Added!",
+            UpgradeType::ShipSize => "Ship Size Increased!",
+            UpgradeType::EnergyCapacity => "Energy Capacity Increased!",
+            UpgradeType::Health => "Health Restored!",
+            UpgradeType::HealthMax => "Health Maxed!",
+            UpgradeType::RapidFire => "Rapid Fire!",
+            UpgradeType::Invincibility => "Invincible!",
+            UpgradeType::TimeSlow => "Time Slowed!",
+        }
+    }
+
+    // Letter shown for the pickup and next to the upgrade in the HUD: Beam, Ship, Energy, Health or Power-up.
+    pub fn icon(&self) -> char {
+        match self {
+            UpgradeType::FireRate
+            | UpgradeType::BulletSpeed
+            | UpgradeType::BulletSize
+            | UpgradeType::CritChance
+            | UpgradeType::Range
+            | UpgradeType::Piercing
+            | UpgradeType::TripleShot
+            | UpgradeType::RearGun
+            | UpgradeType::RadialBurst => 'B',
+            UpgradeType::Booster | UpgradeType::Shield | UpgradeType::ShipSize => 'S',
+            UpgradeType::EnergyCapacity => 'E',
+            UpgradeType::Health | UpgradeType::HealthMax => 'H',
+            UpgradeType::RapidFire | UpgradeType::Invincibility | UpgradeType::TimeSlow => 'P',
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct Upgrade {
+    pub id: EntityId,
     pub position: Vector2D,
     pub upgrade_type: UpgradeType,
     pub display_char: char,
 }
 
 impl Upgrade {
-    pub fn new(position: Vector2D, upgrade_type: UpgradeType) -> Self {
-        let display_char = match upgrade_type {
-            UpgradeType::FireRate => 'B',
-            UpgradeType::BulletSpeed => 'B',
-            UpgradeType::BulletSize => 'B',
-            UpgradeType::Booster => 'S',
-            UpgradeType::Shield => 'S',
-            UpgradeType::ShipSize => 'S',
-            UpgradeType::Health => 'H',
-            UpgradeType::HealthMax => 'H',
-        };
-        Upgrade { position, upgrade_type, display_char }
+    pub fn new(id: EntityId, position: Vector2D, upgrade_type: UpgradeType) -> Self {
+        Upgrade { id, position, upgrade_type, display_char: upgrade_type.icon() }
     }
+}
 
-    pub fn draw(&self, game_grid: &mut GameGrid) {
-        game_grid.set_char(self.position.x.round() as u16, self.position.y.round() as u16, self.display_char);
+impl Entity for Upgrade {
+    fn draw(&self, game_grid: &mut GameGrid) {
+        let color = if self.upgrade_type.power_up().is_some() { POWER_UP_COLOR } else { UPGRADE_COLOR };
+        game_grid.set_cell(self.position.x.round() as u16, self.position.y.round() as u16, self.display_char, color);
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
+        vec![(self.position.x.round() as u16, self.position.y.round() as u16)]
+    }
+}
+
+impl Identified for Upgrade {
+    fn id(&self) -> EntityId {
+        self.id
     }
 }
 
+#[derive(Clone)]
 pub struct UpgradeBox {
+    pub id: EntityId,
     pub position: Vector2D,
     pub hits_remaining: u32,
     pub shape: Vec<(f64, f64)>,
@@ -50,8 +253,9 @@ pub struct UpgradeBox {
 }
 
 impl UpgradeBox {
-    pub fn new(x: f64, y: f64) -> Self {
+    pub fn new(id: EntityId, x: f64, y: f64) -> Self {
         UpgradeBox {
+            id,
             position: Vector2D::new(x, y),
             hits_remaining: 3, // Example health
             shape: vec![
@@ -62,18 +266,26 @@ impl UpgradeBox {
             display_char: 'U',
         }
     }
+}
 
-    pub fn get_absolute_coords(&self) -> Vec<(u16, u16)> {
+impl Entity for UpgradeBox {
+    fn draw(&self, game_grid: &mut GameGrid) {
+        for &(dx, dy) in &self.shape {
+            let draw_x = (self.position.x + dx).round() as u16;
+            let draw_y = (self.position.y + dy).round() as u16;
+            game_grid.set_cell(draw_x, draw_y, self.display_char, UPGRADE_COLOR);
+        }
+    }
+
+    fn coords(&self) -> Vec<(u16, u16)> {
         self.shape.iter().map(|&(dx, dy)| {
             ((self.position.x + dx).round() as u16, (self.position.y + dy).round() as u16)
         }).collect()
     }
+}
 
-    pub fn draw(&self, game_grid: &mut GameGrid) {
-        for &(dx, dy) in &self.shape {
-            let draw_x = (self.position.x + dx).round() as u16;
-            let draw_y = (self.position.y + dy).round() as u16;
-            game_grid.set_char(draw_x, draw_y, self.display_char);
-        }
+impl Identified for UpgradeBox {
+    fn id(&self) -> EntityId {
+        self.id
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,49 @@
+use crate::player::Player;
+
+// --- Versus: two players duel in one field, and the first to a set number of kills takes the round ---
+// Shots hit the other player's ship as well as asteroids; a ship never takes damage from its own shots.
+
+// The other player's ship a shot from `owner` landed on at `point`, if any. `ship_coords` is every player's
+// ship cells, empty for ships out of play.
+pub fn shot_victim(ship_coords: &[Vec<(u16, u16)>], owner: usize, point: (u16, u16)) -> Option<usize> {
+    ship_coords.iter().enumerate().position(|(index, coords)| index != owner && coords.contains(&point))
+}
+
+// How the round went for each player, for the summary screen.
+pub struct RoundSummary {
+    pub kills_to_win: u32,
+    pub players: Vec<PlayerRound>,
+}
+
+#[derive(Clone, Copy)]
+pub struct PlayerRound {
+    pub kills: u32,
+    pub deaths: u32,
+    pub score: u32,
+}
+
+impl RoundSummary {
+    pub fn new(players: &[Player], kills_to_win: u32) -> Self {
+        let players = players.iter().map(|player| PlayerRound { kills: player.kills, deaths: player.deaths, score: player.score }).collect();
+        RoundSummary { kills_to_win, players }
+    }
+
+    // Whoever has the most kills; None for a tie (a round quit early can end level).
+    pub fn winner(&self) -> Option<usize> {
+        let best = self.players.iter().map(|player| player.kills).max()?;
+        let mut leaders = self.players.iter().enumerate().filter(|(_, player)| player.kills == best);
+        let (index, _) = leaders.next()?;
+        leaders.next().is_none().then_some(index)
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![match self.winner() {
+            Some(index) => format!("PLAYER {} WINS", index + 1),
+            None => "DRAW".to_string(),
+        }];
+        for (index, player) in self.players.iter().enumerate() {
+            lines.push(format!("P{}  Kills {}/{}  Deaths {}  Score {}", index + 1, player.kills, self.kills_to_win, player.deaths, player.score));
+        }
+        lines
+    }
+}
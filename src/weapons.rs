@@ -0,0 +1,58 @@
+use std::f64::consts::{PI, TAU};
+
+use crate::constants::{RADIAL_BURST_EVERY, RADIAL_BURST_SHOTS, TRIPLE_SHOT_SPREAD};
+use crate::entities::Ship;
+use crate::upgrades::UpgradeType;
+
+// --- Weapons: firing patterns the weapon upgrades add to the ship's forward gun ---
+// They don't replace each other: a ship with a triple shot and a rear gun fires both every volley. Every shot
+// in a volley gets the ship's shot speed, size, range and piercing, and the volley waits on the usual cooldown.
+pub const WEAPONS: [UpgradeType; 3] = [UpgradeType::TripleShot, UpgradeType::RearGun, UpgradeType::RadialBurst];
+
+// Headings of the shots in the ship's next volley. `volley` counts the volleys fired before it: the radial burst
+// goes off on every RADIAL_BURST_EVERY-th.
+pub fn volley_angles(ship: &Ship, volley: u32) -> Vec<f64> {
+    let fitted = |weapon: UpgradeType| ship.upgrades.contains(&weapon);
+    let mut angles = vec![ship.angle];
+    if fitted(UpgradeType::TripleShot) {
+        angles.extend([ship.angle - TRIPLE_SHOT_SPREAD, ship.angle + TRIPLE_SHOT_SPREAD]);
+    }
+    if fitted(UpgradeType::RearGun) {
+        angles.push(ship.angle + PI);
+    }
+    if fitted(UpgradeType::RadialBurst) && (volley + 1).is_multiple_of(RADIAL_BURST_EVERY) {
+        // Evenly round the ship, leaving out headings another gun already covers
+        for step in 1..RADIAL_BURST_SHOTS {
+            let angle = ship.angle + TAU * step as f64 / RADIAL_BURST_SHOTS as f64;
+            if !angles.iter().any(|&taken| same_heading(angle, taken)) {
+                angles.push(angle);
+            }
+        }
+    }
+    angles
+}
+
+// Weapons every one of `ships` already has fitted. Another of those would do nothing, so they stop dropping.
+pub fn fitted_on_all<'a>(ships: impl IntoIterator<Item = &'a Ship>) -> Vec<UpgradeType> {
+    let ships: Vec<&Ship> = ships.into_iter().collect();
+    WEAPONS.into_iter().filter(|weapon| !ships.is_empty() && ships.iter().all(|ship| ship.upgrades.contains(weapon))).collect()
+}
+
+fn same_heading(a: f64, b: f64) -> bool {
+    let difference = (a - b).rem_euclid(TAU);
+    difference.min(TAU - difference) < 1e-6
+}
+
+// The HUD's weapon indicator, or None for the plain forward gun. The radial burst shows the volleys until it fires.
+pub fn label(ship: &Ship, volley: u32) -> Option<String> {
+    let parts: Vec<String> = WEAPONS
+        .into_iter()
+        .filter(|weapon| ship.upgrades.contains(weapon))
+        .map(|weapon| match weapon {
+            UpgradeType::RadialBurst => format!("Burst {}", RADIAL_BURST_EVERY - volley % RADIAL_BURST_EVERY),
+            UpgradeType::TripleShot => "Triple".to_string(),
+            _ => "Rear".to_string(),
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" + "))
+}
@@ -0,0 +1,78 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::boss::BossEncounter;
+use crate::bounty::Bounty;
+use crate::clock::Timer;
+use crate::constants::COSMETIC_SEED_SALT;
+use crate::encounters::SaucerEncounter;
+use crate::entities::{Asteroid, Bullet, FloatingText, IonCloud, Particle};
+use crate::entity_id::EntityAllocator;
+use crate::fleet::Fleet;
+use crate::juggling::Juggling;
+use crate::levels::LevelManager;
+use crate::player::Player;
+use crate::run_stats::RunStats;
+use crate::scoring::ScoringRules;
+use crate::shockwave::Shockwave;
+use crate::upgrades::{Upgrade, UpgradeBox, UpgradeType};
+
+// --- World: everything one session simulates, so systems take `&mut World` instead of a list of locals ---
+// `Game` keeps what outlives a session (settings, the clock, input and output); a fresh `World` is built for
+// every game, and cloning one captures the exact moment, RNG included (practice checkpoints rely on this).
+// Entities stay in one `Vec` per kind because collisions need the concrete types; updating and drawing go
+// through `entities::Entity`.
+#[derive(Clone)]
+pub struct World {
+    pub players: Vec<Player>, // Ships, health, lives and scores
+    pub asteroids: Vec<Asteroid>,
+    pub bullets: Vec<Bullet>,
+    pub particles: Vec<Particle>,
+    pub floating_texts: Vec<FloatingText>,
+    pub upgrade_boxes: Vec<UpgradeBox>,
+    pub upgrades: Vec<Upgrade>,
+    pub ion_clouds: Vec<IonCloud>,
+    pub shockwaves: Vec<Shockwave>, // Bombs going off
+    pub fleet: Option<Fleet>, // --fleet: which of the ships the keyboard is steering
+    pub banner: Option<(String, Timer)>, // Centred message and how long it stays up
+    pub levels: LevelManager, // Wave director: quotas, spawn rate and asteroid speed
+    pub saucer_encounter: SaucerEncounter,
+    pub boss_encounter: BossEncounter,
+    pub scoring: Box<dyn ScoringRules>,
+    pub juggling: Juggling,
+    pub bounty: Bounty,
+    pub stats: RunStats, // Kills, shots and pickups for the game over summary
+    pub favoured_upgrades: Vec<UpgradeType>, // The run's loadout tips upgrade drops toward these
+    pub entity_ids: EntityAllocator,
+    pub rng: StdRng,
+    pub cosmetic_rng: StdRng, // Particles and other effects, kept off `rng` so they can't nudge the simulation
+}
+
+impl World {
+    pub fn new(players: Vec<Player>, levels: LevelManager, scoring: Box<dyn ScoringRules>, seed: u64) -> Self {
+        World {
+            players,
+            asteroids: Vec::new(),
+            bullets: Vec::new(),
+            particles: Vec::new(),
+            floating_texts: Vec::new(),
+            upgrade_boxes: Vec::new(),
+            upgrades: Vec::new(),
+            ion_clouds: Vec::new(),
+            shockwaves: Vec::new(),
+            fleet: None,
+            banner: None,
+            levels,
+            saucer_encounter: SaucerEncounter::new(),
+            boss_encounter: BossEncounter::new(),
+            scoring,
+            juggling: Juggling::new(),
+            bounty: Bounty::new(),
+            stats: RunStats::new(),
+            favoured_upgrades: Vec::new(),
+            entity_ids: EntityAllocator::new(),
+            rng: StdRng::seed_from_u64(seed),
+            cosmetic_rng: StdRng::seed_from_u64(seed ^ COSMETIC_SEED_SALT),
+        }
+    }
+}